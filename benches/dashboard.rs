@@ -0,0 +1,73 @@
+//! Renders a dashboard of 50 gradient blocks, once without state (a
+//! new scratch buffer for every side) and once with a
+//! `GradientBlockState` per block reusing its scratch buffers.
+//!
+//! `cargo bench --bench dashboard`
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    widgets::{StatefulWidgetRef, WidgetRef},
+};
+use std::time::{Duration, Instant};
+use tui_gradient_block::{
+    gradient_block::GradientBlock,
+    structs::state::GradientBlockState,
+    theme_presets::cool::t_misty_blue,
+};
+const FRAMES: u32 = 500;
+fn areas(area: Rect) -> Vec<Rect> {
+    Layout::vertical([Constraint::Fill(1); 5])
+        .split(area)
+        .iter()
+        .flat_map(|row| {
+            Layout::horizontal([Constraint::Fill(1); 10])
+                .split(*row)
+                .to_vec()
+        })
+        .collect()
+}
+fn time(mut frame: impl FnMut()) -> Duration {
+    frame();
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        frame();
+    }
+    start.elapsed() / FRAMES
+}
+fn main() {
+    let area = Rect::new(0, 0, 240, 60);
+    let mut buf = Buffer::empty(area);
+    let areas = areas(area);
+    let theme = t_misty_blue::theme();
+    let blocks: Vec<_> = areas
+        .iter()
+        .enumerate()
+        .map(|(i, _)| {
+            GradientBlock::new()
+                .with_gradient(theme.up.clone())
+                .title_top(format!("block {i}"))
+        })
+        .collect();
+    let mut states =
+        vec![GradientBlockState::default(); blocks.len()];
+    let stateless = time(|| {
+        for (block, area) in blocks.iter().zip(&areas) {
+            WidgetRef::render_ref(block, *area, &mut buf);
+        }
+    });
+    let stateful = time(|| {
+        for ((block, area), state) in
+            blocks.iter().zip(&areas).zip(&mut states)
+        {
+            StatefulWidgetRef::render_ref(
+                block, *area, &mut buf, state,
+            );
+        }
+    });
+    println!(
+        "{} blocks, mean per frame over {FRAMES} frames",
+        blocks.len()
+    );
+    println!("  without state: {stateless:?}");
+    println!("  with state:    {stateful:?}");
+}
@@ -1,25 +1,18 @@
-use colorgrad::{Color, GradientBuilder};
 use crossterm::event::{self, *};
 use std::io;
 use tui_gradient_block::{
+    demo::DemoArgs, gradient, structs::border_symbols::SegmentSet,
     types::G,
-    handle_args, structs::border_symbols::SegmentSet,
 };
 fn main() -> io::Result<()> {
-    let arg = handle_args!();
-    let style = SegmentSet::from_ratatui_set(arg);
+    let args = DemoArgs::from_env();
     let mut terminal = ratatui::init();
-    let app_result = run(&mut terminal, style);
+    let app_result = run(&mut terminal, args.border);
     ratatui::restore();
     app_result
 }
 fn solid(col: (u8, u8, u8)) -> G {
-    Box::new(
-        GradientBuilder::new()
-            .colors(&[Color::from_rgba8(col.0, col.1, col.2, 1)])
-            .build::<colorgrad::LinearGradient>()
-            .unwrap(),
-    )
+    gradient!(col)
 }
 fn run(
     terminal: &mut ratatui::DefaultTerminal,
@@ -30,35 +23,18 @@ fn run(
         .with_set(set)
         .left_gradient(solid((48, 174, 209)))
         .bottom_gradient(solid((48, 174, 209)))
-        .top_gradient(Box::new(
-            GradientBuilder::new()
-                .colors(&[
-                    Color::from_rgba8(48, 174, 209, 1),
-                    Color::from_rgba8(225, 22, 247, 1),
-                ])
-                .build::<colorgrad::LinearGradient>()
-                .unwrap(),
-        ))
-        .right_gradient(Box::new(
-            GradientBuilder::new()
-                .colors(&[
-                    Color::from_rgba8(225, 22, 247, 1),
-                    Color::from_rgba8(48, 174, 209, 1),
-                ])
-                .build::<colorgrad::LinearGradient>()
-                .unwrap(),
-        ));
+        .top_gradient(gradient!((48, 174, 209), (225, 22, 247)))
+        .right_gradient(gradient!((225, 22, 247), (48, 174, 209)));
 
     loop {
         terminal.draw(|f| f.render_widget(&block, f.area()))?;
         let event = event::read()?;
 
-        if let Event::Key(key_event) = event {
-            if key_event.kind == KeyEventKind::Press {
-                if let KeyCode::Char('q') = key_event.code {
-                    break Ok(());
-                }
-            }
+        if let Event::Key(key_event) = event
+            && key_event.kind == KeyEventKind::Press
+            && let KeyCode::Char('q') = key_event.code
+        {
+            break Ok(());
         }
     }
 }
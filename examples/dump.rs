@@ -0,0 +1,17 @@
+use tui_gradient_block::{
+    gradient_block::GradientBlock, theme_presets,
+};
+/// Prints every built-in theme to stdout, without taking over the
+/// terminal
+fn main() {
+    for ((name, theme), (_, titles)) in
+        theme_presets::all().zip(theme_presets::all_titles())
+    {
+        let (theme, titles) = (theme(), titles());
+        let block = GradientBlock::new()
+            .title(titles.up.0, titles.up.1)
+            .with_gradient(theme.up);
+        println!("{name}");
+        print!("{}", block.to_ansi(40, 5));
+    }
+}
@@ -0,0 +1,84 @@
+use crossterm::event::{self, *};
+use std::io;
+use tui_gradient_block::demo::DemoArgs;
+fn main() -> io::Result<()> {
+    let args = DemoArgs::from_env();
+    let mut terminal = ratatui::init();
+    let app_result = run(&mut terminal, args);
+    ratatui::restore();
+    app_result
+}
+fn run(
+    terminal: &mut ratatui::DefaultTerminal,
+    args: DemoArgs,
+) -> io::Result<()> {
+    use ratatui::layout::{Constraint, Direction, Layout};
+    use tui_gradient_block::{
+        enums::ThemeVariant, gradient_block::GradientBlock,
+    };
+    let (theme, titles) =
+        args.theme_or("nord").expect("a built-in theme");
+    let set = args.border;
+    let blocks = ThemeVariant::ALL.map(|v| {
+        let (title, pos) = titles[v].clone();
+        GradientBlock::from_theme(&theme, v)
+            .title(title, pos)
+            .with_set(set.clone())
+    });
+    let (blocks_top, blocks_bottom) = blocks.split_at(7);
+
+    loop {
+        terminal.draw(|f| {
+            let base = Layout::new(
+                Direction::Vertical,
+                [
+                    Constraint::Percentage(50),
+                    Constraint::Percentage(50),
+                ],
+            )
+            .split(f.area());
+            let top = Layout::new(
+                Direction::Horizontal,
+                [
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(15),
+                    Constraint::Percentage(15),
+                ],
+            )
+            .split(base[0]);
+            let bottom = Layout::new(
+                Direction::Horizontal,
+                [
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(14),
+                    Constraint::Percentage(15),
+                    Constraint::Percentage(15),
+                ],
+            )
+            .split(base[1]);
+            for (block, area) in blocks_top.iter().zip(top.iter()) {
+                f.render_widget(block, *area);
+            }
+            for (block, area) in
+                blocks_bottom.iter().zip(bottom.iter())
+            {
+                f.render_widget(block, *area);
+            }
+        })?;
+        let event = event::read()?;
+
+        if let Event::Key(key_event) = event
+            && key_event.kind == KeyEventKind::Press
+            && let KeyCode::Char('q') = key_event.code
+        {
+            break Ok(());
+        }
+    }
+}
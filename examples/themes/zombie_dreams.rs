@@ -1,32 +1,30 @@
 use crossterm::event::{self, *};
 use std::io;
-use tui_gradient_block::{
-    handle_args, structs::border_symbols::SegmentSet,
-};
-use tui_rule::create_raw_spans;
+use tui_gradient_block::demo::DemoArgs;
 fn main() -> io::Result<()> {
-    let arg = handle_args!();
-    let style = SegmentSet::from_ratatui_set(arg);
+    let args = DemoArgs::from_env();
     let mut terminal = ratatui::init();
-    let app_result = run(&mut terminal, style);
+    let app_result = run(&mut terminal, args);
     ratatui::restore();
     app_result
 }
 fn run(
     terminal: &mut ratatui::DefaultTerminal,
-    set: SegmentSet,
+    args: DemoArgs,
 ) -> io::Result<()> {
-    use ratatui::{text::Line, layout::{Constraint, Direction, Layout}};
+    use ratatui::{layout::{Constraint, Direction, Layout}};
     use tui_gradient_block::{
         gradient_block::GradientBlock,
-        theme_presets::cool::t_zombie_dreams,
+        gradient_text::gradient_line,
     };
-    let theme = t_zombie_dreams::full();
-    let blocks = vec![
+    let (theme, _) =
+        args.theme_or("zombie_dreams").expect("a built-in theme");
+    let set = args.border;
+    let blocks = [
         GradientBlock::new()
             .with_gradient(theme.double_corners_left)
             .with_set(set.clone()),
-        GradientBlock::new().title_top(Line::from(tui_rule::generate_gradient_text!("Zombie Dreams", theme.right.top)).centered())
+        GradientBlock::new().title_top(gradient_line("Zombie Dreams", &theme.right.top).centered())
             .with_gradient(theme.misc1)
             .with_set(set.clone()),
         GradientBlock::new()
@@ -54,12 +52,11 @@ fn run(
         })?;
         let event = event::read()?;
 
-        if let Event::Key(key_event) = event {
-            if key_event.kind == KeyEventKind::Press {
-                if let KeyCode::Char('q') = key_event.code {
-                    break Ok(());
-                }
-            }
+        if let Event::Key(key_event) = event
+            && key_event.kind == KeyEventKind::Press
+            && let KeyCode::Char('q') = key_event.code
+        {
+            break Ok(());
         }
     }
 }
@@ -0,0 +1,133 @@
+//! [Base16](https://github.com/tinted-theming/home) and Base24
+//! colorschemes as border themes.
+//!
+//! Both the original flat scheme files (`base00: "181818"`) and the
+//! newer ones with a `palette:` section are read; only the `scheme` or
+//! `name`, `author` and `baseXX` keys are looked at.
+//! # Example
+//! ```no_run
+//! # use tui_gradient_block::base16::Base16Scheme;
+//! # use tui_gradient_block::structs::gradient::GradientTheme;
+//! let scheme = Base16Scheme::parse(&std::fs::read_to_string("gruvbox-dark.yaml")?)?;
+//! let theme = GradientTheme::from_base16(&scheme);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use crate::{
+    structs::gradient::{GradientTheme, GradientVariation},
+    types::E,
+};
+use colorgrad::Color;
+/// A Base16 or Base24 colorscheme
+#[derive(Clone, Debug, PartialEq)]
+pub struct Base16Scheme {
+    pub name: String,
+    pub author: String,
+    /// `base00..=base0F`, followed by `base10..=base17` for a Base24
+    /// scheme
+    pub colors: Vec<Color>,
+}
+impl Base16Scheme {
+    /// A Base16 scheme from its 16 colors, `base00` first
+    pub fn new(colors: [Color; 16]) -> Self {
+        Self {
+            name: String::new(),
+            author: String::new(),
+            colors: colors.to_vec(),
+        }
+    }
+    /// Reads a scheme from the text of its YAML file. Every slot from
+    /// `base00` to `base0F` has to be set; with all of `base10..=base17`
+    /// as well, the scheme is a Base24 one.
+    pub fn parse(yaml: &str) -> Result<Self, E> {
+        let mut name = String::new();
+        let mut author = String::new();
+        let mut slots: [Option<Color>; 24] = Default::default();
+        for line in yaml.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key =
+                key.trim().trim_matches(|c| c == '"' || c == '\'');
+            let value = value.trim();
+            let value = match value.chars().next() {
+                Some(q @ ('"' | '\'')) => {
+                    value[1..].split(q).next().unwrap_or_default()
+                }
+                _ => value
+                    .split(" #")
+                    .next()
+                    .unwrap_or_default()
+                    .trim(),
+            };
+            match key {
+                "scheme" | "name" => name = value.to_string(),
+                "author" => author = value.to_string(),
+                _ => {
+                    let Some(slot) = key
+                        .strip_prefix("base")
+                        .filter(|n| n.len() == 2)
+                        .and_then(|n| {
+                            usize::from_str_radix(n, 16).ok()
+                        })
+                        .filter(|&n| n < slots.len())
+                    else {
+                        continue;
+                    };
+                    let hex = value.trim_start_matches('#');
+                    let color =
+                        Color::from_html(format!("#{hex}"))
+                            .map_err(|err| format!("{key}: {err}"))?;
+                    slots[slot] = Some(color);
+                }
+            }
+        }
+        let len = match slots[16..].iter().all(Option::is_some) {
+            true => 24,
+            false => 16,
+        };
+        let colors = slots[..len]
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                c.clone().ok_or_else(|| {
+                    format!("the scheme lacks base{i:02X}").into()
+                })
+            })
+            .collect::<Result<_, E>>()?;
+        Ok(Self {
+            name,
+            author,
+            colors,
+        })
+    }
+    /// The color of slot `slot`, such as `0x0D` for `base0D`
+    pub fn base(&self, slot: usize) -> Option<&Color> {
+        self.colors.get(slot)
+    }
+    pub fn is_base24(&self) -> bool {
+        self.colors.len() >= 24
+    }
+}
+impl GradientTheme {
+    /// A theme made of the accents of a Base16 scheme, from `COLOR_1` to
+    /// `COLOR_5` of the presets (see [`GradientTheme::from_colors`]):
+    /// `base0D` (functions, blue) for the solid sides, `base0E`
+    /// (keywords, magenta), `base0C` (support, cyan), `base0B` (strings,
+    /// green) and `base05`, the foreground, for the light end.
+    pub fn from_base16(scheme: &Base16Scheme) -> Self {
+        let (r, g, b) = GradientVariation::FALLBACK;
+        let base = |slot: usize| {
+            scheme
+                .base(slot)
+                .cloned()
+                .unwrap_or(Color::from_rgba8(r, g, b, 255))
+        };
+        Self::from_colors([
+            base(0x0D),
+            base(0x0E),
+            base(0x0C),
+            base(0x0B),
+            base(0x05),
+        ])
+    }
+}
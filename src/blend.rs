@@ -0,0 +1,82 @@
+use crate::{
+    buffer::Buffer, enums::BlendMode, layout::Rect, style::Color,
+};
+/// Copies the cells of `area` out of the buffer so they can be blended
+/// against once the block has been drawn on top of them.
+pub fn snapshot(area: Rect, buf: &Buffer) -> Buffer {
+    let area = area.intersection(buf.area);
+    let mut under = Buffer::empty(area);
+    for pos in area.positions() {
+        under[pos] = buf[pos].clone();
+    }
+    under
+}
+/// Blends a single channel (0.0..=1.0) of `src` over `dst`.
+pub fn blend_channel(mode: BlendMode, src: f32, dst: f32) -> f32 {
+    match mode {
+        BlendMode::Normal => src,
+        BlendMode::Multiply => src * dst,
+        BlendMode::Screen => 1.0 - (1.0 - src) * (1.0 - dst),
+        BlendMode::Overlay => {
+            if dst < 0.5 {
+                2.0 * src * dst
+            } else {
+                1.0 - 2.0 * (1.0 - src) * (1.0 - dst)
+            }
+        }
+    }
+}
+/// Blends `src` over `dst`.
+/// Only rgb colors can be blended, anything else returns `src` unchanged.
+pub fn blend_color(mode: BlendMode, src: Color, dst: Color) -> Color {
+    match (src, dst) {
+        (Color::Rgb(sr, sg, sb), Color::Rgb(dr, dg, db)) => {
+            macro_rules! ch {
+                ($s:expr, $d:expr) => {
+                    (blend_channel(
+                        mode,
+                        $s as f32 / 255.0,
+                        $d as f32 / 255.0,
+                    ) * 255.0)
+                        .round() as u8
+                };
+            }
+            Color::Rgb(ch!(sr, dr), ch!(sg, dg), ch!(sb, db))
+        }
+        _ => src,
+    }
+}
+/// Blends every cell the block changed against the cell that was there
+/// before it was drawn (taken with [`snapshot`]).
+///
+/// The foreground is blended against the old foreground, falling back
+/// to the old background when the old cell had no rgb foreground.
+pub fn apply(mode: BlendMode, under: &Buffer, buf: &mut Buffer) {
+    if mode == BlendMode::Normal {
+        return;
+    }
+    for pos in under.area.positions() {
+        let old = &under[pos];
+        let cell = &mut buf[pos];
+        if cell == old {
+            continue;
+        }
+        let dst_fg = match old.fg {
+            Color::Rgb(..) => old.fg,
+            _ => old.bg,
+        };
+        cell.fg = blend_color(mode, cell.fg, dst_fg);
+        cell.bg = blend_color(mode, cell.bg, old.bg);
+    }
+}
+/// Restores every cell that had a visible symbol before the block was
+/// drawn (taken with [`snapshot`]), so the block only shows on blank
+/// cells.
+pub fn preserve_content(under: &Buffer, buf: &mut Buffer) {
+    for pos in under.area.positions() {
+        let old = &under[pos];
+        if !old.symbol().trim().is_empty() {
+            buf[pos] = old.clone();
+        }
+    }
+}
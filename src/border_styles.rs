@@ -1,5 +1,4 @@
-use crate::structs::border_symbols::SegmentSet;
-use tui_rule::Set;
+use crate::structs::border_symbols::{SegmentSet, Symbol, SymbolSet};
 // A module of predefined border styles for different visual aesthetics. Each `BorderSymbolSet`
 // instance defines the characters to be used for different parts of the border (corners, sides, and centers).
 //
@@ -9,95 +8,95 @@ use tui_rule::Set;
 // - `MISC3`: A unique style with "$" corners, "~" for center sides, and "─" for top and bottom edges.
 // These styles can be used to customize the appearance of borders for blocks
 pub const MISC1: SegmentSet = SegmentSet {
-    left: Set {
-        start: '+',
-        rep_1: '|',
-        center: '+',
-        rep_2: '|',
-        end: '+',
+    left: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('|'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('|'),
+        end: Symbol::from_char('+'),
     },
-    right: Set {
-        start: '+',
-        rep_1: '|',
-        center: '+',
-        rep_2: '|',
-        end: '+',
+    right: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('|'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('|'),
+        end: Symbol::from_char('+'),
     },
-    top: Set {
-        start: '+',
-        rep_1: '-',
-        center: '+',
-        rep_2: '-',
-        end: '+',
+    top: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('-'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('-'),
+        end: Symbol::from_char('+'),
     },
-    bottom: Set {
-        start: '+',
-        rep_1: '-',
-        center: '+',
-        rep_2: '-',
-        end: '+',
+    bottom: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('-'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('-'),
+        end: Symbol::from_char('+'),
     },
 };
 /// A simple border style with "&" edges and "+" center symbols
 pub const MISC2: SegmentSet = SegmentSet {
-    left: Set {
-        start: '&',
-        rep_1: '|',
-        center: '+',
-        rep_2: '|',
-        end: '&',
+    left: SymbolSet {
+        start: Symbol::from_char('&'),
+        rep_1: Symbol::from_char('|'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('|'),
+        end: Symbol::from_char('&'),
     },
-    right: Set {
-        start: '&',
-        rep_1: '|',
-        center: '+',
-        rep_2: '|',
-        end: '&',
+    right: SymbolSet {
+        start: Symbol::from_char('&'),
+        rep_1: Symbol::from_char('|'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('|'),
+        end: Symbol::from_char('&'),
     },
-    top: Set {
-        start: '&',
-        rep_1: '-',
-        center: '-',
-        rep_2: '-',
-        end: '&',
+    top: SymbolSet {
+        start: Symbol::from_char('&'),
+        rep_1: Symbol::from_char('-'),
+        center: Symbol::from_char('-'),
+        rep_2: Symbol::from_char('-'),
+        end: Symbol::from_char('&'),
     },
-    bottom: Set {
-        start: '&',
-        rep_1: '-',
-        center: '-',
-        rep_2: '-',
-        end: '&',
+    bottom: SymbolSet {
+        start: Symbol::from_char('&'),
+        rep_1: Symbol::from_char('-'),
+        center: Symbol::from_char('-'),
+        rep_2: Symbol::from_char('-'),
+        end: Symbol::from_char('&'),
     },
 };
 
 /// A more unique border style featuring "$" for the corners and "~" for the center sides
 pub const MISC3: SegmentSet = SegmentSet {
-    left: Set {
-        start: '$',
-        rep_1: '│',
-        center: '~',
-        rep_2: '│',
-        end: '$',
+    left: SymbolSet {
+        start: Symbol::from_char('$'),
+        rep_1: Symbol::from_char('│'),
+        center: Symbol::from_char('~'),
+        rep_2: Symbol::from_char('│'),
+        end: Symbol::from_char('$'),
     },
-    right: Set {
-        start: '$',
-        rep_1: '│',
-        center: '~',
-        rep_2: '│',
-        end: '$',
+    right: SymbolSet {
+        start: Symbol::from_char('$'),
+        rep_1: Symbol::from_char('│'),
+        center: Symbol::from_char('~'),
+        rep_2: Symbol::from_char('│'),
+        end: Symbol::from_char('$'),
     },
-    top: Set {
-        start: '$',
-        rep_1: '─',
-        center: '~',
-        rep_2: '─',
-        end: '$',
+    top: SymbolSet {
+        start: Symbol::from_char('$'),
+        rep_1: Symbol::from_char('─'),
+        center: Symbol::from_char('~'),
+        rep_2: Symbol::from_char('─'),
+        end: Symbol::from_char('$'),
     },
-    bottom: Set {
-        start: '$',
-        rep_1: '─',
-        center: '$',
-        rep_2: '─',
-        end: '$',
+    bottom: SymbolSet {
+        start: Symbol::from_char('$'),
+        rep_1: Symbol::from_char('─'),
+        center: Symbol::from_char('$'),
+        rep_2: Symbol::from_char('─'),
+        end: Symbol::from_char('$'),
     },
 };
@@ -0,0 +1,174 @@
+use crate::{enums::ColorDepth, gradient_block::GradientBlock};
+use ratatui::style::Color;
+use std::time::Duration;
+/// What the current terminal is able to display.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The recommended color depth
+    pub color_depth: ColorDepth,
+    /// Whether unicode box-drawing characters can be used safely
+    pub unicode: bool,
+}
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            color_depth: ColorDepth::TrueColor,
+            unicode: true,
+        }
+    }
+}
+impl Capabilities {
+    /// Detects the capabilities of the current terminal from the
+    /// `NO_COLOR`, `COLORTERM`, `TERM`, `LC_ALL`, `LC_CTYPE` and `LANG`
+    /// environment variables.
+    pub fn detect() -> Self {
+        let var = |k: &str| std::env::var(k).ok();
+        let locale = var("LC_ALL")
+            .filter(|v| !v.is_empty())
+            .or_else(|| var("LC_CTYPE").filter(|v| !v.is_empty()))
+            .or_else(|| var("LANG"));
+        let mut caps = Self::from_vars(
+            var("COLORTERM").as_deref(),
+            var("TERM").as_deref(),
+            locale.as_deref(),
+        );
+        if crate::degrade::no_color() {
+            caps.color_depth = ColorDepth::NoColor;
+        }
+        caps
+    }
+    /// Works out the capabilities from the values of `COLORTERM`, `TERM`
+    /// and the locale, without reading the environment.
+    pub fn from_vars(
+        colorterm: Option<&str>,
+        term: Option<&str>,
+        locale: Option<&str>,
+    ) -> Self {
+        let term = term.unwrap_or_default().to_lowercase();
+        let colorterm = colorterm.unwrap_or_default().to_lowercase();
+        let color_depth = if colorterm == "truecolor"
+            || colorterm == "24bit"
+            || term.contains("direct")
+        {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else if term.is_empty() || term == "dumb" {
+            ColorDepth::NoColor
+        } else {
+            ColorDepth::Ansi16
+        };
+        let utf8 = locale.is_some_and(|l| {
+            let l = l.to_lowercase();
+            l.contains("utf-8") || l.contains("utf8")
+        });
+        Self {
+            color_depth,
+            unicode: utf8 && term != "linux" && term != "dumb",
+        }
+    }
+}
+impl GradientBlock<'_> {
+    /// Applies the fallbacks needed for the given terminal
+    /// capabilities: the color depth is limited, and the borders fall
+    /// back to [`crate::preset::ASCII`] when unicode is not safe.
+    ///
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::{capabilities::Capabilities, gradient_block::GradientBlock};
+    /// # use tui_gradient_block::theme_presets::cool::t_misty_blue;
+    /// let block = GradientBlock::new()
+    ///     .with_gradient(t_misty_blue::up())
+    ///     .auto_degrade(Capabilities::detect());
+    /// ```
+    pub fn auto_degrade(mut self, caps: Capabilities) -> Self {
+        self.color_depth = caps.color_depth;
+        if !caps.unicode {
+            self = self.with_set(crate::preset::ASCII);
+        }
+        self
+    }
+}
+/// The background color of the terminal, asked for with the OSC 11
+/// escape sequence. `None` if stdin or stdout isn't a terminal, or no
+/// answer comes within `timeout`, as with terminals that don't support
+/// the query.
+///
+/// Raw mode is enabled while waiting, so call this before the
+/// application starts reading input. A terminal that never answers
+/// leaves a thread blocked reading stdin.
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # use ratatui::style::Color;
+/// # use tui_gradient_block::{capabilities::query_background, structs::gradient::GradientTheme};
+/// let bg = query_background(Duration::from_millis(100)).unwrap_or(Color::Reset);
+/// let theme = GradientTheme::for_background(bg);
+/// ```
+pub fn query_background(timeout: Duration) -> Option<Color> {
+    query_osc_color(11, timeout)
+}
+/// The foreground color of the terminal, asked for with the OSC 10
+/// escape sequence, see [`query_background`]
+pub fn query_foreground(timeout: Duration) -> Option<Color> {
+    query_osc_color(10, timeout)
+}
+fn query_osc_color(code: u8, timeout: Duration) -> Option<Color> {
+    use std::io::{IsTerminal, Read, Write};
+    if !std::io::stdin().is_terminal()
+        || !std::io::stdout().is_terminal()
+    {
+        return None;
+    }
+    let was_raw = crossterm::terminal::is_raw_mode_enabled().ok()?;
+    if !was_raw {
+        crossterm::terminal::enable_raw_mode().ok()?;
+    }
+    let mut out = std::io::stdout();
+    let sent =
+        write!(out, "\x1b]{code};?\x07").and_then(|_| out.flush());
+    let (tx, rx) = std::sync::mpsc::channel();
+    if sent.is_ok() {
+        std::thread::spawn(move || {
+            let mut reply = Vec::new();
+            for byte in std::io::stdin().lock().bytes() {
+                let Ok(byte) = byte else { break };
+                reply.push(byte);
+                // terminated by BEL or ST
+                if byte == 0x07 || reply.ends_with(b"\x1b\\") {
+                    break;
+                }
+            }
+            let _ = tx.send(reply);
+        });
+    }
+    let reply = rx.recv_timeout(timeout).ok();
+    if !was_raw {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+    parse_osc_color(&String::from_utf8_lossy(&reply?))
+}
+/// The color in a terminal's answer to an OSC 10 or 11 query, such as
+/// `"\x1b]11;rgb:1e1e/1e1e/2e2e\x07"`. Each channel has 1 to 4 hex
+/// digits.
+pub fn parse_osc_color(reply: &str) -> Option<Color> {
+    let (_, rgb) = reply.split_once("rgb:")?;
+    let rgb = rgb
+        .split(|c: char| !c.is_ascii_hexdigit() && c != '/')
+        .next()?;
+    let channel = |hex: &str| -> Option<u8> {
+        if hex.is_empty() || hex.len() > 4 {
+            return None;
+        }
+        let max = (1_u32 << (4 * hex.len())) - 1;
+        let v = u32::from_str_radix(hex, 16).ok()?;
+        Some((v * 255 / max) as u8)
+    };
+    let mut channels = rgb.split('/').map(channel);
+    let (r, g, b) =
+        (channels.next()??, channels.next()??, channels.next()??);
+    match channels.next() {
+        None => Some(Color::Rgb(r, g, b)),
+        Some(_) => None,
+    }
+}
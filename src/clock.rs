@@ -0,0 +1,72 @@
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+/// A source of time for the animations, read by
+/// [`GradientBlockState::tick_with`](crate::structs::state::GradientBlockState::tick_with).
+///
+/// `now` only has to grow monotonically; the animations count from the
+/// first reading, so where it starts doesn't matter.
+pub trait Clock {
+    fn now(&self) -> Duration;
+}
+/// The wall clock, counting from when it was created
+#[derive(Clone, Copy, Debug)]
+pub struct SystemClock {
+    start: Instant,
+}
+impl SystemClock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+/// A clock that only moves when told to, for snapshot tests and
+/// replaying recorded sessions
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use tui_gradient_block::clock::ManualClock;
+/// # use tui_gradient_block::structs::state::GradientBlockState;
+/// let clock = ManualClock::default();
+/// let mut state = GradientBlockState::default();
+/// state.tick_with(&clock);
+/// clock.advance(Duration::from_millis(250));
+/// state.tick_with(&clock);
+/// assert_eq!(state.elapsed, Duration::from_millis(250));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ManualClock {
+    now: Cell<Duration>,
+}
+impl ManualClock {
+    pub fn new(now: Duration) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+    /// Moves the clock forward by `dt`
+    pub fn advance(&self, dt: Duration) {
+        self.now.set(self.now.get().saturating_add(dt));
+    }
+    /// Sets the clock to `now`
+    pub fn set(&self, now: Duration) {
+        self.now.set(now);
+    }
+}
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
@@ -0,0 +1,44 @@
+//! Color adjustments for building custom themes, working on the
+//! colorgrad colors the presets are made of.
+//!
+//! Every adjustment happens in the perceptual Oklch color space, so
+//! lightening keeps the hue and rotating the hue keeps the lightness.
+//! Colors pushed outside of the rgb gamut are clamped.
+//! # Example
+//! ```
+//! # use colorgrad::Color;
+//! # use tui_gradient_block::color;
+//! # use tui_gradient_block::gradient;
+//! let base = Color::from_rgba8(229, 110, 85, 255);
+//! let g = gradient!(color::darken(&base, 0.4), base.clone(), color::lighten(&base, 0.4));
+//! ```
+use colorgrad::Color;
+fn map_oklch(c: &Color, f: impl Fn([f32; 3]) -> [f32; 3]) -> Color {
+    let [l, chroma, hue, a] = c.to_oklcha();
+    let [l, chroma, hue] = f([l, chroma, hue]);
+    Color::from_oklcha(l.clamp(0.0, 1.0), chroma.max(0.0), hue, a)
+        .clamp()
+}
+/// Lightens `c` by `f` (0.0..=1.0) of the way to white
+pub fn lighten(c: &Color, f: f32) -> Color {
+    map_oklch(c, |[l, chroma, hue]| [l + (1.0 - l) * f, chroma, hue])
+}
+/// Darkens `c` by `f` (0.0..=1.0) of the way to black
+pub fn darken(c: &Color, f: f32) -> Color {
+    map_oklch(c, |[l, chroma, hue]| [l * (1.0 - f), chroma, hue])
+}
+/// Scales the chroma of `c` by `1.0 + f`; negative values desaturate,
+/// -1.0 gives a gray of the same lightness
+pub fn saturate(c: &Color, f: f32) -> Color {
+    map_oklch(c, |[l, chroma, hue]| [l, chroma * (1.0 + f), hue])
+}
+/// Rotates the hue of `c` by `degrees`
+pub fn shift_hue(c: &Color, degrees: f32) -> Color {
+    map_oklch(c, |[l, chroma, hue]| {
+        [l, chroma, hue + degrees.to_radians()]
+    })
+}
+/// Sets the Oklch lightness of `c` (0.0..=1.0), keeping its hue
+pub fn with_lightness(c: &Color, lightness: f32) -> Color {
+    map_oklch(c, |[_, chroma, hue]| [lightness, chroma, hue])
+}
@@ -0,0 +1,134 @@
+use crate::{
+    enums::ColorVision,
+    structs::gradient::{GradientTheme, GradientVariation},
+    types::G,
+};
+use colorgrad::Color;
+/// The simulation matrices of Machado, Oliveira & Fernandes (2009) at
+/// full severity, applied to linear rgb
+fn matrix(vision: ColorVision) -> Option<[[f32; 3]; 3]> {
+    match vision {
+        ColorVision::Normal => None,
+        ColorVision::Protanopia => Some([
+            [0.152286, 1.052583, -0.204868],
+            [0.114503, 0.786281, 0.099216],
+            [-0.003882, -0.048116, 1.051998],
+        ]),
+        ColorVision::Deuteranopia => Some([
+            [0.367322, 0.860646, -0.227968],
+            [0.280085, 0.672501, 0.047413],
+            [-0.011820, 0.042940, 0.968881],
+        ]),
+        ColorVision::Tritanopia => Some([
+            [1.255528, -0.076749, -0.178779],
+            [-0.078411, 0.930809, 0.147602],
+            [0.004733, 0.691367, 0.303900],
+        ]),
+        ColorVision::Achromatopsia => {
+            Some([[0.2126, 0.7152, 0.0722]; 3])
+        }
+    }
+}
+/// How `c` looks with the given color vision
+pub fn simulate(vision: ColorVision, c: &Color) -> Color {
+    let Some(m) = matrix(vision) else {
+        return c.clone();
+    };
+    let [r, g, b, a] = c.to_linear_rgba();
+    let row = |i: usize| {
+        (m[i][0] * r + m[i][1] * g + m[i][2] * b).clamp(0.0, 1.0)
+    };
+    Color::from_linear_rgba(row(0), row(1), row(2), a)
+}
+/// Shifts the part of `c` lost under `vision` into channels that are
+/// still seen (daltonization), so colors confused under it become
+/// distinguishable again. Typical color vision still sees the shift.
+pub fn daltonize(vision: ColorVision, c: &Color) -> Color {
+    let sim = simulate(vision, c);
+    let (er, eg, eb) = (c.r - sim.r, c.g - sim.g, c.b - sim.b);
+    let (dr, dg, db) = match vision {
+        ColorVision::Normal | ColorVision::Achromatopsia => {
+            return c.clone();
+        }
+        // red/green errors go to the blue channel and lightness
+        ColorVision::Protanopia | ColorVision::Deuteranopia => {
+            (0.0, 0.7 * er + eg, 0.7 * er + eb)
+        }
+        // blue/yellow errors go to red and green
+        ColorVision::Tritanopia => {
+            (er + 0.7 * eb, eg + 0.7 * eb, 0.0)
+        }
+    };
+    Color::new(
+        (c.r + dr).clamp(0.0, 1.0),
+        (c.g + dg).clamp(0.0, 1.0),
+        (c.b + db).clamp(0.0, 1.0),
+        c.a,
+    )
+}
+/// A gradient seen with a given color vision, either simulated or
+/// corrected with [`daltonize`]
+#[derive(Clone)]
+pub struct ColorVisionGradient {
+    pub inner: G,
+    pub vision: ColorVision,
+    /// Daltonize instead of simulating
+    pub correct: bool,
+}
+impl ColorVisionGradient {
+    pub fn new(inner: G, vision: ColorVision, correct: bool) -> Self {
+        Self {
+            inner,
+            vision,
+            correct,
+        }
+    }
+}
+impl colorgrad::Gradient for ColorVisionGradient {
+    fn at(&self, t: f32) -> Color {
+        let c = self.inner.at(t);
+        match self.correct {
+            true => daltonize(self.vision, &c),
+            false => simulate(self.vision, &c),
+        }
+    }
+    fn domain(&self) -> (f32, f32) {
+        self.inner.domain()
+    }
+}
+impl GradientVariation {
+    /// The variation as seen with `vision`, to check a theme
+    pub fn simulate(self, vision: ColorVision) -> Self {
+        self.map(|g| {
+            Box::new(ColorVisionGradient::new(g, vision, false))
+        })
+    }
+}
+impl GradientTheme {
+    /// The theme as seen with `vision`, to check that it still works
+    /// for users with a color vision deficiency
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::enums::ColorVision;
+    /// # use tui_gradient_block::theme_presets::cool::t_minty_green;
+    /// let preview = t_minty_green::theme().simulate(ColorVision::Deuteranopia);
+    /// ```
+    pub fn simulate(self, vision: ColorVision) -> Self {
+        self.map(|g| {
+            Box::new(ColorVisionGradient::new(g, vision, false))
+        })
+    }
+    /// Adjusts every color so red/green based gradients stay
+    /// distinguishable under protanopia and deuteranopia, the most
+    /// common deficiencies (see [`daltonize`])
+    pub fn make_distinguishable(self) -> Self {
+        self.daltonize(ColorVision::Deuteranopia)
+    }
+    /// Adjusts every color so the theme stays distinguishable under
+    /// `vision` (see [`daltonize`])
+    pub fn daltonize(self, vision: ColorVision) -> Self {
+        self.map(|g| {
+            Box::new(ColorVisionGradient::new(g, vision, true))
+        })
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Color, Modifier},
+    types::G,
+};
+/// WCAG relative luminance of a color, 0.0..=1.0. Named and indexed
+/// colors use their usual xterm values; `Reset` gives `None`.
+pub fn relative_luminance(c: Color) -> Option<f32> {
+    let (r, g, b) = crate::degrade::to_rgb(c)?;
+    let linear = |v: u8| {
+        let v = v as f32 / 255.0;
+        match v <= 0.04045 {
+            true => v / 12.92,
+            false => ((v + 0.055) / 1.055).powf(2.4),
+        }
+    };
+    Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+}
+/// WCAG contrast ratio between two colors, from 1.0 (none) to 21.0
+/// (black on white). `None` if either color is `Reset`.
+pub fn contrast_ratio(a: Color, b: Color) -> Option<f32> {
+    let (a, b) = (relative_luminance(a)?, relative_luminance(b)?);
+    Some((a.max(b) + 0.05) / (a.min(b) + 0.05))
+}
+/// The lowest contrast ratio of `fg` against any of the colors
+/// `beneath` it. `Reset` colors are skipped; with nothing left the
+/// result is 21.0, as there is nothing to clash with.
+pub fn min_contrast(
+    fg: Color,
+    beneath: impl IntoIterator<Item = Color>,
+) -> f32 {
+    beneath
+        .into_iter()
+        .filter_map(|c| contrast_ratio(fg, c))
+        .fold(21.0, f32::min)
+}
+/// The colors of `gradient` under a title spanning `start..=end` of a
+/// segment (0.0..=1.0), sampled at `cells` evenly spaced points, one
+/// per cell of the title
+pub fn gradient_span(
+    gradient: &G,
+    start: f32,
+    end: f32,
+    cells: usize,
+) -> Vec<Color> {
+    (0..cells)
+        .map(|i| {
+            let t = match cells {
+                1 => start,
+                _ => {
+                    start
+                        + (end - start) * i as f32
+                            / (cells - 1) as f32
+                }
+            };
+            crate::to_ratatui_color!(gradient.at(t.clamp(0.0, 1.0)))
+        })
+        .collect()
+}
+/// The colors beneath a title about to be drawn over `area` of an
+/// already rendered buffer: the background of each cell, or its
+/// foreground (the border gradient) where the background is `Reset`
+pub fn buffer_span(buf: &Buffer, area: Rect) -> Vec<Color> {
+    area.intersection(buf.area)
+        .positions()
+        .map(|pos| match buf[pos].bg {
+            Color::Reset => buf[pos].fg,
+            bg => bg,
+        })
+        .collect()
+}
+/// The candidate with the highest [`min_contrast`] against `beneath`,
+/// `None` if there are no candidates
+pub fn readable_fg(
+    beneath: &[Color],
+    candidates: &[Color],
+) -> Option<Color> {
+    candidates.iter().copied().max_by(|a, b| {
+        min_contrast(*a, beneath.iter().copied())
+            .total_cmp(&min_contrast(*b, beneath.iter().copied()))
+    })
+}
+/// Picks `accent` if it reaches a contrast ratio of at least `min_ratio`
+/// against every color `beneath`, otherwise whichever of white and
+/// black reads better. WCAG asks for 4.5 for normal text and 3.0 for
+/// large or bold text.
+/// # Example
+/// ```
+/// # use ratatui::style::{Color, Stylize};
+/// # use tui_gradient_block::contrast::gradient_span;
+/// # use tui_gradient_block::contrast::readable_title_fg;
+/// # use tui_gradient_block::{gradient_block::GradientBlock, theme_presets::cool::t_misty_blue};
+/// # let theme = t_misty_blue::theme();
+/// # let block = GradientBlock::new();
+/// # let title = ratatui::text::Line::from(" Logs ");
+/// let beneath = gradient_span(&theme.up.top, 0.1, 0.4, title.width());
+/// let fg = readable_title_fg(&beneath, Some(Color::Cyan), 4.5);
+/// let block = block.title_top(title.fg(fg));
+/// ```
+pub fn readable_title_fg(
+    beneath: &[Color],
+    accent: Option<Color>,
+    min_ratio: f32,
+) -> Color {
+    if let Some(accent) = accent
+        && min_contrast(accent, beneath.iter().copied()) >= min_ratio
+    {
+        return accent;
+    }
+    readable_fg(beneath, &[Color::White, Color::Black])
+        .unwrap_or(Color::White)
+}
+/// The heavy box drawing counterpart of a light, rounded or double
+/// border glyph. Other glyphs are returned unchanged.
+pub fn heavy_glyph(c: char) -> char {
+    match c {
+        '─' | '═' | '╌' | '┄' | '┈' | '╴' | '╶' => '━',
+        '│' | '║' | '╎' | '┆' | '┊' | '╵' | '╷' => '┃',
+        '┌' | '╭' | '╔' => '┏',
+        '┐' | '╮' | '╗' => '┓',
+        '└' | '╰' | '╚' => '┗',
+        '┘' | '╯' | '╝' => '┛',
+        '├' | '╠' => '┣',
+        '┤' | '╣' => '┫',
+        '┬' | '╦' => '┳',
+        '┴' | '╩' => '┻',
+        '┼' | '╬' => '╋',
+        c => c,
+    }
+}
+/// `c` pushed to the lightness extreme opposite of `bg` (Oklch, keeping
+/// its hue), or plain white or black if that still doesn't reach a
+/// contrast ratio of 7.0 (WCAG AAA). A `Reset` background is taken to be
+/// a dark terminal.
+pub fn high_contrast_color(c: Color, bg: Color) -> Color {
+    let dark_bg = relative_luminance(bg).unwrap_or(0.0) < 0.18;
+    let fallback = match dark_bg {
+        true => Color::White,
+        false => Color::Black,
+    };
+    let Some(color) = crate::structs::gradient::to_colorgrad_color(c)
+    else {
+        return fallback;
+    };
+    let l = if dark_bg { 0.92 } else { 0.3 };
+    let snapped = crate::to_ratatui_color!(
+        crate::color::with_lightness(&color, l)
+    );
+    let bg = match bg {
+        Color::Reset => Color::Black,
+        bg => bg,
+    };
+    match contrast_ratio(snapped, bg).unwrap_or(0.0) >= 7.0 {
+        true => snapped,
+        false => fallback,
+    }
+}
+/// Snaps the colors of the border `cells` to [`high_contrast_color`]
+/// against their background, swaps their glyphs for heavy ones and
+/// makes them bold
+pub fn apply_high_contrast(
+    cells: impl IntoIterator<Item = Position>,
+    buf: &mut Buffer,
+) {
+    for pos in cells {
+        if !buf.area.contains(pos) {
+            continue;
+        }
+        let cell = &mut buf[pos];
+        let heavy: String =
+            cell.symbol().chars().map(heavy_glyph).collect();
+        cell.set_symbol(&heavy);
+        cell.fg = high_contrast_color(cell.fg, cell.bg);
+        cell.modifier.insert(Modifier::BOLD);
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{
+    buffer::Buffer,
+    enums::ColorDepth,
+    style::{Color, Modifier},
+    types::G,
+};
+/// Returns true when the `NO_COLOR` environment variable is set to a
+/// non-empty value (see <https://no-color.org>).
+pub fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+/// Relative luminance of an rgb color in the 0.0..=1.0 range.
+/// Returns `None` for non-rgb colors.
+pub fn luminance(c: Color) -> Option<f32> {
+    match c {
+        Color::Rgb(r, g, b) => Some(
+            (0.2126 * r as f32
+                + 0.7152 * g as f32
+                + 0.0722 * b as f32)
+                / 255.0,
+        ),
+        _ => None,
+    }
+}
+/// Strips the colors from every cell the block changed, leaving the
+/// plain border glyphs and titles.
+///
+/// When `modifiers` is true, the intensity of the stripped foreground is
+/// kept as a modifier: dark colors become `DIM` and light ones `BOLD`.
+pub fn apply_monochrome(
+    under: &Buffer,
+    buf: &mut Buffer,
+    modifiers: bool,
+) {
+    for pos in under.area.positions() {
+        let old = &under[pos];
+        let cell = &mut buf[pos];
+        if cell == old {
+            continue;
+        }
+        if modifiers {
+            match luminance(cell.fg) {
+                Some(l) if l < 0.33 => {
+                    cell.modifier.insert(Modifier::DIM)
+                }
+                Some(l) if l > 0.66 => {
+                    cell.modifier.insert(Modifier::BOLD)
+                }
+                _ => {}
+            }
+        }
+        cell.fg = old.fg;
+        cell.bg = old.bg;
+    }
+}
+/// The 16 ansi colors with their usual xterm rgb values.
+pub const ANSI_16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (128, 0, 0)),
+    (Color::Green, (0, 128, 0)),
+    (Color::Yellow, (128, 128, 0)),
+    (Color::Blue, (0, 0, 128)),
+    (Color::Magenta, (128, 0, 128)),
+    (Color::Cyan, (0, 128, 128)),
+    (Color::Gray, (192, 192, 192)),
+    (Color::DarkGray, (128, 128, 128)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (0, 0, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+/// The rgb value of a color, using the usual xterm values for the ansi
+/// and indexed colors. `Reset` has no rgb value.
+pub fn to_rgb(c: Color) -> Option<(u8, u8, u8)> {
+    match c {
+        Color::Reset => None,
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Indexed(i @ 0..16) => Some(ANSI_16[i as usize].1),
+        Color::Indexed(i @ 16..232) => {
+            let i = i - 16;
+            Some((
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            ))
+        }
+        Color::Indexed(i) => {
+            let v = 8 + (i - 232) * 10;
+            Some((v, v, v))
+        }
+        named => ANSI_16
+            .iter()
+            .find(|(n, _)| *n == named)
+            .map(|(_, rgb)| *rgb),
+    }
+}
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+/// Maps an rgb color to the closest of the 16 ansi colors.
+pub fn to_ansi16(c: Color) -> Color {
+    match c {
+        Color::Rgb(r, g, b) => ANSI_16
+            .iter()
+            .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+            .map_or(c, |(named, _)| *named),
+        _ => c,
+    }
+}
+/// Maps a color to the closest of the 16 ansi colors as a
+/// `Color::Indexed(0..16)`, the palette index a terminal themes. Named
+/// colors become their index; `Reset` is kept.
+pub fn to_indexed16(c: Color) -> Color {
+    let Some(rgb) = to_rgb(c) else {
+        return c;
+    };
+    if let Color::Indexed(0..16) = c {
+        return c;
+    }
+    (0..16)
+        .min_by_key(|&i| distance(ANSI_16[i].1, rgb))
+        .map_or(c, |i| Color::Indexed(i as u8))
+}
+/// `n` colors evenly spaced along `gradient`, each mapped to the terminal
+/// palette with [`to_indexed16`], for drawing a gradient without any rgb
+/// output
+/// # Example
+/// ```
+/// # use tui_gradient_block::degrade::indexed_gradient;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::gradient::gradient_from_ratatui;
+/// # use tui_gradient_block::theme_presets::cool::t_misty_blue;
+/// let steps = indexed_gradient(&t_misty_blue::d_to_l(), 8);
+/// let block = GradientBlock::new().top_gradient(gradient_from_ratatui(&steps));
+/// ```
+pub fn indexed_gradient(gradient: &G, n: usize) -> Vec<Color> {
+    crate::gradient_utils::resample(gradient, n)
+        .into_iter()
+        .map(|c| {
+            let [r, g, b, _] = c.to_rgba8();
+            to_indexed16(Color::Rgb(r, g, b))
+        })
+        .collect()
+}
+/// Maps an rgb color to the closest color of the xterm 256 color
+/// palette (the 6x6x6 cube or the grayscale ramp).
+pub fn to_ansi256(c: Color) -> Color {
+    let Color::Rgb(r, g, b) = c else {
+        return c;
+    };
+    let level = |v: u8| {
+        (0..6)
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - v as i32).abs())
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+    let avg = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_i = (avg.saturating_sub(3) / 10).min(23) as u8;
+    let gray_v = 8 + gray_i * 10;
+    match distance((gray_v, gray_v, gray_v), (r, g, b))
+        < distance(cube, (r, g, b))
+    {
+        true => Color::Indexed(232 + gray_i),
+        false => Color::Indexed(
+            16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8,
+        ),
+    }
+}
+/// Converts a color so that it can be displayed with the given depth.
+pub fn quantize(depth: ColorDepth, c: Color) -> Color {
+    match depth {
+        ColorDepth::TrueColor => c,
+        ColorDepth::Ansi256 => to_ansi256(c),
+        ColorDepth::Ansi16 => to_ansi16(c),
+        ColorDepth::Indexed16 => to_indexed16(c),
+        ColorDepth::NoColor => Color::Reset,
+    }
+}
+/// Quantizes the colors of every cell the block changed.
+pub fn apply_color_depth(
+    depth: ColorDepth,
+    under: &Buffer,
+    buf: &mut Buffer,
+) {
+    for pos in under.area.positions() {
+        let cell = &mut buf[pos];
+        if cell == &under[pos] {
+            continue;
+        }
+        cell.fg = quantize(depth, cell.fg);
+        cell.bg = quantize(depth, cell.bg);
+    }
+}
@@ -0,0 +1,206 @@
+//! Command line handling for the examples and small demo apps:
+//! `--border <set>` picks a border set from [`crate::preset::sets`] and
+//! `--theme <name>` a theme from [`crate::theme_presets::all`].
+//!
+//! A lone argument without a flag is taken as the border, so
+//! `cargo run --example misty_blue thick` keeps working.
+//! # Example
+//! ```no_run
+//! # use tui_gradient_block::demo::DemoArgs;
+//! # use tui_gradient_block::enums::ThemeVariant;
+//! # use tui_gradient_block::gradient_block::GradientBlock;
+//! fn main() -> std::io::Result<()> {
+//!     let args = DemoArgs::from_env();
+//!     let (theme, titles) = args.theme_or("misty_blue").unwrap();
+//!     let block = GradientBlock::from_theme(&theme, ThemeVariant::Up)
+//!         .with_set(args.border);
+//!     // ...
+//! #   Ok(())
+//! }
+//! ```
+use crate::{
+    preset,
+    structs::{
+        border_symbols::SegmentSet, gradient::GradientTheme,
+        title::TitleSet,
+    },
+    theme_presets,
+};
+/// The options of a demo, see the [module docs](self)
+#[derive(Clone, Debug)]
+pub struct DemoArgs {
+    /// The border set picked with `--border`, [`preset::PLAIN`] if none
+    pub border: SegmentSet,
+    /// The name of the border set, as listed in [`preset::sets`]
+    pub border_name: &'static str,
+    /// The name of the theme picked with `--theme`, as listed in
+    /// [`theme_presets::all`]
+    pub theme: Option<&'static str>,
+}
+impl Default for DemoArgs {
+    fn default() -> Self {
+        Self {
+            border: preset::PLAIN,
+            border_name: "plain",
+            theme: None,
+        }
+    }
+}
+/// An argument [`DemoArgs::parse`] can't make sense of. Its `Display`
+/// says what is wrong.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DemoArgsError {
+    /// A flag was given without its value
+    MissingValue(String),
+    /// No preset border set has this name
+    UnknownBorder(String),
+    /// No built-in theme has this name
+    UnknownTheme(String),
+    /// An argument that isn't a flag, after the border was given
+    Unexpected(String),
+}
+impl std::fmt::Display for DemoArgsError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::MissingValue(flag) => {
+                write!(f, "`{flag}` needs a value")
+            }
+            Self::UnknownBorder(name) => write!(
+                f,
+                "there is no border set called `{name}`; valid ones \
+                 are {}",
+                border_names().join(", ")
+            ),
+            Self::UnknownTheme(name) => write!(
+                f,
+                "there is no theme called `{name}`; valid ones are {}",
+                theme_names().join(", ")
+            ),
+            Self::Unexpected(arg) => {
+                write!(f, "unexpected argument `{arg}`")
+            }
+        }
+    }
+}
+impl std::error::Error for DemoArgsError {}
+impl DemoArgs {
+    /// Reads the options from `args`, without the program name
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::demo::DemoArgs;
+    /// let args = DemoArgs::parse(["--theme", "nord", "--border=rounded"])?;
+    /// assert_eq!(args.border_name, "rounded");
+    /// # Ok::<(), tui_gradient_block::demo::DemoArgsError>(())
+    /// ```
+    pub fn parse<I, S>(args: I) -> Result<Self, DemoArgsError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut out = Self::default();
+        let mut border_given = false;
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) => {
+                    (flag, Some(value.to_string()))
+                }
+                None => (arg.as_str(), None),
+            };
+            let mut value = || {
+                inline.clone().or_else(|| args.next()).ok_or_else(
+                    || DemoArgsError::MissingValue(flag.to_string()),
+                )
+            };
+            match flag {
+                "--border" | "-b" => {
+                    out.set_border(&value()?)?;
+                    border_given = true;
+                }
+                "--theme" | "-t" => {
+                    let name = value()?;
+                    out.theme =
+                        Some(find_theme(&name).ok_or(
+                            DemoArgsError::UnknownTheme(name),
+                        )?);
+                }
+                _ if !border_given && !arg.starts_with('-') => {
+                    out.set_border(&arg)?;
+                    border_given = true;
+                }
+                _ => return Err(DemoArgsError::Unexpected(arg)),
+            }
+        }
+        Ok(out)
+    }
+    /// Reads the options from the command line. Prints the usage and
+    /// exits for `--help`, and prints the error and usage and exits
+    /// with an error code for invalid arguments.
+    pub fn from_env() -> Self {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        if args.iter().any(|a| a == "--help" || a == "-h") {
+            println!("{}", usage());
+            std::process::exit(0);
+        }
+        match Self::parse(args) {
+            Ok(args) => args,
+            Err(err) => {
+                eprintln!("{err}\n\n{}", usage());
+                std::process::exit(1);
+            }
+        }
+    }
+    /// The theme picked with `--theme`, or else the built-in theme
+    /// called `default`, with its titles. `None` if `default` is not a
+    /// built-in theme and no other was picked.
+    pub fn theme_or(
+        &self,
+        default: &str,
+    ) -> Option<(GradientTheme, TitleSet<'static>)> {
+        let name = self.theme.unwrap_or(default);
+        let theme = theme_presets::by_name(name)?;
+        let titles = theme_presets::all_titles()
+            .find(|(n, _)| *n == name)
+            .map(|(_, titles)| titles())?;
+        Some((theme, titles))
+    }
+    fn set_border(
+        &mut self,
+        name: &str,
+    ) -> Result<(), DemoArgsError> {
+        // `round` was accepted by the old `handle_args!`
+        let lookup = match name.trim().to_lowercase().as_str() {
+            "round" => "rounded".to_string(),
+            other => other.replace('-', "_"),
+        };
+        let (name, set) =
+            preset::sets().find(|(n, _)| *n == lookup).ok_or_else(
+                || DemoArgsError::UnknownBorder(name.to_string()),
+            )?;
+        self.border = set;
+        self.border_name = name;
+        Ok(())
+    }
+}
+/// The help text of [`DemoArgs::from_env`]
+pub fn usage() -> String {
+    format!(
+        "Usage: cargo run --example <example> -- [--border <set>] \
+         [--theme <name>]\n\nBorder sets: {}\nThemes: {}",
+        border_names().join(", "),
+        theme_names().join(", ")
+    )
+}
+fn find_theme(name: &str) -> Option<&'static str> {
+    let name = name.trim().to_lowercase().replace('-', "_");
+    theme_presets::all().map(|(n, _)| n).find(|n| *n == name)
+}
+fn border_names() -> Vec<&'static str> {
+    preset::sets().map(|(name, _)| name).collect()
+}
+fn theme_names() -> Vec<&'static str> {
+    theme_presets::all().map(|(name, _)| name).collect()
+}
@@ -1,8 +1,431 @@
 #[derive(Clone)]
-/// contains the custom border types
-/// Defines different border styles that can be applied.
+/// The border symbols picked by `GradientBlock::with_border_style`
+// only ever passed once to `with_border_style`, boxing the set would
+// just make it harder to write
+#[allow(clippy::large_enum_variant)]
 pub enum BorderStyle {
+    /// A blank border, see `preset::EMPTY`
     NewSet,
     CustomSet(crate::structs::border_symbols::SegmentSet),
     RatatuiSet(ratatui::symbols::border::Set),
+    /// The ratatui border types of the same names
+    Plain,
+    Double,
+    Thick,
+    Rounded,
+    QuadrantInside,
+    QuadrantOutside,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Controls how the block's colors are combined with the colors
+/// already in the buffer underneath it.
+pub enum BlendMode {
+    /// The block's colors replace whatever is underneath (default)
+    #[default]
+    Normal,
+    /// Darkens: `src * dst`
+    Multiply,
+    /// Lightens: `1 - (1 - src) * (1 - dst)`
+    Screen,
+    /// Multiply on dark destinations, screen on light ones
+    Overlay,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+/// How many colors the terminal can display.
+/// Ordered from least to most capable.
+pub enum ColorDepth {
+    /// No colors at all, only glyphs and modifiers
+    NoColor,
+    /// The 16 standard ansi colors
+    Ansi16,
+    /// The same 16 colors, written as `Color::Indexed(0..16)`: nothing
+    /// but references to the terminal's own palette is output, so the
+    /// border follows the colorscheme the terminal is themed with
+    Indexed16,
+    /// The 256 color xterm palette
+    Ansi256,
+    /// 24-bit rgb (default)
+    #[default]
+    TrueColor,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The curve a gradient built with [`crate::gradient!`] follows through
+/// its colors, one of the colorgrad gradient types.
+pub enum GradientKind {
+    /// Straight lines between the colors (default)
+    #[default]
+    Linear,
+    /// A smooth B-spline that passes near, not through, the inner colors
+    Basis,
+    /// A smooth spline through every color
+    CatmullRom,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The color space a gradient blends its colors in.
+pub enum Interpolation {
+    /// Plain rgb, the same as colorgrad's default (default)
+    #[default]
+    Rgb,
+    /// Linear (gamma corrected) rgb
+    LinearRgb,
+    /// Perceptually uniform, avoids muddy midpoints
+    Oklab,
+    /// CIE Lab
+    Lab,
+    /// Hue, saturation, value. Goes around the hue wheel
+    Hsv,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// What a gradient does outside of its domain
+pub enum Repeat {
+    /// Stays at the end colors (default)
+    #[default]
+    Clamp,
+    /// Starts over from the beginning
+    Repeat,
+    /// Goes back and forth
+    Mirror,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// One of the four sides of a block
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// How much visual weight the border has
+pub enum BorderWeight {
+    /// Line glyphs from the border set (default)
+    #[default]
+    Normal,
+    /// Solid block and shade elements (`█`, `▓`, `▒`), see
+    /// [`crate::renderer::HeavyRenderer`]
+    Heavy,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// Which dots of a braille border line are lit, see
+/// [`crate::renderer::BrailleRenderer`]
+pub enum BrailleTexture {
+    /// Every dot, an unbroken line (default)
+    #[default]
+    Solid,
+    /// Every other dot
+    Dotted,
+    /// Runs of four dots with gaps of two
+    Dashed,
+}
+impl BrailleTexture {
+    /// Whether the dot at `index` along the line is lit
+    pub const fn lit(self, index: usize) -> bool {
+        match self {
+            Self::Solid => true,
+            Self::Dotted => index.is_multiple_of(2),
+            Self::Dashed => index % 6 < 4,
+        }
+    }
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// A corner of a block
+pub enum Corner {
+    #[default]
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+impl Corner {
+    /// The cell of this corner of `area`. `area` must not be empty.
+    pub fn position(
+        self,
+        area: ratatui::layout::Rect,
+    ) -> ratatui::layout::Position {
+        use ratatui::layout::Position;
+        let (right, bottom) = (
+            area.right().saturating_sub(1),
+            area.bottom().saturating_sub(1),
+        );
+        match self {
+            Self::TopLeft => Position::new(area.x, area.y),
+            Self::TopRight => Position::new(right, area.y),
+            Self::BottomRight => Position::new(right, bottom),
+            Self::BottomLeft => Position::new(area.x, bottom),
+        }
+    }
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The direction a gradient runs across an area
+pub enum GradientDirection {
+    /// Left to right
+    #[default]
+    Horizontal,
+    /// Top to bottom
+    Vertical,
+    /// Top left to bottom right
+    Diagonal,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// A kind of color vision, for simulating color vision deficiencies
+pub enum ColorVision {
+    /// Typical color vision, colors are left as they are (default)
+    #[default]
+    Normal,
+    /// No red cones, red and green are confused
+    Protanopia,
+    /// No green cones, the most common red/green confusion
+    Deuteranopia,
+    /// No blue cones, blue and yellow are confused
+    Tritanopia,
+    /// No color vision at all, only lightness
+    Achromatopsia,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// What the block blanks before drawing, like ratatui's `Clear`
+pub enum ClearMode {
+    /// Draws over whatever is already there (default)
+    #[default]
+    None,
+    /// Blanks the whole area of the block
+    Area,
+    /// Blanks only the area inside the border, see
+    /// [`GradientBlock::inner`](crate::gradient_block::GradientBlock::inner)
+    Inner,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// How the block treats cells that already have content
+pub enum Compositing {
+    /// The block draws over everything in its area (default)
+    #[default]
+    Overwrite,
+    /// The block only draws onto blank cells and leaves cells with a
+    /// visible symbol as they were, so it can frame existing widgets as
+    /// an overlay without destroying their content
+    PreserveContent,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// What a block does when its area is smaller than its border and
+/// titles need, see
+/// [`GradientBlock::min_size`](crate::gradient_block::GradientBlock::min_size)
+pub enum MinSizePolicy {
+    /// Draws the block at its minimum size and keeps the part that
+    /// falls inside the area (default)
+    #[default]
+    Clip,
+    /// Draws only the top line of the block, with its top titles, on
+    /// the first row of the area
+    Collapse,
+    /// Draws nothing
+    Hide,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// What happens to titles and fill text wider than the room they have
+pub enum Overflow {
+    /// Cut off at the edge of the room
+    Clip,
+    /// Cut off one cell early, with a `…` in that cell
+    Ellipsis,
+    /// Left out entirely
+    Hide,
+    /// Fill text is wrapped onto the next rows; titles can't wrap and
+    /// are cut off between the corners like with `Clip` (default)
+    #[default]
+    Wrap,
+}
+impl Overflow {
+    /// `line` fitted into `width` cells, `None` if it should be left out.
+    /// `Wrap` returns it as it is, wrapping is up to the caller.
+    pub fn fit<'a>(
+        self,
+        line: ratatui::text::Line<'a>,
+        width: u16,
+    ) -> Option<ratatui::text::Line<'a>> {
+        use ratatui::text::Span;
+        let width = width as usize;
+        if line.width() <= width {
+            return Some(line);
+        }
+        let ellipsis = match self {
+            Self::Wrap => return Some(line),
+            Self::Hide => return None,
+            Self::Ellipsis if width == 0 => return None,
+            Self::Ellipsis => 1,
+            Self::Clip => 0,
+        };
+        let mut used = 0;
+        let mut spans = Vec::new();
+        let mut style = ratatui::style::Style::new();
+        'spans: for span in &line.spans {
+            let mut content = String::new();
+            style = span.style;
+            for g in span.styled_graphemes(span.style) {
+                let w = Span::raw(g.symbol).width();
+                if used + w > width - ellipsis {
+                    spans.push(Span::styled(content, style));
+                    break 'spans;
+                }
+                used += w;
+                content.push_str(g.symbol);
+            }
+            spans.push(Span::styled(content, style));
+        }
+        if ellipsis > 0 {
+            spans.push(Span::styled("…", style));
+        }
+        Some(ratatui::text::Line { spans, ..line })
+    }
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// How a [`GradientParagraph`](crate::gradient_paragraph::GradientParagraph)
+/// lays its gradient over the text
+pub enum TextGradient {
+    /// Every line, after wrapping, runs through the whole gradient
+    /// (default)
+    #[default]
+    PerLine,
+    /// The gradient runs once through all of the text, in reading order
+    Text,
+}
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// One of the variations of a
+/// [`GradientTheme`](crate::structs::gradient::GradientTheme), which
+/// both it and a [`TitleSet`](crate::structs::title::TitleSet) can be
+/// indexed with
+/// # Example
+/// ```
+/// # use tui_gradient_block::enums::ThemeVariant;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::theme_presets::cool::t_minty_green;
+/// let theme = t_minty_green::full();
+/// let blocks = ThemeVariant::ALL.map(|v| {
+///     GradientBlock::themed(theme[v].clone(), v.name())
+/// });
+/// ```
+pub enum ThemeVariant {
+    Up,
+    Down,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    DoubleCornersLeft,
+    DoubleCornersRight,
+    Vertical,
+    Horizontal,
+    Misc1,
+    Misc2,
+}
+impl ThemeVariant {
+    /// Every variant, in the order the example apps lay them out
+    pub const ALL: [Self; 14] = [
+        Self::Up,
+        Self::Down,
+        Self::Left,
+        Self::Right,
+        Self::TopLeft,
+        Self::TopRight,
+        Self::BottomLeft,
+        Self::BottomRight,
+        Self::DoubleCornersLeft,
+        Self::DoubleCornersRight,
+        Self::Vertical,
+        Self::Horizontal,
+        Self::Misc1,
+        Self::Misc2,
+    ];
+    /// The name the preset titles use for the variant
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Up => "Up",
+            Self::Down => "Down",
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::TopLeft => "Top Left",
+            Self::TopRight => "Top Right",
+            Self::BottomLeft => "Bottom Left",
+            Self::BottomRight => "Bottom Right",
+            Self::DoubleCornersLeft => "Two Corners Left",
+            Self::DoubleCornersRight => "Two Corners Right",
+            Self::Vertical => "Vertical",
+            Self::Horizontal => "Horizontal",
+            Self::Misc1 => "Misc 1",
+            Self::Misc2 => "Misc 2",
+        }
+    }
+    /// Every variant along with its name
+    pub fn iter() -> impl Iterator<Item = (Self, &'static str)> {
+        Self::ALL.into_iter().map(|v| (v, v.name()))
+    }
 }
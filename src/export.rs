@@ -0,0 +1,239 @@
+use crate::{
+    buffer::{Buffer, Cell},
+    gradient_block::GradientBlock,
+    layout::Rect,
+    style::{Color, Modifier},
+    text::Span,
+    widgets::WidgetRef,
+};
+use std::fmt::Write;
+/// The SGR parameters selecting `c`, as a foreground color or as a
+/// background color
+fn sgr_color(c: Color, bg: bool) -> String {
+    let base = if bg { 40 } else { 30 };
+    match c {
+        Color::Reset => format!("{}", base + 9),
+        Color::Black => format!("{}", base),
+        Color::Red => format!("{}", base + 1),
+        Color::Green => format!("{}", base + 2),
+        Color::Yellow => format!("{}", base + 3),
+        Color::Blue => format!("{}", base + 4),
+        Color::Magenta => format!("{}", base + 5),
+        Color::Cyan => format!("{}", base + 6),
+        Color::Gray => format!("{}", base + 7),
+        Color::DarkGray => format!("{}", base + 60),
+        Color::LightRed => format!("{}", base + 61),
+        Color::LightGreen => format!("{}", base + 62),
+        Color::LightYellow => format!("{}", base + 63),
+        Color::LightBlue => format!("{}", base + 64),
+        Color::LightMagenta => format!("{}", base + 65),
+        Color::LightCyan => format!("{}", base + 66),
+        Color::White => format!("{}", base + 67),
+        Color::Indexed(i) => format!("{};5;{i}", base + 8),
+        Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+    }
+}
+/// The full SGR escape for the style of a cell
+fn sgr(cell: &Cell) -> String {
+    let mut params = vec!["0".to_string()];
+    for (modifier, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if cell.modifier.contains(modifier) {
+            params.push(code.to_string());
+        }
+    }
+    params.push(sgr_color(cell.fg, false));
+    params.push(sgr_color(cell.bg, true));
+    format!("\x1b[{}m", params.join(";"))
+}
+/// Serializes a buffer to text with SGR escapes, one line per row.
+/// Escapes are only emitted when the style changes, and every line ends
+/// with a reset.
+pub fn buffer_to_ansi(buf: &Buffer) -> String {
+    let mut out = String::new();
+    for y in buf.area.top()..buf.area.bottom() {
+        let mut last = None;
+        let mut skip = 0;
+        for x in buf.area.left()..buf.area.right() {
+            let cell = &buf[(x, y)];
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let style = sgr(cell);
+            if last.as_ref() != Some(&style) {
+                out.push_str(&style);
+                last = Some(style);
+            }
+            out.push_str(cell.symbol());
+            // wide symbols cover the cells after them
+            skip = Span::raw(cell.symbol()).width().saturating_sub(1);
+        }
+        let _ = writeln!(out, "\x1b[0m");
+    }
+    out
+}
+impl GradientBlock<'_> {
+    /// Renders the block into a `width` x `height` buffer and
+    /// serializes it with true color SGR escapes, for previews in a
+    /// terminal, screenshots for issues, or golden-file tests.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// print!("{}", GradientBlock::new().to_ansi(20, 5));
+    /// ```
+    pub fn to_ansi(&self, width: u16, height: u16) -> String {
+        buffer_to_ansi(&self.to_buffer(width, height))
+    }
+    fn to_buffer(&self, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        self.render_ref(buf.area, &mut buf);
+        buf
+    }
+    /// Renders the block into a `width` x `height` buffer and
+    /// serializes it to an html `<pre>`, for documentation and gallery
+    /// pages.
+    #[cfg(feature = "export")]
+    pub fn to_html(&self, width: u16, height: u16) -> String {
+        buffer_to_html(&self.to_buffer(width, height))
+    }
+    /// Renders the block into a `width` x `height` buffer and
+    /// serializes it to an svg image.
+    #[cfg(feature = "export")]
+    pub fn to_svg(&self, width: u16, height: u16) -> String {
+        buffer_to_svg(&self.to_buffer(width, height))
+    }
+}
+/// A css/svg hex color, `None` for `Reset`
+#[cfg(feature = "export")]
+fn hex(c: Color) -> Option<String> {
+    crate::degrade::to_rgb(c)
+        .map(|(r, g, b)| format!("#{r:02x}{g:02x}{b:02x}"))
+}
+#[cfg(feature = "export")]
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+/// The inline css for the style of a cell
+#[cfg(feature = "export")]
+fn css(cell: &Cell) -> String {
+    let mut css = String::new();
+    if let Some(fg) = hex(cell.fg) {
+        let _ = write!(css, "color:{fg};");
+    }
+    if let Some(bg) = hex(cell.bg) {
+        let _ = write!(css, "background:{bg};");
+    }
+    if cell.modifier.contains(Modifier::BOLD) {
+        css.push_str("font-weight:bold;");
+    }
+    if cell.modifier.contains(Modifier::DIM) {
+        css.push_str("opacity:0.6;");
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        css.push_str("font-style:italic;");
+    }
+    if cell.modifier.contains(Modifier::UNDERLINED) {
+        css.push_str("text-decoration:underline;");
+    }
+    css
+}
+#[cfg(feature = "export")]
+fn push_span(out: &mut String, style: &str, text: &str) {
+    match style.is_empty() {
+        true => out.push_str(text),
+        false => {
+            let _ =
+                write!(out, "<span style=\"{style}\">{text}</span>");
+        }
+    }
+}
+/// Serializes a buffer to an html `<pre>` with inline colors, one
+/// `<span>` per run of cells with the same style.
+#[cfg(feature = "export")]
+pub fn buffer_to_html(buf: &Buffer) -> String {
+    let mut out = String::from(
+        "<pre style=\"font-family:monospace;line-height:1.2\">",
+    );
+    for y in buf.area.top()..buf.area.bottom() {
+        let mut run: Option<(String, String)> = None;
+        let mut skip = 0;
+        for x in buf.area.left()..buf.area.right() {
+            let cell = &buf[(x, y)];
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            skip = Span::raw(cell.symbol()).width().saturating_sub(1);
+            let style = css(cell);
+            match &mut run {
+                Some((s, text)) if *s == style => {
+                    text.push_str(&escape(cell.symbol()));
+                }
+                _ => {
+                    if let Some((s, text)) = run.take() {
+                        push_span(&mut out, &s, &text);
+                    }
+                    run = Some((style, escape(cell.symbol())));
+                }
+            }
+        }
+        if let Some((s, text)) = run {
+            push_span(&mut out, &s, &text);
+        }
+        out.push('\n');
+    }
+    out.push_str("</pre>");
+    out
+}
+/// Serializes a buffer to an svg image, with a background rect and a
+/// text element per cell. Cells are 9x18 units.
+#[cfg(feature = "export")]
+pub fn buffer_to_svg(buf: &Buffer) -> String {
+    const W: u32 = 9;
+    const H: u32 = 18;
+    let area = buf.area;
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" \
+         height=\"{}\" font-family=\"monospace\" font-size=\"15\">\n",
+        area.width as u32 * W,
+        area.height as u32 * H,
+    );
+    for pos in area.positions() {
+        let cell = &buf[pos];
+        let x = (pos.x - area.x) as u32 * W;
+        let y = (pos.y - area.y) as u32 * H;
+        if let Some(bg) = hex(cell.bg) {
+            let _ = writeln!(
+                out,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{W}\" height=\"{H}\" fill=\"{bg}\"/>"
+            );
+        }
+        if cell.symbol().trim().is_empty() {
+            continue;
+        }
+        let fill =
+            hex(cell.fg).unwrap_or_else(|| "currentColor".into());
+        let weight = match cell.modifier.contains(Modifier::BOLD) {
+            true => " font-weight=\"bold\"",
+            false => "",
+        };
+        let _ = writeln!(
+            out,
+            "<text x=\"{x}\" y=\"{}\" fill=\"{fill}\"{weight}>{}</text>",
+            y + H - 4,
+            escape(cell.symbol())
+        );
+    }
+    out.push_str("</svg>\n");
+    out
+}
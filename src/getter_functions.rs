@@ -0,0 +1,77 @@
+use crate::{
+    enums::Side,
+    gradient_block::{self, SS},
+    layout::Margin,
+    structs::{flags::Sides, gradient::GradientDescriptor},
+    types::{G, T},
+    widgets::Padding,
+};
+/// Read access to what the builders configured, for wrapper code and
+/// tests. The getters carry a `get_` prefix as most setters already use
+/// the plain names.
+impl<'a> gradient_block::GradientBlock<'a> {
+    /// The symbols of every segment
+    pub fn get_symbols(&self) -> SS {
+        let segs = &self.border_segments;
+        SS {
+            top: segs.top.seg.symbols,
+            bottom: segs.bottom.seg.symbols,
+            right: segs.right.seg.symbols,
+            left: segs.left.seg.symbols,
+        }
+    }
+    /// The gradient of a segment, `None` if it has none
+    pub fn get_gradient(&self, side: Side) -> Option<&G> {
+        self.border_segments.get(side).seg.gradient.as_ref()
+    }
+    /// The gradient of a segment as a descriptor with `stops` evenly
+    /// spaced stops (see [`GradientDescriptor::sample`])
+    pub fn get_gradient_descriptor(
+        &self,
+        side: Side,
+        stops: usize,
+    ) -> Option<GradientDescriptor> {
+        self.get_gradient(side)
+            .map(|g| GradientDescriptor::sample(g, stops))
+    }
+    /// The titles, in the order they were added
+    pub fn get_titles(&self) -> &[T<'a>] {
+        &self.titles
+    }
+    /// The sides that are drawn
+    pub fn get_borders(&self) -> Sides {
+        let segs = &self.border_segments;
+        [
+            (Sides::TOP, &segs.top),
+            (Sides::BOTTOM, &segs.bottom),
+            (Sides::LEFT, &segs.left),
+            (Sides::RIGHT, &segs.right),
+        ]
+        .into_iter()
+        .filter(|(_, seg)| seg.should_be_rendered)
+        .fold(Sides::NONE, |sides, (side, _)| sides | side)
+    }
+    /// The padding of each side, as set by `left_padding`, ...
+    pub fn get_padding(&self) -> Padding {
+        let segs = &self.border_segments;
+        Padding {
+            left: segs.left.seg.padding.left,
+            right: segs.right.seg.padding.right,
+            top: segs.top.seg.padding.top,
+            bottom: segs.bottom.seg.padding.bottom,
+        }
+    }
+    /// The margin around the block, as set by `margin`
+    pub fn get_margin(&self) -> Margin {
+        self.border_segments.top.area_margin
+    }
+    /// The margins per side, as set by `margin_sides`, on top of
+    /// [`Self::get_margin`]
+    pub fn get_margin_sides(&self) -> Padding {
+        self.side_margins
+    }
+    /// How many rows or columns a side takes up, 0 when it is hidden
+    pub fn get_thickness(&self, side: Side) -> u16 {
+        self.border_segments.thickness(side)
+    }
+}
@@ -9,23 +9,81 @@ pub use crate::{
     },
     style::{Color, Style},
     text::{self, Line},
-    types::{G, T},
+    types::{Shader, G, T},
     widgets::{
         self,
         block::{self, title::Position},
         Block, Borders, Paragraph, WidgetRef,
     },
 };
-use std::rc::Rc;
 /// A struct that represents a customizable block with gradient text, borders, and other visual elements.
 ///
 /// This struct allows you to create and manage blocks that have a gradient color effect for text,
 /// customizable borders, and areas with specific alignments and fill styles.
+///
+/// Besides the setter chain, a block can be put together field by field
+/// with [`GradientBlockBuilder`], where unset fields keep the values of
+/// [`GradientBlock::new`].
+/// # Example
+/// ```
+/// # use ratatui::style::Color;
+/// # use tui_gradient_block::gradient_block::GradientBlockBuilder;
+/// let block = GradientBlockBuilder::default()
+///     .bg(Some(Color::Black))
+///     .monochrome(tui_gradient_block::degrade::no_color())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(derive_builder::Builder)]
+#[builder(pattern = "owned", default)]
 pub struct GradientBlock<'a> {
     pub fill: Line<'a>,
+    /// Tiled over the inner area, under the fill
+    pub pattern: Option<structs::pattern::Pattern>,
+    /// Animated background of the inner area
+    pub effect: Option<structs::effect::Effect>,
     pub titles: Vec<T<'a>>,
+    /// Labels drawn on a side at a fraction (0.0..=1.0) of its length
+    pub labels: Vec<(enums::Side, f32, Line<'a>)>,
     pub bg: Option<Color>,
     pub border_segments: border_segment::BorderSegments,
+    pub blend_mode: enums::BlendMode,
+    pub monochrome: bool,
+    pub high_contrast: bool,
+    pub clear: enums::ClearMode,
+    pub compositing: enums::Compositing,
+    /// Margins per side, on top of the symmetric `margin`
+    pub side_margins: widgets::Padding,
+    pub corner_colors: Vec<(enums::Corner, Color)>,
+    /// At most one badge per corner
+    pub badges: Vec<structs::badge::Badge>,
+    pub intensity_modifiers: bool,
+    pub color_depth: enums::ColorDepth,
+    pub cell_shader: Option<Shader>,
+    pub phase: f32,
+    pub shadow: Option<structs::shadow::ShadowConfig>,
+    pub glow: Option<structs::glow::GlowConfig>,
+    pub progress: Option<structs::progress::ProgressConfig>,
+    pub gauge: Option<structs::gauge::GaugeConfig>,
+    pub scrollbar: Option<structs::scrollbar::ScrollbarConfig>,
+    pub spinner: Option<structs::spinner::SpinnerConfig>,
+    pub tabs: Option<structs::tabs::TabsConfig>,
+    pub hints: Option<structs::hints::HintsConfig>,
+    pub value_label: Option<structs::value_label::ValueLabel>,
+    /// At most one row of indicators per side
+    pub indicators: Vec<structs::indicators::Indicators>,
+    pub min_size_policy: enums::MinSizePolicy,
+    pub overflow: enums::Overflow,
+    pub reveal: Option<structs::reveal::RevealConfig>,
+    pub pulse: Option<structs::pulse::PulseConfig>,
+    pub shimmer: Option<structs::shimmer::ShimmerConfig>,
+    pub sparkle: Option<structs::sparkle::SparkleConfig>,
+    pub scope: Option<gradient::GradientScope>,
+    pub style: Style,
+    pub border_style: Style,
+    pub title_style: Style,
+    pub title_alignment: Option<prelude::Alignment>,
+    pub title_background: Option<structs::title::TitleBackground>,
 }
 
 impl Default for GradientBlock<'_> {
@@ -34,34 +92,125 @@ impl Default for GradientBlock<'_> {
     }
 }
 
-impl GradientBlock<'_> {
+impl<'a> GradientBlock<'a> {
     pub fn new() -> Self {
         Self {
             fill: Line::raw(""),
+            pattern: None,
+            effect: None,
             titles: Vec::new(),
+            labels: Vec::new(),
             bg: None,
             border_segments: border_segment::BorderSegments::new(),
+            blend_mode: enums::BlendMode::Normal,
+            monochrome: false,
+            high_contrast: false,
+            clear: enums::ClearMode::None,
+            compositing: enums::Compositing::Overwrite,
+            side_margins: widgets::Padding::ZERO,
+            corner_colors: Vec::new(),
+            badges: Vec::new(),
+            intensity_modifiers: false,
+            color_depth: enums::ColorDepth::TrueColor,
+            cell_shader: None,
+            phase: 0.0,
+            shadow: None,
+            glow: None,
+            progress: None,
+            gauge: None,
+            scrollbar: None,
+            spinner: None,
+            tabs: None,
+            hints: None,
+            value_label: None,
+            indicators: Vec::new(),
+            min_size_policy: enums::MinSizePolicy::Clip,
+            overflow: enums::Overflow::Wrap,
+            reveal: None,
+            pulse: None,
+            shimmer: None,
+            sparkle: None,
+            scope: None,
+            style: Style::new(),
+            border_style: Style::new(),
+            title_style: Style::new(),
+            title_alignment: None,
+            title_background: None,
         }
     }
+    /// A block with `variation` as its gradients and `title` on top,
+    /// the usual `new` + `title_top` + `with_gradient` chain in one call.
+    /// The border keeps the default set; chain `with_set` for another.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::preset;
+    /// # use tui_gradient_block::theme_presets::cool::t_minty_green;
+    /// let block = GradientBlock::themed(t_minty_green::full().up, "Up")
+    ///     .with_set(preset::ROUNDED);
+    /// ```
+    pub fn themed<L: Into<Line<'a>>>(
+        variation: gradient::GradientVariation,
+        title: L,
+    ) -> Self {
+        Self::new().title_top(title).with_gradient(variation)
+    }
+    /// A block with the `which` variation of `theme` as its gradients
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::enums::ThemeVariant;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::theme_presets::cool::t_minty_green;
+    /// let theme = t_minty_green::full();
+    /// let block = GradientBlock::from_theme(&theme, ThemeVariant::Misc1)
+    ///     .title_top("Logs");
+    /// ```
+    pub fn from_theme(
+        theme: &gradient::GradientTheme,
+        which: enums::ThemeVariant,
+    ) -> Self {
+        Self::new().with_gradient(theme.variation(which).clone())
+    }
+    /// A block using `default` as its gradients, unless the end user
+    /// overrides the theme through the `TUI_GRADIENT_THEME` environment
+    /// variable, in which case the `misc1` variation of that theme is
+    /// used (see [`crate::theme_presets::from_env`]).
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::theme_presets::cool::t_misty_blue;
+    /// let block = GradientBlock::from_env_or(t_misty_blue::theme().misc1);
+    /// ```
+    pub fn from_env_or(default: gradient::GradientVariation) -> Self {
+        let variation = crate::theme_presets::from_env()
+            .map(|theme| theme.misc1)
+            .unwrap_or(default);
+        Self::new().with_gradient(variation)
+    }
     /// Sets the border line segments based on the area and border symbols.
-    fn render_block(&self, area: Rc<R>, buf: &mut buffer::Buffer) {
+    fn render_block(
+        &self,
+        area: R,
+        scratch: &mut buffer::Buffer,
+        buf: &mut buffer::Buffer,
+    ) {
         if self.border_segments.left.should_be_rendered {
-            Self::render_left(self, *area, buf);
+            Self::render_left(self, area, scratch, buf);
         }
         if self.border_segments.right.should_be_rendered {
-            Self::render_right(self, *area, buf);
+            Self::render_right(self, area, scratch, buf);
         }
         if self.border_segments.top.should_be_rendered {
-            Self::render_top(self, *area, buf);
+            Self::render_top(self, area, scratch, buf);
         }
         if self.border_segments.bottom.should_be_rendered {
-            Self::render_bottom(self, *area, buf);
+            Self::render_bottom(self, area, scratch, buf);
         }
     }
     /// Renders the top segment of the border with an optional gradient
     /// ## Visual Representation:
     /// Without the function:
-    /// ```
+    /// ```text
     /// +     +
     /// |     |
     /// |     |
@@ -70,14 +219,25 @@ impl GradientBlock<'_> {
     /// |     |
     /// +-----+
     /// ```
-    fn render_top(&self, area: R, buf: &mut buffer::Buffer) {
-        self.border_segments.top.seg.render_ref(area, buf);
+    fn render_top(
+        &self,
+        area: R,
+        scratch: &mut buffer::Buffer,
+        buf: &mut buffer::Buffer,
+    ) {
+        self.border_segments.render_side_in(
+            enums::Side::Top,
+            area,
+            self.phase,
+            scratch,
+            buf,
+        );
     }
 
     /// Renders the left segment of the border with an optional gradient
     /// ## Visual Representation:
     /// Without the function:
-    /// ```
+    /// ```text
     /// +-----+
     ///       |
     ///       |
@@ -87,14 +247,25 @@ impl GradientBlock<'_> {
     ///       |
     /// +-----+
     /// ```
-    fn render_left(&self, area: R, buf: &mut buffer::Buffer) {
-        self.border_segments.left.seg.render_ref(area, buf);
+    fn render_left(
+        &self,
+        area: R,
+        scratch: &mut buffer::Buffer,
+        buf: &mut buffer::Buffer,
+    ) {
+        self.border_segments.render_side_in(
+            enums::Side::Left,
+            area,
+            self.phase,
+            scratch,
+            buf,
+        );
     }
 
     /// Renders the bottom segment of the border with an optional gradient
     /// ## Visual Representation:
     /// Without the function:
-    /// ```
+    /// ```text
     /// +--+--+
     /// |     |
     /// |     |
@@ -103,14 +274,25 @@ impl GradientBlock<'_> {
     /// |     |
     /// +     +
     /// ````
-    fn render_bottom(&self, area: R, buf: &mut buffer::Buffer) {
-        self.border_segments.bottom.seg.render_ref(area, buf);
+    fn render_bottom(
+        &self,
+        area: R,
+        scratch: &mut buffer::Buffer,
+        buf: &mut buffer::Buffer,
+    ) {
+        self.border_segments.render_side_in(
+            enums::Side::Bottom,
+            area,
+            self.phase,
+            scratch,
+            buf,
+        );
     }
 
     /// Renders the right segment of the border with an optional gradient
     /// ## Visual Representation:
     /// Without the function:
-    /// ```
+    /// ```text
     /// +--+--+
     /// |     
     /// |     
@@ -119,71 +301,784 @@ impl GradientBlock<'_> {
     /// |     
     /// +--+--+
     /// ```
-    fn render_right(&self, area: R, buf: &mut buffer::Buffer) {
-        self.border_segments.right.seg.render_ref(area, buf);
+    fn render_right(
+        &self,
+        area: R,
+        scratch: &mut buffer::Buffer,
+        buf: &mut buffer::Buffer,
+    ) {
+        self.border_segments.render_side_in(
+            enums::Side::Right,
+            area,
+            self.phase,
+            scratch,
+            buf,
+        );
     }
 
-    /// Renders the titles for the widget, with an optional gradient
-    fn render_titles(&self, area: Rc<R>, buf: &mut buffer::Buffer) {
-        for (title, pos) in &self.titles {
-            let padding = match pos {
-                Position::Top => self.border_segments.top.seg.padding,
-                Position::Bottom => {
-                    self.border_segments.bottom.seg.padding
+    /// The positions and symbols of every border cell the block would
+    /// draw in `area`, row by row. Symbols are whole, as
+    /// `Cell::symbol` has them, so emoji and combining characters are
+    /// kept.
+    ///
+    /// Useful for hit-testing, custom styling passes, or exporting the
+    /// border without re-deriving the segment math.
+    /// # Example
+    /// ```
+    /// # use ratatui::layout::{Position, Rect};
+    /// # let block = tui_gradient_block::gradient_block::GradientBlock::new();
+    /// # let area = Rect::new(0, 0, 20, 5);
+    /// # let mouse_pos = Position::new(19, 2);
+    /// let on_border = block
+    ///     .border_cells(area)
+    ///     .any(|(pos, _)| pos == mouse_pos);
+    /// # assert!(on_border);
+    /// ```
+    pub fn border_cells(
+        &self,
+        area: R,
+    ) -> impl Iterator<Item = (crate::layout::Position, String)> {
+        self.cells(self.block_area(area))
+    }
+    /// The border cells in an area the side margins were already taken
+    /// off
+    fn cells(
+        &self,
+        area: R,
+    ) -> impl Iterator<Item = (crate::layout::Position, String)> {
+        self.border_segments.cells(area).into_iter()
+    }
+    /// `area` without the side margins (see `margin_sides`)
+    fn outer(&self, area: R) -> R {
+        let m = self.side_margins;
+        R {
+            x: area.x.saturating_add(m.left),
+            y: area.y.saturating_add(m.top),
+            width: area.width.saturating_sub(m.left + m.right),
+            height: area.height.saturating_sub(m.top + m.bottom),
+        }
+    }
+
+    /// `area` without the side margins and the room an inset glow
+    /// takes, where the border is drawn
+    fn block_area(&self, area: R) -> R {
+        let area = self.outer(area);
+        self.glow.map_or(area, |g| g.block_area(area))
+    }
+
+    /// Every cell a block drawn in `area` (a block area) may change:
+    /// the block itself plus its shadow and an outside glow, clipped to
+    /// `bounds`
+    fn painted_area(&self, area: R, bounds: R) -> R {
+        let shadow = self
+            .shadow
+            .map_or(area, |s| area.union(s.area(area, bounds)));
+        self.glow
+            .map_or(shadow, |g| shadow.union(g.area(area, bounds)))
+            .intersection(bounds)
+    }
+
+    /// The area inside the border and margins, where content rendered
+    /// inside the block should go.
+    pub fn inner(&self, area: R) -> R {
+        self.inner_of_block_area(self.block_area(area))
+    }
+    /// The area inside the border of a block drawn in `area`, an area
+    /// [`Self::block_area`] was already applied to
+    fn inner_of_block_area(&self, area: R) -> R {
+        use enums::Side;
+        let segs = &self.border_segments;
+        let marg = segs.top.area_margin;
+        let left = marg.horizontal + segs.thickness(Side::Left);
+        let right = marg.horizontal + segs.thickness(Side::Right);
+        let top = marg.vertical + segs.thickness(Side::Top);
+        let bottom = marg.vertical + segs.thickness(Side::Bottom);
+        R {
+            x: area.x.saturating_add(left),
+            y: area.y.saturating_add(top),
+            width: area.width.saturating_sub(left + right),
+            height: area.height.saturating_sub(top + bottom),
+        }
+    }
+
+    /// Applies the border style to every border cell, keeping the
+    /// foreground colors gradients gave them
+    fn render_border_style(&self, area: R, buf: &mut buffer::Buffer) {
+        if self.border_style == Style::new() {
+            return;
+        }
+        for (pos, _) in self.cells(area) {
+            if let Some(cell) = buf.cell_mut(pos) {
+                let fg = cell.fg;
+                cell.set_style(self.border_style);
+                if fg != Color::Reset {
+                    cell.fg = fg;
                 }
-            };
-            let marg = self.border_segments.top.seg.area_margin;
-            let x = get_aligned_position!(
-                *area,
-                title.alignment,
-                title.width() as u16,
-                padding.left,
-                padding.right
-            )
-            .saturating_add(marg.horizontal / 2);
-            let y = match pos {
-                Position::Top => area
-                    .top()
-                    .saturating_add(padding.top)
-                    .saturating_add(marg.horizontal),
-
-                Position::Bottom => area
-                    .bottom()
-                    .saturating_sub(padding.bottom)
-                    .saturating_sub(marg.vertical),
-            };
+            }
+        }
+    }
+
+    /// Forces the corner glyphs set with `corner_color` to their colors
+    fn render_corner_colors(
+        &self,
+        area: R,
+        buf: &mut buffer::Buffer,
+    ) {
+        let area = area.inner(self.border_segments.top.area_margin);
+        if area.is_empty() {
+            return;
+        }
+        for (corner, color) in &self.corner_colors {
+            if let Some(cell) = buf.cell_mut(corner.position(area)) {
+                cell.fg = *color;
+            }
+        }
+    }
+
+    /// Recolors every border cell from the global gradient, if a scope
+    /// is set
+    fn render_scope(&self, area: R, buf: &mut buffer::Buffer) {
+        let Some(scope) = &self.scope else {
+            return;
+        };
+        let frame = scope.frame.unwrap_or(buf.area);
+        for (pos, _) in self.cells(area) {
+            let color = crate::renderer::sample(
+                &scope.gradient,
+                scope.t(frame, pos),
+                0.0,
+            );
+            if let Some(cell) = buf.cell_mut(pos) {
+                cell.fg = color;
+            }
+        }
+    }
+
+    /// Recolors every border cell with the cell shader, if one is set
+    fn render_shader(&self, area: R, buf: &mut buffer::Buffer) {
+        let Some(shader) = &self.cell_shader else {
+            return;
+        };
+        for seg in self.border_segments.rendered() {
+            let cells =
+                self.border_segments.side_cells(seg.side, area);
+            let last = cells.len().saturating_sub(1).max(1) as f32;
+            for (i, (pos, _)) in cells.into_iter().enumerate() {
+                if let Some(cell) = buf.cell_mut(pos) {
+                    cell.fg = shader(pos.x, pos.y, i as f32 / last);
+                }
+            }
+        }
+    }
 
-            buf.set_line(x, y, title, area.width);
+    /// The cells the title at `index` takes up when the block is drawn
+    /// in `area`, the caps of its background included and cut off at
+    /// the right of `area`, for hit-testing. `None` if there is no such
+    /// title or it falls outside `area`.
+    pub fn title_area(&self, index: usize, area: R) -> Option<R> {
+        self.place_title(index, self.block_area(area))
+            .map(|(_, rect)| rect)
+    }
+    /// The title at `index` fitted to the room between the corners
+    /// with the block's overflow, and where it goes, in an area the
+    /// margins were already taken off
+    fn place_title(
+        &self,
+        index: usize,
+        area: R,
+    ) -> Option<(Line<'a>, R)> {
+        let (title, pos) = self.titles.get(index)?;
+        let padding = match pos {
+            Position::Top => self.border_segments.top.seg.padding,
+            Position::Bottom => {
+                self.border_segments.bottom.seg.padding
+            }
+        };
+        let caps = self
+            .title_background
+            .as_ref()
+            .map_or(0, |bg| bg.caps_width());
+        let marg = self.border_segments.top.area_margin;
+        let room = area.width.saturating_sub(
+            padding.left
+                + padding.right
+                + marg.horizontal * 2
+                + caps
+                + 2,
+        );
+        // titles can't wrap, so they are clipped between the corners
+        let overflow = match self.overflow {
+            enums::Overflow::Wrap => enums::Overflow::Clip,
+            overflow => overflow,
+        };
+        let title = overflow.fit(title.clone(), room)?;
+        let width = (title.width() as u16).saturating_add(caps);
+        let x = get_aligned_position!(
+            area,
+            title.alignment.or(self.title_alignment),
+            width,
+            padding.left,
+            padding.right
+        )
+        .saturating_add(marg.horizontal / 2)
+        .max(area.left());
+        let y = match pos {
+            Position::Top => area
+                .top()
+                .saturating_add(padding.top)
+                .saturating_add(marg.vertical),
+
+            Position::Bottom => area
+                .bottom()
+                .saturating_sub(1)
+                .saturating_sub(padding.bottom)
+                .saturating_sub(marg.vertical),
+        };
+        // titles that don't fit in the area are cut off, never drawn
+        // outside of it
+        if !(area.top()..area.bottom()).contains(&y)
+            || x >= area.right()
+        {
+            return None;
         }
+        Some((title, R::new(x, y, width.min(area.right() - x), 1)))
+    }
+
+    /// The cells the badge in `corner` takes up when the block is drawn
+    /// in `area`, for hit-testing. `None` if there is no badge there.
+    /// # Example
+    /// ```
+    /// # use ratatui::{layout::{Position, Rect}, style::Style};
+    /// # use tui_gradient_block::{enums::Corner, gradient_block::GradientBlock};
+    /// # let block = GradientBlock::new().badge(Corner::TopRight, "3", Style::new());
+    /// # let area = Rect::new(0, 0, 20, 5);
+    /// # let mouse_pos = Position::new(16, 0);
+    /// let clicked = block
+    ///     .badge_area(Corner::TopRight, area)
+    ///     .is_some_and(|badge| badge.contains(mouse_pos));
+    /// ```
+    pub fn badge_area(
+        &self,
+        corner: enums::Corner,
+        area: R,
+    ) -> Option<R> {
+        self.badges.iter().find(|b| b.corner == corner)?.area(
+            self.block_area(area),
+            self.border_segments.top.area_margin,
+        )
     }
 
-    /// Renders the fill for the widget, including optional gradient rendering.
-    fn render_fill(&self, area: Rc<R>, buf: &mut buffer::Buffer) {
-        Paragraph::new(self.fill.clone())
-            .wrap(widgets::Wrap { trim: true })
-            .block(Block::default().borders(Borders::ALL))
-            .render(*area, buf);
+    /// The cells of the indicator dots on `side` when the block is drawn
+    /// in `area`, in the order they were given, for hit-testing. `None`
+    /// for dots that don't fit; empty if the side has no indicators.
+    pub fn indicator_positions(
+        &self,
+        side: enums::Side,
+        area: R,
+    ) -> Vec<Option<crate::layout::Position>> {
+        self.indicators
+            .iter()
+            .find(|i| i.side == side)
+            .map(|i| {
+                i.positions(
+                    self.border_segments.get(side),
+                    self.block_area(area),
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    /// Renders the titles for the widget, with an optional gradient
+    fn render_titles(&self, area: R, buf: &mut buffer::Buffer) {
+        let mut spans = Vec::with_capacity(self.titles.len());
+        for i in 0..self.titles.len() {
+            let Some((title, title_area)) = self.place_title(i, area)
+            else {
+                continue;
+            };
+            spans.push(title_area);
+            let title = title.patch_style(self.title_style);
+            match &self.title_background {
+                Some(bg) => bg.render(&title, title_area, buf),
+                None => {
+                    buf.set_line(
+                        title_area.x,
+                        title_area.y,
+                        &title,
+                        area.right() - title_area.x,
+                    );
+                }
+            }
+        }
+        if let Some(tabs) = &self.tabs
+            && self.border_segments.top.should_be_rendered
+        {
+            tabs.render(&self.border_segments.top, area, &spans, buf);
+        }
+        if let Some(hints) = &self.hints
+            && self.border_segments.bottom.should_be_rendered
+        {
+            hints.render(
+                &self.border_segments.bottom,
+                area,
+                &spans,
+                buf,
+            );
+        }
+        for indicators in &self.indicators {
+            let segment = self.border_segments.get(indicators.side);
+            if segment.should_be_rendered {
+                indicators.render(segment, area, buf);
+            }
+        }
+        if let Some(gauge) = &self.gauge {
+            let label = Line::from(gauge.label());
+            self.render_label(
+                area,
+                enums::Side::Bottom,
+                0.5,
+                &label,
+                buf,
+            );
+        }
+        for (side, at, label) in &self.labels {
+            self.render_label(area, *side, *at, label, buf);
+        }
+    }
+    /// Draws `label` on the line of `side`, centered on `at` of its
+    /// length and pushed off the corners. Labels read left to right on
+    /// the top and bottom and one grapheme per row on the sides; the
+    /// part that doesn't fit between the corners is cut off.
+    fn render_label(
+        &self,
+        area: R,
+        side: enums::Side,
+        at: f32,
+        label: &Line,
+        buf: &mut buffer::Buffer,
+    ) {
+        let seg = self.border_segments.get(side);
+        if !seg.should_be_rendered {
+            return;
+        }
+        let label = label.clone().patch_style(self.title_style);
+        let vertical =
+            matches!(side, enums::Side::Left | enums::Side::Right);
+        let len = seg.edge_len(area);
+        let n = match vertical {
+            true => label.styled_graphemes(Style::default()).count(),
+            false => label.width(),
+        } as u16;
+        let center = (at.clamp(0.0, 1.0)
+            * len.saturating_sub(1) as f32)
+            .round();
+        let start = (center as u16)
+            .saturating_sub(n / 2)
+            .min(len.saturating_sub(n + 1))
+            .max(1);
+        let end = len.saturating_sub(1);
+        if vertical {
+            let graphemes = label.styled_graphemes(Style::default());
+            for (offset, g) in (start..end).zip(graphemes) {
+                if let Some(cell) = seg
+                    .line_position(area, offset)
+                    .and_then(|pos| buf.cell_mut(pos))
+                {
+                    cell.set_symbol(g.symbol).set_style(g.style);
+                }
+            }
+        } else if let Some(pos) = seg.line_position(area, start) {
+            buf.set_line(
+                pos.x,
+                pos.y,
+                &label,
+                end.saturating_sub(start),
+            );
+        }
+    }
+
+    /// Renders the fill for the widget, including optional gradient
+    /// rendering, inside the border of a block drawn in `area`
+    fn render_fill(&self, area: R, buf: &mut buffer::Buffer) {
+        let inner = self.inner_of_block_area(area);
+        if self.overflow == enums::Overflow::Wrap {
+            Paragraph::new(self.fill.clone())
+                .wrap(widgets::Wrap { trim: true })
+                .render(inner, buf);
+            return;
+        }
+        if let Some(fill) =
+            self.overflow.fit(self.fill.clone(), inner.width)
+        {
+            Paragraph::new(fill).render(inner, buf);
+        }
     }
 
     /// Renders the `Gradientblock` widget, including optional fill and custom block rendering,
     /// along with titles.
-    pub fn main(
+    ///
+    /// Rendering is safe at any size and never draws outside `area`:
+    /// - an empty area (0 wide or 0 tall) draws nothing
+    /// - a single row draws only the top border, so 1x1 is a single
+    ///   corner and Nx1 a single line
+    /// - two rows draw the top and bottom borders without the sides
+    /// - from three rows up the whole border is drawn
+    ///
+    /// Titles and fill wider than their room follow the block's
+    /// [`enums::Overflow`], and areas reaching past the buffer are
+    /// clipped to it. See [`Self::min_size`] for areas smaller than the
+    /// border and titles need.
+    pub fn main(&self, area: R, buf: &mut buffer::Buffer) {
+        self.main_with_state(
+            area,
+            buf,
+            &structs::state::GradientBlockState::default(),
+        );
+    }
+
+    /// Renders the widget like [`Self::main`], with the animations at
+    /// the point `state` is at.
+    pub fn main_with_state(
+        &self,
+        area: R,
+        buf: &mut buffer::Buffer,
+        state: &structs::state::GradientBlockState,
+    ) {
+        self.render_with(area, buf, state, &mut Default::default());
+    }
+
+    /// Renders the widget like [`Self::main_with_state`], reusing the
+    /// scratch buffers kept in `state` from the previous frame
+    pub fn render_stateful(
+        &self,
+        area: R,
+        buf: &mut buffer::Buffer,
+        state: &mut structs::state::GradientBlockState,
+    ) {
+        let mut scratch = std::mem::take(&mut state.scratch);
+        self.render_with(area, buf, state, &mut scratch);
+        state.scratch = scratch;
+    }
+
+    /// The smallest area the block can be drawn in without its border
+    /// and titles running into each other: room for the sides, the
+    /// margins and the padding and caps of the titles between the
+    /// corners. Titles wider than their room are fitted to it with the
+    /// block's [`enums::Overflow`], so their text doesn't count.
+    pub fn min_size(&self) -> crate::layout::Size {
+        use enums::Side;
+        let segs = &self.border_segments;
+        let marg = segs.top.area_margin;
+        let m = self.side_margins;
+        let glow = self
+            .glow
+            .filter(|g| g.inset)
+            .map_or(0, |g| g.rings() * 2);
+        let caps = self
+            .title_background
+            .as_ref()
+            .map_or(0, |bg| bg.caps_width());
+        let titles = self
+            .titles
+            .iter()
+            .map(|(_, pos)| {
+                let p = match pos {
+                    Position::Top => segs.top.seg.padding,
+                    Position::Bottom => segs.bottom.seg.padding,
+                };
+                caps.saturating_add(p.left + p.right + 2)
+            })
+            .max()
+            .unwrap_or(0);
+        let sides =
+            segs.thickness(Side::Left) + segs.thickness(Side::Right);
+        let width = sides
+            .max(titles)
+            .saturating_add(marg.horizontal * 2 + m.left + m.right)
+            .saturating_add(glow);
+        let height = (segs.thickness(Side::Top)
+            + segs.thickness(Side::Bottom))
+        .saturating_add(marg.vertical * 2 + m.top + m.bottom)
+        .saturating_add(glow);
+        crate::layout::Size::new(width, height)
+    }
+
+    /// Renders the block, following the min size policy when `area` is
+    /// smaller than [`Self::min_size`]. Smaller blocks are drawn at
+    /// their minimum size in a scratch buffer, holding what's already
+    /// in `buf` so blending still sees it, and the cells the policy
+    /// keeps are copied back.
+    fn render_with(
         &self,
-        area: &prelude::Rect,
+        area: R,
         buf: &mut buffer::Buffer,
+        state: &structs::state::GradientBlockState,
+        scratch: &mut structs::state::RenderScratch,
     ) {
-        let area_rc = Rc::new(*area);
+        // only problems that break the border itself are fatal, the
+        // others are often intended and only warned about
+        #[cfg(debug_assertions)]
+        for err in self.problems() {
+            use crate::validate::ValidationError as V;
+            match err {
+                V::ZeroWidthSymbol { .. }
+                | V::DegenerateGradient { .. } => {
+                    panic!("invalid GradientBlock: {err}")
+                }
+                #[cfg(feature = "tracing")]
+                _ => tracing::warn!("GradientBlock: {err}"),
+                #[cfg(not(feature = "tracing"))]
+                _ => {}
+            }
+        }
+        let area = area.intersection(buf.area);
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::trace_span!("GradientBlock::render", ?area)
+                .entered();
+        let min = self.min_size();
+        if area.width >= min.width && area.height >= min.height {
+            return self.render_sized(area, buf, state, scratch);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            ?area,
+            ?min,
+            policy = ?self.min_size_policy,
+            "area smaller than the block's minimum size"
+        );
+        if area.is_empty()
+            || self.min_size_policy == enums::MinSizePolicy::Hide
+        {
+            return;
+        }
+        let full = R::new(
+            area.x,
+            area.y,
+            area.width.max(min.width),
+            area.height.max(min.height),
+        );
+        let mut tmp = buffer::Buffer::empty(full);
+        for pos in area.positions() {
+            tmp[pos] = buf[pos].clone();
+        }
+        self.render_sized(full, &mut tmp, state, scratch);
+        if self.min_size_policy == enums::MinSizePolicy::Collapse {
+            let top = self
+                .block_area(full)
+                .inner(self.border_segments.top.area_margin)
+                .y;
+            for x in area.left()..area.right() {
+                buf[(x, area.y)] = tmp[(x, top)].clone();
+            }
+        } else {
+            for pos in area.positions() {
+                buf[pos] = tmp[pos].clone();
+            }
+        }
+    }
+
+    fn render_sized(
+        &self,
+        area: R,
+        buf: &mut buffer::Buffer,
+        state: &structs::state::GradientBlockState,
+        scratch: &mut structs::state::RenderScratch,
+    ) {
+        let area = self.block_area(area);
+        let inner = self.inner_of_block_area(area);
+        match self.clear {
+            enums::ClearMode::None => {}
+            enums::ClearMode::Area => {
+                widgets::Clear.render(area, buf)
+            }
+            enums::ClearMode::Inner => {
+                widgets::Clear.render(inner, buf)
+            }
+        }
+        let mono = self.monochrome
+            || self.color_depth == enums::ColorDepth::NoColor
+            || crate::degrade::no_color();
+        // the shadow and an outside glow are drawn around `area`, so
+        // the color passes look at everything the block paints
+        let degraded = (mono
+            || self.color_depth != enums::ColorDepth::TrueColor)
+            .then(|| {
+                crate::blend::snapshot(
+                    self.painted_area(area, buf.area),
+                    buf,
+                )
+            });
+        if let Some(shadow) = &self.shadow {
+            shadow.render(area, buf);
+        }
+        let under = (self.compositing
+            != enums::Compositing::Overwrite
+            || self.blend_mode != enums::BlendMode::Normal)
+            .then(|| crate::blend::snapshot(area, buf));
+        buf.set_style(area, crate::style::Styled::style(self));
+        if let Some(effect) = &self.effect {
+            effect.render(inner, state.elapsed, buf);
+        }
+        if let Some(pattern) = &self.pattern {
+            pattern.render(inner, buf);
+        }
         if !self.fill.spans.is_empty() {
-            self.render_fill(Rc::clone(&area_rc), buf);
+            self.render_fill(area, buf);
+        }
+        let reveal =
+            self.reveal.filter(|r| !r.is_done(state.elapsed));
+        let before_block =
+            reveal.map(|_| crate::blend::snapshot(area, buf));
+        self.render_block(area, &mut scratch.side, buf);
+        self.render_border_style(area, buf);
+        self.render_scope(area, buf);
+        self.render_shader(area, buf);
+        if let Some(pulse) = &self.pulse {
+            pulse.render(
+                self.cells(area).map(|(pos, _)| pos),
+                state.elapsed,
+                buf,
+            );
+        }
+        if let Some(shimmer) = &self.shimmer {
+            shimmer.render(
+                area,
+                self.cells(area).map(|(pos, _)| pos),
+                state.elapsed,
+                buf,
+            );
+        }
+        if let Some(sparkle) = &self.sparkle {
+            sparkle.render(
+                self.cells(area).map(|(pos, _)| pos),
+                state.elapsed,
+                buf,
+            );
+        }
+        if let Some(progress) = &self.progress {
+            progress.render(
+                area,
+                self.cells(area).map(|(pos, _)| pos),
+                buf,
+            );
+        }
+        if let Some(gauge) = &self.gauge {
+            gauge.render(
+                area.inner(self.border_segments.top.area_margin),
+                self.cells(area).map(|(pos, _)| pos),
+                buf,
+            );
+        }
+        if let Some(scrollbar) = &self.scrollbar
+            && self.border_segments.right.should_be_rendered
+        {
+            scrollbar.render(
+                &self.border_segments.right,
+                area,
+                state.scroll,
+                inner.height,
+                buf,
+            );
+        }
+        if let Some(spinner) = &self.spinner {
+            spinner.render(
+                area,
+                self.border_segments.top.area_margin,
+                state.frame,
+                buf,
+            );
+        }
+        self.render_corner_colors(area, buf);
+        if self.high_contrast {
+            crate::contrast::apply_high_contrast(
+                self.cells(area).map(|(pos, _)| pos),
+                buf,
+            );
+        }
+        if let (Some(reveal), Some(before)) = (reveal, &before_block)
+        {
+            reveal.hide_border(
+                area,
+                self.cells(area).map(|(pos, _)| pos),
+                before,
+                state.elapsed,
+                buf,
+            );
+        }
+        if let Some(glow) =
+            self.glow.filter(|_| !mono && !self.high_contrast)
+        {
+            glow.render(area, buf);
+        }
+        match reveal {
+            Some(reveal) => {
+                let before = crate::blend::snapshot(area, buf);
+                self.render_titles(area, buf);
+                reveal.fade_in(
+                    reveal.title_alpha(state.elapsed),
+                    &before,
+                    buf,
+                );
+            }
+            None => self.render_titles(area, buf),
+        }
+        if let Some(label) = &self.value_label {
+            label.render(
+                self.border_segments.get(label.side),
+                area,
+                state.value.map_or(label.value, |v| v.0),
+                buf,
+            );
+        }
+        for badge in &self.badges {
+            badge.render(
+                area,
+                self.border_segments.top.area_margin,
+                buf,
+            );
+        }
+        if let Some(under) = under {
+            if self.compositing == enums::Compositing::PreserveContent
+            {
+                crate::blend::preserve_content(&under, buf);
+            }
+            if !mono {
+                crate::blend::apply(self.blend_mode, &under, buf);
+            }
         }
-        self.render_block(Rc::clone(&area_rc), buf);
-        self.render_titles(Rc::clone(&area_rc), buf);
-        if let Some(bg) = self.bg {
-            buf.set_style(*(Rc::clone(&area_rc)), bg);
+        if let Some(degraded) = degraded {
+            match mono {
+                true => crate::degrade::apply_monochrome(
+                    &degraded,
+                    buf,
+                    self.intensity_modifiers,
+                ),
+                false => crate::degrade::apply_color_depth(
+                    self.color_depth,
+                    &degraded,
+                    buf,
+                ),
+            }
         }
     }
 }
 
+/// Makes the block work with ratatui's `Stylize` shorthands
+/// (`.on_blue()`, `.bold()`, ...), which set its base style.
+impl<'a> crate::style::Styled for GradientBlock<'a> {
+    type Item = GradientBlock<'a>;
+    /// The base style of the block area, including the `bg` color
+    fn style(&self) -> Style {
+        match self.bg {
+            Some(bg) => self.style.bg(bg),
+            None => self.style,
+        }
+    }
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style.into())
+    }
+}
 impl widgets::Widget for GradientBlock<'_> {
     /// Renders the `Gradientblock` widget using the `main` function.
     fn render(self, area: prelude::Rect, buf: &mut buffer::Buffer) {
@@ -192,6 +1087,40 @@ impl widgets::Widget for GradientBlock<'_> {
 }
 impl widgets::WidgetRef for GradientBlock<'_> {
     fn render_ref(&self, area: R, buf: &mut buffer::Buffer) {
-        self.main(&area, buf);
+        self.main(area, buf);
+    }
+}
+impl widgets::StatefulWidget for GradientBlock<'_> {
+    type State = structs::state::GradientBlockState;
+    fn render(
+        self,
+        area: R,
+        buf: &mut buffer::Buffer,
+        state: &mut Self::State,
+    ) {
+        self.render_stateful(area, buf, state);
+    }
+}
+/// Lets a block built once be rendered with a state every frame
+impl widgets::StatefulWidget for &GradientBlock<'_> {
+    type State = structs::state::GradientBlockState;
+    fn render(
+        self,
+        area: R,
+        buf: &mut buffer::Buffer,
+        state: &mut Self::State,
+    ) {
+        self.render_stateful(area, buf, state);
+    }
+}
+impl widgets::StatefulWidgetRef for GradientBlock<'_> {
+    type State = structs::state::GradientBlockState;
+    fn render_ref(
+        &self,
+        area: R,
+        buf: &mut buffer::Buffer,
+        state: &mut Self::State,
+    ) {
+        self.render_stateful(area, buf, state);
     }
 }
@@ -0,0 +1,87 @@
+use crate::{
+    buffer::Buffer,
+    gradient_block::GradientBlock,
+    layout::Rect,
+    widgets::{StatefulWidget, StatefulWidgetRef, Widget, WidgetRef},
+};
+/// A widget framed by a [`GradientBlock`]: the block is rendered first
+/// and the widget in its [`GradientBlock::inner`] area, so `List`,
+/// `Table`, `Chart`, ... get a gradient border in a single render call.
+///
+/// Stateful widgets keep their own state (a `ListState`, ...); the
+/// block is rendered without one, so animations that need a
+/// `GradientBlockState` stay at their first frame.
+/// # Example
+/// ```
+/// # use ratatui::widgets::{List, ListState};
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # let items = ["one", "two"];
+/// # let mut list_state = ListState::default();
+/// # let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(20, 5)).unwrap();
+/// # terminal.draw(|frame| {
+/// # let area = frame.area();
+/// let list = List::new(items).highlight_symbol("> ");
+/// frame.render_stateful_widget(
+///     GradientBlock::new().title_top("Items").wrap(list),
+///     area,
+///     &mut list_state,
+/// );
+/// # }).unwrap();
+/// ```
+pub struct GradientFrame<'a, W> {
+    pub block: GradientBlock<'a>,
+    pub widget: W,
+}
+impl<'a, W> GradientFrame<'a, W> {
+    pub fn new(block: GradientBlock<'a>, widget: W) -> Self {
+        Self { block, widget }
+    }
+}
+impl<'a> GradientBlock<'a> {
+    /// Frames `widget` with the block, see [`GradientFrame`]
+    pub fn wrap<W>(self, widget: W) -> GradientFrame<'a, W> {
+        GradientFrame::new(self, widget)
+    }
+}
+impl<W: Widget> Widget for GradientFrame<'_, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.block.inner(area.intersection(buf.area));
+        WidgetRef::render_ref(&self.block, area, buf);
+        self.widget.render(inner, buf);
+    }
+}
+impl<W: WidgetRef> WidgetRef for GradientFrame<'_, W> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let inner = self.block.inner(area.intersection(buf.area));
+        WidgetRef::render_ref(&self.block, area, buf);
+        self.widget.render_ref(inner, buf);
+    }
+}
+impl<W: StatefulWidget> StatefulWidget for GradientFrame<'_, W> {
+    type State = W::State;
+    fn render(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut W::State,
+    ) {
+        let inner = self.block.inner(area.intersection(buf.area));
+        WidgetRef::render_ref(&self.block, area, buf);
+        self.widget.render(inner, buf, state);
+    }
+}
+impl<W: StatefulWidgetRef> StatefulWidgetRef
+    for GradientFrame<'_, W>
+{
+    type State = W::State;
+    fn render_ref(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut W::State,
+    ) {
+        let inner = self.block.inner(area.intersection(buf.area));
+        WidgetRef::render_ref(&self.block, area, buf);
+        self.widget.render_ref(inner, buf, state);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    buffer::Buffer,
+    enums::TextGradient,
+    gradient_block::GradientBlock,
+    layout::{Alignment, Position, Rect},
+    text::Text,
+    types::G,
+    widgets::{Paragraph, Widget, WidgetRef, Wrap},
+};
+/// Text colored with a gradient after it is wrapped, so every visual
+/// line (or the text as a whole, see [`TextGradient`]) gets the full
+/// gradient, unlike [`GradientBlock::fill_gradient`] which colors a
+/// single unwrapped line.
+///
+/// Only the foreground of the text is recolored; backgrounds and
+/// modifiers of the spans are kept. With a [`Self::block`] the text is
+/// drawn in the block's inner area.
+/// # Example
+/// ```
+/// # use ratatui::widgets::Wrap;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::gradient_paragraph::GradientParagraph;
+/// # let text = "Gradients all the way down";
+/// let paragraph = GradientParagraph::new(text, Box::new(colorgrad::preset::warm()))
+///     .wrap(Wrap { trim: true })
+///     .block(GradientBlock::new().title_top("Notes"));
+/// ```
+pub struct GradientParagraph<'a> {
+    pub text: Text<'a>,
+    pub gradient: G,
+    pub mode: TextGradient,
+    pub wrap: Option<Wrap>,
+    pub alignment: Alignment,
+    pub scroll: (u16, u16),
+    pub block: Option<GradientBlock<'a>>,
+}
+impl<'a> GradientParagraph<'a> {
+    pub fn new<T: Into<Text<'a>>>(text: T, gradient: G) -> Self {
+        Self {
+            text: text.into(),
+            gradient,
+            mode: TextGradient::PerLine,
+            wrap: None,
+            alignment: Alignment::Left,
+            scroll: (0, 0),
+            block: None,
+        }
+    }
+    /// Sets how the gradient is laid over the text
+    pub fn mode(mut self, mode: TextGradient) -> Self {
+        self.mode = mode;
+        self
+    }
+    /// Wraps the text, like `Paragraph::wrap`
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+    /// Scrolls the text by `(rows, columns)`, like `Paragraph::scroll`
+    pub fn scroll(mut self, offset: (u16, u16)) -> Self {
+        self.scroll = offset;
+        self
+    }
+    /// Draws the text inside `block`
+    pub fn block(mut self, block: GradientBlock<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+    fn paragraph(&self) -> Paragraph<'a> {
+        let paragraph = Paragraph::new(self.text.clone())
+            .alignment(self.alignment)
+            .scroll(self.scroll);
+        match self.wrap {
+            Some(wrap) => paragraph.wrap(wrap),
+            None => paragraph,
+        }
+    }
+    /// The cells the text covers in `area`, row by row, found by
+    /// drawing it on an empty buffer first
+    fn text_rows(&self, area: Rect) -> Vec<Vec<Position>> {
+        let mut scratch = Buffer::empty(area);
+        self.paragraph().render(area, &mut scratch);
+        area.rows()
+            .map(|row| {
+                row.positions()
+                    .filter(|pos| scratch[*pos].symbol() != " ")
+                    .collect::<Vec<_>>()
+            })
+            .filter(|row| !row.is_empty())
+            .collect()
+    }
+}
+/// `t` of item `i` out of `n`, evenly spread over 0.0..=1.0
+fn spread(i: usize, n: usize) -> f32 {
+    match n {
+        0 | 1 => 0.0,
+        n => i as f32 / (n - 1) as f32,
+    }
+}
+impl WidgetRef for GradientParagraph<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let area = match &self.block {
+            Some(block) => {
+                block.render_ref(area, buf);
+                block.inner(area)
+            }
+            None => area,
+        };
+        if area.is_empty() {
+            return;
+        }
+        let rows = self.text_rows(area);
+        self.paragraph().render(area, buf);
+        let total = rows.iter().map(Vec::len).sum();
+        let mut i = 0;
+        for row in rows {
+            // the first and last covered cells take the ends of the
+            // gradient, so the spaces in between still count
+            let (first, last) = (row[0].x, row[row.len() - 1].x);
+            for pos in row {
+                let t = match self.mode {
+                    TextGradient::PerLine => spread(
+                        (pos.x - first) as usize,
+                        (last - first) as usize + 1,
+                    ),
+                    TextGradient::Text => spread(i, total),
+                };
+                i += 1;
+                buf[pos].fg =
+                    crate::to_ratatui_color!(self.gradient.at(t));
+            }
+        }
+    }
+}
+impl Widget for GradientParagraph<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
@@ -0,0 +1,119 @@
+//! Text colored along a gradient, for titles, fills and labels,
+//! without tui_rule's `generate_gradient_text!`.
+//!
+//! The gradient steps once per grapheme, so emoji and combined
+//! characters get one color each and are never split. See
+//! [`crate::structs::title::gradient_spans`] to grade only some of the
+//! spans of a line, and [`crate::gradient_paragraph::GradientParagraph`]
+//! to color text after it is wrapped.
+//! # Example
+//! ```
+//! # use ratatui::text::Line;
+//! # use tui_gradient_block::gradient_block::GradientBlock;
+//! # use tui_gradient_block::gradient_text::gradient_line;
+//! # let theme = tui_gradient_block::theme_presets::cool::t_misty_blue::theme();
+//! let block = GradientBlock::new()
+//!     .title_top(gradient_line(Line::from("Logs").centered(), &theme.up.top));
+//! ```
+use crate::{
+    style::Style,
+    text::{Line, Span, Text},
+    to_ratatui_color,
+};
+use colorgrad::Gradient;
+/// `text` with every grapheme colored along `gradient`, from its start
+/// at the first grapheme to its end at the last. A `Line` keeps its
+/// alignment and style, and every grapheme the rest of the style of
+/// its span.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_text::gradient_line;
+/// # let theme = tui_gradient_block::theme_presets::cool::t_zombie_dreams::theme();
+/// let title = gradient_line("Zombie Dreams", &theme.right.top).centered();
+/// ```
+pub fn gradient_line<'a>(
+    text: impl Into<Line<'a>>,
+    gradient: &dyn Gradient,
+) -> Line<'a> {
+    let line = text.into();
+    Line {
+        spans: colored(&line.spans, Style::default(), gradient),
+        ..line
+    }
+}
+/// The graphemes of `text` as spans colored along `gradient`, like
+/// [`gradient_line`], for building a line out of several parts. The
+/// style of a `Line` goes onto its spans, as there is no line left to
+/// hold it.
+/// # Example
+/// ```
+/// # use ratatui::text::Span;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::gradient_text::gradient_spans;
+/// # use tui_gradient_block::theme_presets::warm::t_rusty_ruins;
+/// let mut spans = vec![Span::raw("\u{f489} ")];
+/// spans.extend(gradient_spans("cargo build", &t_rusty_ruins::d_to_l()));
+/// let block = GradientBlock::new().title_top(spans);
+/// ```
+pub fn gradient_spans<'a>(
+    text: impl Into<Line<'a>>,
+    gradient: &dyn Gradient,
+) -> Vec<Span<'a>> {
+    let line = text.into();
+    colored(&line.spans, line.style, gradient)
+}
+/// `text` with each line a color of `gradient`, from its start at the
+/// first line to its end at the last, for big ASCII-art headers and
+/// multi-line fills. Empty lines take their color like the others, so
+/// the gradient follows the rows. The color goes on the lines, so spans
+/// with a foreground of their own keep it.
+/// # Example
+/// ```
+/// # use ratatui::widgets::Paragraph;
+/// # use tui_gradient_block::gradient_text::vertical_gradient;
+/// # use tui_gradient_block::theme_presets::cool::t_misty_blue;
+/// # let block = tui_gradient_block::gradient_block::GradientBlock::new();
+/// # let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(20, 5)).unwrap();
+/// # terminal.draw(|frame| {
+/// # let area = frame.area();
+/// let header = vertical_gradient(
+///     " _  _ \n| || |\n|_||_|",
+///     &t_misty_blue::d_to_l(),
+/// );
+/// frame.render_widget(Paragraph::new(header), block.inner(area));
+/// # }).unwrap();
+/// ```
+pub fn vertical_gradient<'a>(
+    text: impl Into<Text<'a>>,
+    gradient: &dyn Gradient,
+) -> Text<'a> {
+    let mut text = text.into();
+    let last = text.lines.len().saturating_sub(1).max(1) as f32;
+    let (dmin, dmax) = gradient.domain();
+    for (i, line) in text.lines.iter_mut().enumerate() {
+        let t = dmin + (dmax - dmin) * i as f32 / last;
+        line.style =
+            line.style.fg(to_ratatui_color!(gradient.at(t).clamp()));
+    }
+    text
+}
+/// One span per grapheme of `spans`, each on top of `base` and its own
+/// span's style and colored along `gradient`
+fn colored(
+    spans: &[Span],
+    base: Style,
+    gradient: &dyn Gradient,
+) -> Vec<Span<'static>> {
+    let graphemes =
+        || spans.iter().flat_map(|span| span.styled_graphemes(base));
+    let last = graphemes().count().saturating_sub(1).max(1) as f32;
+    let (dmin, dmax) = gradient.domain();
+    graphemes()
+        .enumerate()
+        .map(|(i, g)| {
+            let t = dmin + (dmax - dmin) * i as f32 / last;
+            let color = to_ratatui_color!(gradient.at(t).clamp());
+            Span::styled(g.symbol.to_string(), g.style.fg(color))
+        })
+        .collect()
+}
@@ -0,0 +1,154 @@
+//! Helpers for building gradients out of other gradients, so themes
+//! don't have to spell out every mirrored or joined list of colors.
+use crate::{enums::GradientKind, types::G};
+/// The color spaces [`build_in`] can blend in, re-exported so
+/// `gradient!(...; mode = Oklab)` works without depending on colorgrad
+pub use colorgrad::BlendMode;
+use colorgrad::{
+    Color, Gradient, GradientBuilder, GradientBuilderError,
+};
+/// A boxed gradient of the given kind through `colors`, anything that
+/// converts into a colorgrad `Color` such as `(u8, u8, u8)` tuples. The
+/// function behind [`crate::gradient!`], returning the error instead of
+/// panicking; a gradient needs at least one color.
+/// # Example
+/// ```
+/// # use tui_gradient_block::enums::GradientKind;
+/// # use tui_gradient_block::gradient_utils::build;
+/// let g = build([(255, 0, 0), (0, 0, 255)], GradientKind::CatmullRom)?;
+/// # Ok::<(), colorgrad::GradientBuilderError>(())
+/// ```
+pub fn build(
+    colors: impl IntoIterator<Item = impl Into<Color>>,
+    kind: GradientKind,
+) -> Result<G, GradientBuilderError> {
+    build_in(colors, kind, BlendMode::Rgb)
+}
+/// [`build`] blending the colors in the color space `mode` instead of
+/// plain rgb, the version behind `gradient!(...; mode = Oklab)`
+/// # Example
+/// ```
+/// # use tui_gradient_block::enums::GradientKind;
+/// # use tui_gradient_block::gradient_utils::{build_in, BlendMode};
+/// // no muddy gray halfway between red and cyan
+/// let g = build_in(
+///     [(255, 0, 0), (0, 255, 255)],
+///     GradientKind::Linear,
+///     BlendMode::Oklab,
+/// )?;
+/// # Ok::<(), colorgrad::GradientBuilderError>(())
+/// ```
+pub fn build_in(
+    colors: impl IntoIterator<Item = impl Into<Color>>,
+    kind: GradientKind,
+    mode: BlendMode,
+) -> Result<G, GradientBuilderError> {
+    let colors =
+        colors.into_iter().map(Into::into).collect::<Vec<_>>();
+    if colors.is_empty() {
+        return Err(GradientBuilderError::InvalidStops);
+    }
+    let mut builder = GradientBuilder::new();
+    builder.colors(&colors).mode(mode);
+    Ok(match kind {
+        GradientKind::Linear => {
+            Box::new(builder.build::<colorgrad::LinearGradient>()?)
+        }
+        GradientKind::Basis => {
+            Box::new(builder.build::<colorgrad::BasisGradient>()?)
+        }
+        GradientKind::CatmullRom => Box::new(
+            builder.build::<colorgrad::CatmullRomGradient>()?,
+        ),
+    })
+}
+/// `n` colors evenly spaced over the domain of `gradient`, from its
+/// start to its end. A single color is its start.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_utils::resample;
+/// # use tui_gradient_block::theme_presets::cool::t_misty_blue;
+/// // the stops of a 5 step palette
+/// let stops = resample(&t_misty_blue::d_to_l(), 5);
+/// ```
+pub fn resample(gradient: &G, n: usize) -> Vec<Color> {
+    let (dmin, dmax) = gradient.domain();
+    let last = n.saturating_sub(1).max(1) as f32;
+    (0..n)
+        .map(|i| gradient.at(dmin + (dmax - dmin) * i as f32 / last))
+        .collect()
+}
+/// `n` colors evenly spaced along a linear gradient through `colors`,
+/// keeping their order; with as many colors as there are `colors`, they
+/// come back unchanged
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_utils::palette;
+/// // the five colors a preset is built from
+/// let [c1, c2, c3, c4, c5] = palette([(40, 30, 90), (230, 190, 255)], 5).try_into().unwrap();
+/// ```
+pub fn palette(
+    colors: impl IntoIterator<Item = impl Into<Color>>,
+    n: usize,
+) -> Vec<Color> {
+    match build(colors, GradientKind::Linear) {
+        Ok(g) => resample(&g, n),
+        Err(_) => Vec::new(),
+    }
+}
+/// `gradient` running from its end to its start
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_utils::reverse;
+/// # use tui_gradient_block::types::G;
+/// # use tui_gradient_block::theme_presets::cool::t_misty_blue::d_to_l;
+/// pub fn l_to_d() -> G {
+///     reverse(d_to_l())
+/// }
+/// ```
+pub fn reverse(gradient: G) -> G {
+    Box::new(ReversedGradient { inner: gradient })
+}
+/// `a` followed by `b`, each taking up half of the domain 0.0..=1.0
+/// and sampled over its own domain
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_utils::concat;
+/// # use tui_gradient_block::gradient_utils::reverse;
+/// # use tui_gradient_block::theme_presets::cool::t_misty_blue::d_to_l;
+/// // dark to light and back
+/// let there_and_back = concat(d_to_l(), reverse(d_to_l()));
+/// ```
+pub fn concat(a: G, b: G) -> G {
+    Box::new(ConcatGradient { a, b })
+}
+/// See [`reverse`]
+#[derive(Clone)]
+pub struct ReversedGradient {
+    pub inner: G,
+}
+impl Gradient for ReversedGradient {
+    fn at(&self, t: f32) -> Color {
+        let (dmin, dmax) = self.inner.domain();
+        self.inner.at(dmax - (t - dmin))
+    }
+    fn domain(&self) -> (f32, f32) {
+        self.inner.domain()
+    }
+}
+/// See [`concat`]
+#[derive(Clone)]
+pub struct ConcatGradient {
+    pub a: G,
+    pub b: G,
+}
+impl Gradient for ConcatGradient {
+    fn at(&self, t: f32) -> Color {
+        let (g, t) = match t < 0.5 {
+            true => (&self.a, t * 2.0),
+            false => (&self.b, t * 2.0 - 1.0),
+        };
+        let (dmin, dmax) = g.domain();
+        g.at(dmin + t * (dmax - dmin))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    gradient_block::GradientBlock,
+    layout::{Constraint, Layout, Rect},
+    structs::gradient::{GradientTheme, GradientVariation},
+};
+/// The variation of `theme` suited to the block at `row`, `col` of a
+/// `rows` x `cols` grid, so the gradients flow outwards:
+/// - corners use the corner variations (`top_left`, ...)
+/// - the other blocks of the outer rows and columns use `up`, `down`,
+///   `left` and `right`
+/// - a single row uses `left`, `horizontal` and `right`, a single
+///   column `up`, `vertical` and `down`
+/// - inner blocks, and a grid of a single block, use `misc1`
+pub fn variation_for(
+    theme: &GradientTheme,
+    row: usize,
+    col: usize,
+    rows: usize,
+    cols: usize,
+) -> &GradientVariation {
+    let (top, bottom) = (row == 0, row + 1 >= rows);
+    let (left, right) = (col == 0, col + 1 >= cols);
+    match (rows, cols) {
+        (1, 1) => &theme.misc1,
+        (1, _) if left => &theme.left,
+        (1, _) if right => &theme.right,
+        (1, _) => &theme.horizontal,
+        (_, 1) if top => &theme.up,
+        (_, 1) if bottom => &theme.down,
+        (_, 1) => &theme.vertical,
+        _ => match (top, bottom, left, right) {
+            (true, _, true, _) => &theme.top_left,
+            (true, _, _, true) => &theme.top_right,
+            (_, true, true, _) => &theme.bottom_left,
+            (_, true, _, true) => &theme.bottom_right,
+            (true, ..) => &theme.up,
+            (_, true, ..) => &theme.down,
+            (.., true, _) => &theme.left,
+            (.., true) => &theme.right,
+            _ => &theme.misc1,
+        },
+    }
+}
+/// Splits `area` into a grid of `rows` x `cols` and returns a block for
+/// every cell, row by row, themed with [`variation_for`].
+/// # Example
+/// ```
+/// # use ratatui::layout::Constraint;
+/// # use tui_gradient_block::grid::grid;
+/// # use tui_gradient_block::theme_presets::cool::t_misty_blue;
+/// # let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(30, 10)).unwrap();
+/// # terminal.draw(|f| {
+/// let theme = t_misty_blue::full();
+/// let tiles = grid(
+///     f.area(),
+///     &[Constraint::Fill(1); 2],
+///     &[Constraint::Fill(1); 3],
+///     &theme,
+/// );
+/// for (area, block) in tiles {
+///     f.render_widget(block, area);
+/// }
+/// # }).unwrap();
+/// ```
+pub fn grid<'a>(
+    area: Rect,
+    rows: &[Constraint],
+    cols: &[Constraint],
+    theme: &GradientTheme,
+) -> Vec<(Rect, GradientBlock<'a>)> {
+    let row_areas =
+        Layout::vertical(rows.iter().copied()).split(area);
+    let mut blocks = Vec::with_capacity(rows.len() * cols.len());
+    for (r, row) in row_areas.iter().enumerate() {
+        let cells =
+            Layout::horizontal(cols.iter().copied()).split(*row);
+        for (c, cell) in cells.iter().enumerate() {
+            let variation =
+                variation_for(theme, r, c, rows.len(), cols.len());
+            blocks.push((
+                *cell,
+                GradientBlock::new().with_gradient(variation.clone()),
+            ));
+        }
+    }
+    blocks
+}
@@ -0,0 +1,101 @@
+//! Themes made from the colors of an image, behind the `image` feature,
+//! for interfaces that follow the album art or the wallpaper.
+//! # Example
+//! ```no_run
+//! # use tui_gradient_block::{gradient_block::GradientBlock, structs::gradient::GradientTheme};
+//! let theme = GradientTheme::from_image("cover.jpg", 5)?;
+//! let block = GradientBlock::new().with_gradient(theme.up);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use crate::{structs::gradient::GradientTheme, types::E};
+use colorgrad::Color;
+use std::path::Path;
+/// The side the image is scaled down to before its colors are counted
+const SAMPLE_SIZE: u32 = 64;
+/// Rounds of k-means refinement
+const ITERATIONS: usize = 12;
+impl GradientTheme {
+    /// A theme built from the `k` dominant colors of the image at
+    /// `path` (see [`dominant_colors`] and
+    /// [`GradientTheme::from_stops`]). PNG and JPEG images are
+    /// supported.
+    pub fn from_image(
+        path: impl AsRef<Path>,
+        k: usize,
+    ) -> Result<Self, E> {
+        let img = image::open(path)?
+            .thumbnail(SAMPLE_SIZE, SAMPLE_SIZE)
+            .into_rgba8();
+        let pixels = img
+            .pixels()
+            .filter(|p| p[3] >= 128)
+            .map(|p| Color::from_rgba8(p[0], p[1], p[2], 255))
+            .collect::<Vec<_>>();
+        Ok(Self::from_stops(&dominant_colors(&pixels, k)))
+    }
+}
+/// The `k` dominant colors of `pixels`, most common first, found with
+/// k-means clustering in Oklab.
+///
+/// The clusters start at evenly spaced lightness quantiles, so the
+/// result is the same on every run. Fewer than `k` colors are returned
+/// if there are fewer distinct pixels.
+pub fn dominant_colors(pixels: &[Color], k: usize) -> Vec<Color> {
+    let mut points = pixels
+        .iter()
+        .map(|c| {
+            let [l, a, b, _] = c.to_oklaba();
+            [l, a, b]
+        })
+        .collect::<Vec<_>>();
+    points.sort_by(|p, q| p[0].total_cmp(&q[0]));
+    let k = k.min(points.len());
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut centers = (0..k)
+        .map(|i| points[(2 * i + 1) * points.len() / (2 * k)])
+        .collect::<Vec<_>>();
+    centers.dedup();
+    let mut counts = vec![0_usize; centers.len()];
+    for _ in 0..ITERATIONS {
+        let mut sums = vec![[0.0_f32; 3]; centers.len()];
+        counts.fill(0);
+        for p in &points {
+            let i = nearest(&centers, p);
+            counts[i] += 1;
+            for (s, v) in sums[i].iter_mut().zip(p) {
+                *s += v;
+            }
+        }
+        for ((center, sum), &n) in
+            centers.iter_mut().zip(&sums).zip(&counts)
+        {
+            if n > 0 {
+                *center = sum.map(|s| s / n as f32);
+            }
+        }
+    }
+    let mut clusters = centers
+        .into_iter()
+        .zip(counts)
+        .filter(|&(_, n)| n > 0)
+        .collect::<Vec<_>>();
+    clusters.sort_by_key(|&(_, n)| std::cmp::Reverse(n));
+    clusters
+        .into_iter()
+        .map(|([l, a, b], _)| {
+            Color::from_oklaba(l, a, b, 1.0).clamp()
+        })
+        .collect()
+}
+fn nearest(centers: &[[f32; 3]], p: &[f32; 3]) -> usize {
+    let dist = |c: &[f32; 3]| -> f32 {
+        c.iter().zip(p).map(|(c, p)| (c - p) * (c - p)).sum()
+    };
+    (0..centers.len())
+        .min_by(|&a, &b| {
+            dist(&centers[a]).total_cmp(&dist(&centers[b]))
+        })
+        .unwrap_or(0)
+}
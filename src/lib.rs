@@ -1,10 +1,37 @@
+pub mod base16;
+pub mod blend;
 pub mod border_styles;
+#[cfg(feature = "capabilities")]
+pub mod capabilities;
+pub mod clock;
+pub mod color;
+pub mod color_vision;
+pub mod contrast;
+pub mod demo;
+pub mod degrade;
 pub mod enums;
+pub mod export;
+pub mod getter_functions;
 pub mod gradient_block;
+pub mod gradient_frame;
+pub mod gradient_paragraph;
+pub mod gradient_text;
+pub mod gradient_utils;
+pub mod grid;
+#[cfg(feature = "image")]
+pub mod image_palette;
 pub mod macros;
+#[cfg(feature = "palette")]
+pub mod palette_interop;
 pub mod preset;
+#[cfg(feature = "serde")]
+pub mod pywal;
+pub mod renderer;
 pub mod setter_functions;
+pub mod test_utils;
+pub mod theme_manager;
 pub mod types;
+pub mod validate;
 pub mod theme_presets {
     pub mod dark {
         pub mod t_midnight_blurple;
@@ -24,13 +51,136 @@ pub mod theme_presets {
     pub mod misc {
         pub mod t_monochrome;
     }
+    pub mod palettes {
+        pub mod t_catppuccin;
+        pub mod t_dracula;
+        pub mod t_gruvbox;
+        pub mod t_nord;
+    }
+    use crate::structs::{gradient::GradientTheme, title::TitleSet};
+    /// The names and constructors of every built-in theme, in
+    /// alphabetical order, for theme pickers and galleries. The
+    /// constructors are the cached `theme()` of each preset.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::theme_presets;
+    /// for (name, theme) in theme_presets::all() {
+    ///     let block = GradientBlock::new().title_top(name);
+    ///     // ... apply theme()
+    /// }
+    /// ```
+    pub fn all(
+    ) -> impl Iterator<Item = (&'static str, fn() -> GradientTheme)>
+    {
+        [
+            (
+                "catppuccin",
+                palettes::t_catppuccin::theme as fn() -> _,
+            ),
+            ("dracula", palettes::t_dracula::theme),
+            ("gruvbox", palettes::t_gruvbox::theme),
+            ("midnight_blurple", dark::t_midnight_blurple::theme),
+            ("minty_green", cool::t_minty_green::theme),
+            ("misty_blue", cool::t_misty_blue::theme),
+            ("monochrome", misc::t_monochrome::theme),
+            ("nord", palettes::t_nord::theme),
+            ("rusty_ruins", warm::t_rusty_ruins::theme),
+            ("zombie_dreams", cool::t_zombie_dreams::theme),
+        ]
+        .into_iter()
+    }
+    /// The title sets matching the themes of [`all`], by the same names
+    pub fn all_titles(
+    ) -> impl Iterator<Item = (&'static str, fn() -> TitleSet<'static>)>
+    {
+        [
+            (
+                "catppuccin",
+                palettes::t_catppuccin::titles as fn() -> _,
+            ),
+            ("dracula", palettes::t_dracula::titles),
+            ("gruvbox", palettes::t_gruvbox::titles),
+            ("midnight_blurple", dark::t_midnight_blurple::titles),
+            ("minty_green", cool::t_minty_green::titles),
+            ("misty_blue", cool::t_misty_blue::titles),
+            ("monochrome", misc::t_monochrome::titles),
+            ("nord", palettes::t_nord::titles),
+            ("rusty_ruins", warm::t_rusty_ruins::titles),
+            ("zombie_dreams", cool::t_zombie_dreams::titles),
+        ]
+        .into_iter()
+    }
+    /// Looks up a built-in theme by its name in [`all`]
+    pub fn by_name(name: &str) -> Option<GradientTheme> {
+        all().find(|(n, _)| *n == name).map(|(_, theme)| theme())
+    }
+    /// The environment variable read by [`from_env`]
+    pub const THEME_ENV: &str = "TUI_GRADIENT_THEME";
+    /// The theme selected by the `TUI_GRADIENT_THEME` environment
+    /// variable, letting end users override an app's border theme, for
+    /// example to force `monochrome`.
+    ///
+    /// The value is either the name of a built-in theme (case and `-`
+    /// vs `_` don't matter) or, with the `serde` feature, the path of a
+    /// [`GradientDescriptor`](crate::structs::gradient::GradientDescriptor)
+    /// json file used for every side. Unset, empty or invalid values
+    /// give `None`.
+    pub fn from_env() -> Option<GradientTheme> {
+        let value = std::env::var(THEME_ENV).ok()?;
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+        let name = value.to_lowercase().replace('-', "_");
+        if let Some(theme) = by_name(&name) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(name, "theme from {THEME_ENV}");
+            return Some(theme);
+        }
+        #[cfg(feature = "serde")]
+        if let Ok(descriptor) =
+            crate::structs::gradient::GradientDescriptor::from_json(
+                value,
+            )
+        {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(path = value, "theme from {THEME_ENV}");
+            return Some(GradientTheme::uniform(descriptor.build()));
+        }
+        #[cfg(feature = "tracing")]
+        tracing::warn!(value, "{THEME_ENV} is not a theme, ignored");
+        None
+    }
 }
 pub mod structs {
+    pub mod badge;
     pub mod border_segment;
     pub mod border_symbols;
+    pub mod collapse;
+    pub mod effect;
     pub mod flags;
+    pub mod gauge;
+    pub mod glow;
     pub mod gradient;
+    pub mod hints;
+    pub mod indicators;
+    pub mod pattern;
+    pub mod progress;
+    pub mod pulse;
+    pub mod reveal;
+    pub mod ruler;
+    pub mod scrollbar;
+    pub mod shadow;
+    pub mod shimmer;
+    pub mod sparkle;
+    pub mod spinner;
+    pub mod state;
+    pub mod tabs;
+    pub mod ticks;
     pub mod title;
+    pub mod transition;
+    pub mod value_label;
 }
 pub use ratatui::{
     buffer, layout, prelude, style, symbols::border, text, widgets,
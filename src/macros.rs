@@ -3,7 +3,7 @@ macro_rules! get_aligned_position {
     ($area:expr, $alignment:expr, $text_len:expr, $padding_left:expr, $padding_right:expr) => {
         match $alignment {
             Some(prelude::Alignment::Left) => {
-                $area.left().saturating_sub($padding_left + 1)
+                $area.left().saturating_add($padding_left + 1)
             }
             Some(prelude::Alignment::Right) => ($area.right())
                 .saturating_sub($text_len + 1)
@@ -25,6 +25,7 @@ macro_rules! handle_fill {
             .render($area, $buf);
     };
 }
+#[deprecated(note = "use `demo::DemoArgs::from_env`")]
 #[macro_export]
 macro_rules! handle_args {
     () => {{
@@ -76,76 +77,134 @@ macro_rules! to_ratatui_color {
     };
 }
 #[macro_export]
+#[deprecated(note = "use `TitleSet::generate`")]
 macro_rules! gen_titles {
     ($color:expr) => {{
-        use $crate::{
-            structs::title::TitleSet, style::Style, text::Line,
-            to_ratatui_color as to_r_c,
-            widgets::block::Position as P,
-        };
-        let s = Style::new().fg(to_r_c!($color));
-        TitleSet {
-            double_corners_left: (
-                Line::from("Two Corners Left").centered().style(s),
-                P::Top,
-            ),
-            bottom_left: (
-                Line::from("Bottom Left").style(s).centered(),
-                P::Top,
-            ),
-            bottom_right: (
-                Line::from("Bottom Right").centered().style(s),
-                P::Top,
-            ),
-            top_left: (
-                Line::from("Top Left").centered().style(s),
-                P::Top,
-            ),
-            top_right: (
-                Line::from("Top Right").centered().style(s),
-                P::Top,
-            ),
-            horizontal: (
-                Line::from("Horizontal").centered().style(s),
-                P::Top,
-            ),
-            double_corners_right: (
-                Line::from("Two Corners Right")
-                    .centered()
-                    .style(s),
-                P::Top,
-            ),
-            vertical: (
-                Line::from("Vertical").centered().style(s),
-                P::Top,
-            ),
-            left: (Line::from("Left").centered().style(s), P::Top),
-            right: (Line::from("Right").centered().style(s), P::Top),
-            up: (Line::from("Up").centered().style(s), P::Top),
-            down: (Line::from("Down").centered().style(s), P::Top),
-            misc1: (Line::from("Misc 1").centered().style(s), P::Top),
-            misc2: (Line::from("Misc 2").centered().style(s), P::Top),
-        }
+        use $crate::structs::title::{TitleOptions, TitleSet};
+        TitleSet::generate(
+            &[$crate::to_ratatui_color!($color)],
+            TitleOptions::default(),
+        )
     }};
 }
-
 #[macro_export]
 macro_rules! generate_theme_use {
     () => {
         pub use colorgrad::{Color, GradientBuilder};
+        #[cfg(feature = "tui-rule-compat")]
         pub use tui_rule::generate_gradient_text;
         pub use $crate::{
-            gen_titles, gradient_block,
+            color, gradient_block, gradient_utils,
             structs::{
                 gradient::{
                     GradientTheme as GT, GradientVariation as GV,
                 },
-                title::TitleSet,
+                title::{TitleOptions, TitleSet},
             },
+            to_ratatui_color,
             types::G,
         };
     };
 }
+/// Builds a boxed gradient through the given colors: `(u8, u8, u8)`
+/// tuples, colorgrad colors such as the `COLOR_N` of the presets, or
+/// anything else that converts into one. `type` names the
+/// [`GradientKind`](crate::enums::GradientKind), linear by default, and
+/// `mode` the colorgrad `BlendMode` the colors are blended in, rgb by
+/// default.
+///
+/// Panics if the gradient can't be built; see
+/// [`gradient_utils::build_in`](crate::gradient_utils::build_in) for the
+/// version that returns the error.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient;
+/// # use tui_gradient_block::theme_presets::cool::t_misty_blue::{COLOR_1, COLOR_2, COLOR_3};
+/// let g = gradient!((255, 0, 0), (0, 0, 255); type = CatmullRom);
+/// let d_to_l = gradient!(COLOR_1, COLOR_2, COLOR_3);
+/// let perceptual = gradient!(COLOR_1, COLOR_3; mode = Oklab);
+/// let both = gradient!(COLOR_1, COLOR_3; type = Basis, mode = Oklab);
+/// ```
+#[macro_export]
+macro_rules! gradient {
+    ($($color:expr),+ $(,)?) => {
+        $crate::gradient!($($color),+; type = Linear, mode = Rgb)
+    };
+    ($($color:expr),+ $(,)?; type = $kind:ident) => {
+        $crate::gradient!($($color),+; type = $kind, mode = Rgb)
+    };
+    ($($color:expr),+ $(,)?; mode = $mode:ident) => {
+        $crate::gradient!($($color),+; type = Linear, mode = $mode)
+    };
+    ($($color:expr),+ $(,)?; type = $kind:ident, mode = $mode:ident) => {
+        $crate::gradient_utils::build_in(
+            [$($color),+],
+            $crate::enums::GradientKind::$kind,
+            $crate::gradient_utils::BlendMode::$mode,
+        )
+        .expect("gradient! needs colors it can build a gradient from")
+    };
+}
+/// Declares a full [`GradientTheme`](crate::structs::gradient::GradientTheme)
+/// from its base colors, laid out like the presets (see
+/// [`GradientTheme::from_colors`](crate::structs::gradient::GradientTheme::from_colors)).
+///
+/// `colors` are the presets' `COLOR_1` to `COLOR_5`, dark to light;
+/// any other number of colors is resampled to five. `mode` optionally
+/// names the colorgrad `BlendMode` every gradient is blended in, rgb by
+/// default. Variations can then override the gradients of some sides,
+/// each given as the numbers of the colors it goes through, counting
+/// from 1 like `COLOR_N`.
+/// # Example
+/// ```
+/// # use tui_gradient_block::theme;
+/// let theme = theme! {
+///     colors: [(28, 123, 186), (48, 140, 197), (74, 156, 207), (118, 179, 214), (189, 215, 234)],
+///     misc1: { right: [4, 3, 2], left: [5, 3, 5, 2] },
+///     misc2: { bottom: [2] },
+/// };
+/// let perceptual = theme! {
+///     colors: [(255, 0, 0), (0, 255, 255)],
+///     mode: Oklab,
+/// };
+/// ```
+#[macro_export]
+macro_rules! theme {
+    (
+        colors: [$($color:expr),+ $(,)?]
+        $(, $variation:ident: { $($side:ident: [$($i:expr),+ $(,)?]),* $(,)? })*
+        $(,)?
+    ) => {
+        $crate::theme! {
+            colors: [$($color),+],
+            mode: Rgb
+            $(, $variation: { $($side: [$($i),+]),* })*
+        }
+    };
+    (
+        colors: [$($color:expr),+ $(,)?],
+        mode: $mode:ident
+        $(, $variation:ident: { $($side:ident: [$($i:expr),+ $(,)?]),* $(,)? })*
+        $(,)?
+    ) => {{
+        let mode = $crate::gradient_utils::BlendMode::$mode;
+        let colors = $crate::gradient_utils::palette([$($color),+], 5);
+        #[allow(unused_mut)]
+        let mut theme = $crate::structs::gradient::GradientTheme::from_colors_in(
+            colors.clone().try_into().unwrap(),
+            mode,
+        );
+        $($(
+            theme.$variation.$side = $crate::gradient_utils::build_in(
+                [$(colors[$i - 1].clone()),+],
+                $crate::enums::GradientKind::Linear,
+                mode,
+            )
+            .unwrap();
+        )*)*
+        theme
+    }};
+}
 #[macro_export]
 macro_rules! generate_from_json {
     ($path:expr, $returntype:ty) => {{
@@ -161,3 +220,69 @@ macro_rules! generate_to_json {
         serde_json::to_string_pretty(&$val).unwrap()
     };
 }
+/// Renders a block through a `TestBackend` and asserts its symbols
+/// match `expected`, one line per row. Leading and trailing newlines of
+/// `expected` are ignored, so it can be written as a raw block of text.
+/// # Example
+/// ```
+/// # use tui_gradient_block::assert_block_snapshot;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// assert_block_snapshot!(
+///     GradientBlock::new(),
+///     4,
+///     3,
+///     "
+/// ┌──┐
+/// │  │
+/// └──┘"
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_block_snapshot {
+    ($block:expr, $width:expr, $height:expr, $expected:expr) => {{
+        let buf = $crate::test_utils::render(&$block, $width, $height);
+        let actual = $crate::test_utils::buffer_to_string(&buf);
+        let expected: &str = $expected.as_ref();
+        assert_eq!(
+            actual,
+            expected.trim_matches('\n'),
+            "\n{}",
+            $crate::test_utils::buffer_to_annotated(&buf)
+        );
+    }};
+}
+/// Adds the cached accessors of a theme preset, `theme()` and
+/// `title_set()`, built from its `full()` and `titles()`.
+///
+/// Gradients are boxed without `Send`/`Sync` bounds, so the theme is
+/// cached per thread and cloned out; the titles are plain data and are
+/// shared through a `OnceLock`. With the `tracing` feature both report
+/// cache hits and misses as trace events.
+#[macro_export]
+macro_rules! cached_preset {
+    () => {
+        /// The full theme, built once per thread. Cheap enough to fetch
+        /// every frame, unlike [`full`] which rebuilds every gradient.
+        pub fn theme() -> $crate::structs::gradient::GradientTheme {
+            thread_local! {
+                static THEME: std::cell::OnceCell<
+                    $crate::structs::gradient::GradientTheme,
+                > = const { std::cell::OnceCell::new() };
+            }
+            THEME.with(|theme| {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(hit = theme.get().is_some(), "theme cache");
+                theme.get_or_init(full).clone()
+            })
+        }
+        /// The titles of the theme, built once
+        pub fn title_set() -> &'static $crate::structs::title::TitleSet<'static> {
+            static TITLES: std::sync::OnceLock<
+                $crate::structs::title::TitleSet<'static>,
+            > = std::sync::OnceLock::new();
+            #[cfg(feature = "tracing")]
+            tracing::trace!(hit = TITLES.get().is_some(), "title cache");
+            TITLES.get_or_init(titles)
+        }
+    };
+}
@@ -0,0 +1,144 @@
+//! Interop with the [`palette`] crate, behind the `palette` feature.
+//!
+//! Colors computed with `palette` can be used as gradient stops
+//! directly, and whole themes can be shifted with palette's color math.
+//! # Example
+//! ```
+//! use palette::{IntoColor, Lch, Srgb};
+//! # use tui_gradient_block::structs::gradient::GradientDescriptor;
+//! # use tui_gradient_block::theme_presets::dark::t_midnight_blurple;
+//! let g = GradientDescriptor::from_palette([
+//!     Srgb::new(0.2, 0.7, 0.8),
+//!     Lch::new(50.0, 80.0, 300.0).into_color(),
+//! ]);
+//! let theme = t_midnight_blurple::full().hue_rotate(40.0);
+//! ```
+use crate::{
+    structs::gradient::{
+        GradientDescriptor, GradientTheme, GradientVariation,
+    },
+    types::G,
+};
+use colorgrad::Color;
+use palette::{
+    Darken, FromColor, IntoColor, Lch, Lighten, Saturate, ShiftHue,
+    Srgb,
+};
+/// A color transform applied in the Lch color space, so lightening and
+/// rotating keep the perceived hue and lightness where they should be
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorTransform {
+    /// Rotates the hue by this many degrees
+    HueRotate(f32),
+    /// Lightens by this fraction of the remaining range, 0.0..=1.0
+    Lighten(f32),
+    /// Darkens by this fraction of the remaining range, 0.0..=1.0
+    Darken(f32),
+    /// Saturates by this fraction of the remaining range, 0.0..=1.0.
+    /// Negative values desaturate.
+    Saturate(f32),
+}
+impl ColorTransform {
+    pub fn apply(&self, c: Color) -> Color {
+        let lch = Lch::from_color(Srgb::new(c.r, c.g, c.b));
+        let lch = match *self {
+            Self::HueRotate(deg) => lch.shift_hue(deg),
+            Self::Lighten(amount) => lch.lighten(amount),
+            Self::Darken(amount) => lch.darken(amount),
+            Self::Saturate(amount) if amount < 0.0 => {
+                palette::Desaturate::desaturate(lch, -amount)
+            }
+            Self::Saturate(amount) => lch.saturate(amount),
+        };
+        let rgb: Srgb = lch.into_color();
+        Color::new(
+            rgb.red.clamp(0.0, 1.0),
+            rgb.green.clamp(0.0, 1.0),
+            rgb.blue.clamp(0.0, 1.0),
+            c.a,
+        )
+    }
+}
+/// A gradient with a [`ColorTransform`] applied to every color
+#[derive(Clone)]
+pub struct TransformedGradient {
+    pub inner: G,
+    pub transform: ColorTransform,
+}
+impl TransformedGradient {
+    pub fn new(inner: G, transform: ColorTransform) -> Self {
+        Self { inner, transform }
+    }
+}
+impl colorgrad::Gradient for TransformedGradient {
+    fn at(&self, t: f32) -> Color {
+        self.transform.apply(self.inner.at(t))
+    }
+    fn domain(&self) -> (f32, f32) {
+        self.inner.domain()
+    }
+}
+/// Converts any palette color to a colorgrad color
+pub fn from_palette<C: IntoColor<Srgb>>(c: C) -> Color {
+    let rgb: Srgb = c.into_color();
+    Color::new(
+        rgb.red.clamp(0.0, 1.0),
+        rgb.green.clamp(0.0, 1.0),
+        rgb.blue.clamp(0.0, 1.0),
+        1.0,
+    )
+}
+impl GradientDescriptor {
+    /// A descriptor with palette colors as its stops, for example
+    /// `Srgb` or `Lch`
+    pub fn from_palette<C: IntoColor<Srgb>>(
+        colors: impl IntoIterator<Item = C>,
+    ) -> Self {
+        Self::new(
+            colors
+                .into_iter()
+                .map(|c| {
+                    let rgb: Srgb<u8> =
+                        IntoColor::<Srgb>::into_color(c)
+                            .into_format();
+                    (rgb.red, rgb.green, rgb.blue)
+                })
+                .collect(),
+        )
+    }
+}
+/// A gradient through palette colors, evenly spaced
+/// (see [`GradientDescriptor::from_palette`])
+pub fn gradient_from_palette<C: IntoColor<Srgb>>(
+    colors: impl IntoIterator<Item = C>,
+) -> G {
+    GradientDescriptor::from_palette(colors).build()
+}
+impl GradientVariation {
+    /// Applies `transform` to the gradient of every side
+    pub fn transform(self, transform: ColorTransform) -> Self {
+        self.map(|g| Box::new(TransformedGradient::new(g, transform)))
+    }
+}
+impl GradientTheme {
+    /// Applies `transform` to every gradient of the theme
+    pub fn transform(self, transform: ColorTransform) -> Self {
+        self.map(|g| Box::new(TransformedGradient::new(g, transform)))
+    }
+    /// Rotates the hue of the whole theme by `degrees`
+    pub fn hue_rotate(self, degrees: f32) -> Self {
+        self.transform(ColorTransform::HueRotate(degrees))
+    }
+    /// Lightens the whole theme, `amount` in 0.0..=1.0
+    pub fn lighten(self, amount: f32) -> Self {
+        self.transform(ColorTransform::Lighten(amount))
+    }
+    /// Darkens the whole theme, `amount` in 0.0..=1.0
+    pub fn darken(self, amount: f32) -> Self {
+        self.transform(ColorTransform::Darken(amount))
+    }
+    /// Saturates the whole theme, `amount` in -1.0..=1.0
+    pub fn saturate(self, amount: f32) -> Self {
+        self.transform(ColorTransform::Saturate(amount))
+    }
+}
@@ -1,47 +1,47 @@
-use crate::structs::border_symbols::SegmentSet;
-use tui_rule::{Set, presets::neutral::EMPTY as EMPT};
+use crate::structs::border_symbols::{SegmentSet, Symbol, SymbolSet};
+use ratatui::symbols::border;
 // A module of predefined border styles for different visual aesthetics. Each `SegmentSet`
 // instance defines the characters to be used for different parts of the border (corners, sides, and centers).
 //
 // These styles can be used to customize the appearance of borders for blocks
-/// ```
-/// &-----&
+/// ```text
+/// +--+--+
 /// |     |
 /// +     +
 /// |     |
-/// &-----&
+/// +--+--+
 /// ```
 pub const MISC1: SegmentSet = SegmentSet {
-    left: Set {
-        start: '+',
-        rep_1: '|',
-        center: '+',
-        rep_2: '|',
-        end: '+',
-    },
-    right: Set {
-        start: '+',
-        rep_1: '|',
-        center: '+',
-        rep_2: '|',
-        end: '+',
-    },
-    top: Set {
-        start: '+',
-        rep_1: '-',
-        center: '+',
-        rep_2: '-',
-        end: '+',
-    },
-    bottom: Set {
-        start: '+',
-        rep_1: '-',
-        center: '+',
-        rep_2: '-',
-        end: '+',
+    left: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('|'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('|'),
+        end: Symbol::from_char('+'),
+    },
+    right: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('|'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('|'),
+        end: Symbol::from_char('+'),
+    },
+    top: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('-'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('-'),
+        end: Symbol::from_char('+'),
+    },
+    bottom: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('-'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('-'),
+        end: Symbol::from_char('+'),
     },
 };
-/// ```
+/// ```text
 /// &-----&
 /// |     |
 /// +     +
@@ -49,75 +49,324 @@ pub const MISC1: SegmentSet = SegmentSet {
 /// &-----&
 /// ```
 pub const MISC2: SegmentSet = SegmentSet {
-    left: Set {
-        start: '&',
-        rep_1: '|',
-        center: '+',
-        rep_2: '|',
-        end: '&',
-    },
-    right: Set {
-        start: '&',
-        rep_1: '|',
-        center: '+',
-        rep_2: '|',
-        end: '&',
-    },
-    top: Set {
-        start: '&',
-        rep_1: '-',
-        center: '-',
-        rep_2: '-',
-        end: '&',
-    },
-    bottom: Set {
-        start: '&',
-        rep_1: '-',
-        center: '-',
-        rep_2: '-',
-        end: '&',
+    left: SymbolSet {
+        start: Symbol::from_char('&'),
+        rep_1: Symbol::from_char('|'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('|'),
+        end: Symbol::from_char('&'),
+    },
+    right: SymbolSet {
+        start: Symbol::from_char('&'),
+        rep_1: Symbol::from_char('|'),
+        center: Symbol::from_char('+'),
+        rep_2: Symbol::from_char('|'),
+        end: Symbol::from_char('&'),
+    },
+    top: SymbolSet {
+        start: Symbol::from_char('&'),
+        rep_1: Symbol::from_char('-'),
+        center: Symbol::from_char('-'),
+        rep_2: Symbol::from_char('-'),
+        end: Symbol::from_char('&'),
+    },
+    bottom: SymbolSet {
+        start: Symbol::from_char('&'),
+        rep_1: Symbol::from_char('-'),
+        center: Symbol::from_char('-'),
+        rep_2: Symbol::from_char('-'),
+        end: Symbol::from_char('&'),
     },
 };
-/// ```
+/// ```text
 /// $──~──$
-/// |     |
+/// │     │
 /// ~     ~
-/// |     |
-/// $──~──$
+/// │     │
+/// $──$──$
 /// ```
 pub const MISC3: SegmentSet = SegmentSet {
-    left: Set {
-        start: '$',
-        rep_1: '│',
-        center: '~',
-        rep_2: '│',
-        end: '$',
-    },
-    right: Set {
-        start: '$',
-        rep_1: '│',
-        center: '~',
-        rep_2: '│',
-        end: '$',
-    },
-    top: Set {
-        start: '$',
-        rep_1: '─',
-        center: '~',
-        rep_2: '─',
-        end: '$',
-    },
-    bottom: Set {
-        start: '$',
-        rep_1: '─',
-        center: '$',
-        rep_2: '─',
-        end: '$',
+    left: SymbolSet {
+        start: Symbol::from_char('$'),
+        rep_1: Symbol::from_char('│'),
+        center: Symbol::from_char('~'),
+        rep_2: Symbol::from_char('│'),
+        end: Symbol::from_char('$'),
     },
+    right: SymbolSet {
+        start: Symbol::from_char('$'),
+        rep_1: Symbol::from_char('│'),
+        center: Symbol::from_char('~'),
+        rep_2: Symbol::from_char('│'),
+        end: Symbol::from_char('$'),
+    },
+    top: SymbolSet {
+        start: Symbol::from_char('$'),
+        rep_1: Symbol::from_char('─'),
+        center: Symbol::from_char('~'),
+        rep_2: Symbol::from_char('─'),
+        end: Symbol::from_char('$'),
+    },
+    bottom: SymbolSet {
+        start: Symbol::from_char('$'),
+        rep_1: Symbol::from_char('─'),
+        center: Symbol::from_char('$'),
+        rep_2: Symbol::from_char('─'),
+        end: Symbol::from_char('$'),
+    },
+};
+/// Plain ascii, safe for terminals without unicode box-drawing
+/// ```text
+/// +-----+
+/// |     |
+/// |     |
+/// |     |
+/// +-----+
+/// ```
+pub const ASCII: SegmentSet = SegmentSet {
+    left: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('|'),
+        center: Symbol::from_char('|'),
+        rep_2: Symbol::from_char('|'),
+        end: Symbol::from_char('+'),
+    },
+    right: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('|'),
+        center: Symbol::from_char('|'),
+        rep_2: Symbol::from_char('|'),
+        end: Symbol::from_char('+'),
+    },
+    top: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('-'),
+        center: Symbol::from_char('-'),
+        rep_2: Symbol::from_char('-'),
+        end: Symbol::from_char('+'),
+    },
+    bottom: SymbolSet {
+        start: Symbol::from_char('+'),
+        rep_1: Symbol::from_char('-'),
+        center: Symbol::from_char('-'),
+        rep_2: Symbol::from_char('-'),
+        end: Symbol::from_char('+'),
+    },
+};
+pub const EMPTY: SegmentSet =
+    SegmentSet::from_chars(' ', ' ', ' ', ' ', ' ', ' ');
+/// ratatui's plain border, `┌─┐`
+pub const PLAIN: SegmentSet =
+    SegmentSet::from_ratatui_set(border::PLAIN);
+/// ratatui's rounded border, `╭─╮`
+pub const ROUNDED: SegmentSet =
+    SegmentSet::from_ratatui_set(border::ROUNDED);
+/// ratatui's double border, `╔═╗`
+pub const DOUBLE: SegmentSet =
+    SegmentSet::from_ratatui_set(border::DOUBLE);
+/// ratatui's thick border, `┏━┓`
+pub const THICK: SegmentSet =
+    SegmentSet::from_ratatui_set(border::THICK);
+/// ratatui's quadrant border outside the content by half a cell
+/// ```
+/// ▛▀▀▀▀▀▜
+/// ▌     ▐
+/// ▙▄▄▄▄▄▟
+/// ```
+pub const QUADRANT_OUTSIDE: SegmentSet =
+    SegmentSet::from_ratatui_set(border::QUADRANT_OUTSIDE);
+/// ratatui's quadrant border inside the area by half a cell
+/// ```
+/// ▗▄▄▄▄▄▖
+/// ▐     ▌
+/// ▝▀▀▀▀▀▘
+/// ```
+pub const QUADRANT_INSIDE: SegmentSet =
+    SegmentSet::from_ratatui_set(border::QUADRANT_INSIDE);
+/// ratatui's wide one eighth border (McGugan box)
+/// ```
+/// ▁▁▁▁▁▁▁
+/// ▏     ▕
+/// ▔▔▔▔▔▔▔
+/// ```
+pub const ONE_EIGHTH_WIDE: SegmentSet =
+    SegmentSet::from_ratatui_set(border::ONE_EIGHTH_WIDE);
+/// ratatui's tall one eighth border (McGugan box)
+/// ```
+/// ▕▔▔▔▔▔▏
+/// ▕     ▏
+/// ▕▁▁▁▁▁▏
+/// ```
+pub const ONE_EIGHTH_TALL: SegmentSet =
+    SegmentSet::from_ratatui_set(border::ONE_EIGHTH_TALL);
+/// ratatui's proportional border, half blocks on the top and bottom and
+/// full blocks on the sides, so both look as thick
+/// ```
+/// ▄▄▄▄▄▄▄
+/// █     █
+/// ▀▀▀▀▀▀▀
+/// ```
+pub const PROPORTIONAL_WIDE: SegmentSet =
+    SegmentSet::from_ratatui_set(border::PROPORTIONAL_WIDE);
+/// ratatui's tall proportional border
+/// ```
+/// █▀▀▀▀▀█
+/// █     █
+/// █▄▄▄▄▄█
+/// ```
+pub const PROPORTIONAL_TALL: SegmentSet =
+    SegmentSet::from_ratatui_set(border::PROPORTIONAL_TALL);
+/// ratatui's solid border of full blocks
+/// ```
+/// ███████
+/// █     █
+/// ███████
+/// ```
+pub const FULL: SegmentSet = SegmentSet::from_ratatui_set(border::FULL);
+/// Heavy lines with rounded corners
+/// ```text
+/// ╭━━━━━╮
+/// ┃     ┃
+/// ┃     ┃
+/// ┃     ┃
+/// ╰━━━━━╯
+/// ```
+pub const HEAVY_ROUNDED: SegmentSet =
+    SegmentSet::from_chars('╭', '╮', '╰', '╯', '━', '┃');
+/// Double lines with rounded corners
+/// ```
+/// ╭═════╮
+/// ║     ║
+/// ║     ║
+/// ║     ║
+/// ╰═════╯
+/// ```
+pub const DOUBLE_ARC: SegmentSet =
+    SegmentSet::from_chars('╭', '╮', '╰', '╯', '═', '║');
+/// Dashed box-drawing lines
+/// ```
+/// ┌┄┄┄┄┄┐
+/// ┆     ┆
+/// ┆     ┆
+/// ┆     ┆
+/// └┄┄┄┄┄┘
+/// ```
+pub const DOTTED: SegmentSet =
+    SegmentSet::from_chars('┌', '┐', '└', '┘', '┄', '┆');
+/// Only glyphs from code page 437, for the Linux console and other
+/// terminals limited to it
+/// ```
+/// ╒═════╕
+/// │     │
+/// │     │
+/// │     │
+/// ╘═════╛
+/// ```
+pub const CP437: SegmentSet =
+    SegmentSet::from_chars('╒', '╕', '╘', '╛', '═', '│');
+/// Rounded corners with a Nerd Font circle (`nf-fa-circle`) in the
+/// middle of every side. Needs a Nerd Font to render.
+/// ```text
+/// ╭──\u{f111}──╮
+/// │     │
+/// \u{f111}     \u{f111}
+/// │     │
+/// ╰──\u{f111}──╯
+/// ```
+pub const NERD_FONT: SegmentSet = {
+    let set = SegmentSet::from_chars('╭', '╮', '╰', '╯', '─', '│');
+    SegmentSet {
+        top: SymbolSet {
+            center: Symbol::from_char('\u{f111}'),
+            ..set.top
+        },
+        bottom: SymbolSet {
+            center: Symbol::from_char('\u{f111}'),
+            ..set.bottom
+        },
+        left: SymbolSet {
+            center: Symbol::from_char('\u{f111}'),
+            ..set.left
+        },
+        right: SymbolSet {
+            center: Symbol::from_char('\u{f111}'),
+            ..set.right
+        },
+    }
 };
-pub const EMPTY: SegmentSet = SegmentSet {
-    left: EMPT,
-    right: EMPT,
-    top: EMPT,
-    bottom: EMPT,
+/// Thin lines of braille dots along the outside of the block. Drawn
+/// like any other set by default; with
+/// [`crate::renderer::BrailleRenderer`] (see
+/// `GradientBlock::braille`) the lines can be dotted or dashed within a
+/// cell and take the gradient at a finer resolution.
+/// ```
+/// ⡏⠉⠉⠉⠉⠉⢹
+/// ⡇     ⢸
+/// ⡇     ⢸
+/// ⡇     ⢸
+/// ⣇⣀⣀⣀⣀⣀⣸
+/// ```
+pub const BRAILLE: SegmentSet = SegmentSet {
+    top: SymbolSet::from_chars('⡏', '⠉', '⢹'),
+    bottom: SymbolSet::from_chars('⣇', '⣀', '⣸'),
+    left: SymbolSet::from_chars('⡏', '⡇', '⣇'),
+    right: SymbolSet::from_chars('⢹', '⢸', '⣸'),
+};
+/// The names of every preset border set, in alphabetical order, for
+/// set pickers and config files
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::preset;
+/// for (name, set) in preset::sets() {
+///     let block = GradientBlock::new().title_top(name).with_set(set);
+/// }
+/// ```
+pub fn sets() -> impl Iterator<Item = (&'static str, SegmentSet)> {
+    [
+        ("ascii", ASCII),
+        ("braille", BRAILLE),
+        ("cp437", CP437),
+        ("dotted", DOTTED),
+        ("double", DOUBLE),
+        ("double_arc", DOUBLE_ARC),
+        ("empty", EMPTY),
+        ("full", FULL),
+        ("heavy_rounded", HEAVY_ROUNDED),
+        ("misc1", MISC1),
+        ("misc2", MISC2),
+        ("misc3", MISC3),
+        ("nerd_font", NERD_FONT),
+        ("one_eighth_tall", ONE_EIGHTH_TALL),
+        ("one_eighth_wide", ONE_EIGHTH_WIDE),
+        ("plain", PLAIN),
+        ("proportional_tall", PROPORTIONAL_TALL),
+        ("proportional_wide", PROPORTIONAL_WIDE),
+        ("quadrant_inside", QUADRANT_INSIDE),
+        ("quadrant_outside", QUADRANT_OUTSIDE),
+        ("rounded", ROUNDED),
+        ("thick", THICK),
+    ]
+    .into_iter()
+}
+/// Looks up a preset border set by its name in [`sets`]; case and `-`
+/// vs `_` don't matter
+pub fn set_by_name(name: &str) -> Option<SegmentSet> {
+    let name = name.trim().to_lowercase().replace('-', "_");
+    sets().find(|(n, _)| *n == name).map(|(_, set)| set)
+}
+// The built-in gradient themes under short names, e.g.
+// `preset::midnight_blurple::theme()`
+pub use crate::theme_presets::{
+    cool::{
+        t_minty_green as minty_green, t_misty_blue as misty_blue,
+        t_zombie_dreams as zombie_dreams,
+    },
+    dark::t_midnight_blurple as midnight_blurple,
+    misc::t_monochrome as monochrome,
+    palettes::{
+        t_catppuccin as catppuccin, t_dracula as dracula,
+        t_gruvbox as gruvbox, t_nord as nord,
+    },
+    warm::t_rusty_ruins as rusty_ruins,
 };
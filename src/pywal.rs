@@ -0,0 +1,87 @@
+//! Themes matching the system colorscheme generated by pywal or
+//! wallust, read from the `colors.json` they write to
+//! `~/.cache/wal`. Behind the `serde` feature.
+//! # Example
+//! ```
+//! # use tui_gradient_block::structs::gradient::GradientTheme;
+//! # use tui_gradient_block::theme_presets::cool::t_misty_blue;
+//! let theme = GradientTheme::from_pywal_cache()
+//!     .unwrap_or_else(|_| t_misty_blue::theme());
+//! ```
+use crate::{structs::gradient::GradientTheme, types::E};
+use colorgrad::Color;
+use std::{collections::HashMap, path::Path, path::PathBuf};
+#[derive(serde::Deserialize)]
+struct ColorsJson {
+    special: HashMap<String, String>,
+    colors: HashMap<String, String>,
+}
+/// A pywal colorscheme: the terminal background and foreground, and
+/// the 16 ansi colors `color0..=color15`
+#[derive(Clone, Debug, PartialEq)]
+pub struct PywalScheme {
+    pub background: Color,
+    pub foreground: Color,
+    pub colors: Vec<Color>,
+}
+impl PywalScheme {
+    /// Reads a `colors.json` in the pywal format
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", err)
+    )]
+    pub fn from_json(path: &Path) -> Result<Self, E> {
+        let f = std::fs::File::open(path)?;
+        let json: ColorsJson =
+            serde_json::from_reader(std::io::BufReader::new(f))?;
+        let parse = |map: &HashMap<String, String>,
+                     key: &str|
+         -> Result<Color, E> {
+            let hex = map.get(key).ok_or_else(|| {
+                format!("pywal colors lack `{key}`")
+            })?;
+            Ok(Color::from_html(hex)?)
+        };
+        Ok(Self {
+            background: parse(&json.special, "background")?,
+            foreground: parse(&json.special, "foreground")?,
+            colors: (0..16)
+                .map(|i| parse(&json.colors, &format!("color{i}")))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+    /// The accent colors `color1..=color6`, the ones pywal picks from
+    /// the wallpaper
+    pub fn accents(&self) -> &[Color] {
+        &self.colors[1..7]
+    }
+    /// A theme made of the accent colors, see
+    /// [`GradientTheme::from_stops`]
+    pub fn theme(&self) -> GradientTheme {
+        GradientTheme::from_stops(self.accents())
+    }
+}
+/// Where pywal and wallust cache their colors:
+/// `$XDG_CACHE_HOME/wal/colors.json`, or `$HOME/.cache/wal/colors.json`
+pub fn cache_path() -> Option<PathBuf> {
+    let var = |k: &str| std::env::var_os(k).filter(|v| !v.is_empty());
+    let cache =
+        var("XDG_CACHE_HOME").map(PathBuf::from).or_else(|| {
+            var("HOME").map(|h| PathBuf::from(h).join(".cache"))
+        })?;
+    Some(cache.join("wal").join("colors.json"))
+}
+impl GradientTheme {
+    /// A theme made of the accent colors of the pywal `colors.json` at
+    /// `path`, see [`PywalScheme::theme`]
+    pub fn from_pywal(path: impl AsRef<Path>) -> Result<Self, E> {
+        Ok(PywalScheme::from_json(path.as_ref())?.theme())
+    }
+    /// [`GradientTheme::from_pywal`] with the colors pywal or wallust
+    /// last generated, see [`cache_path`]
+    pub fn from_pywal_cache() -> Result<Self, E> {
+        let path = cache_path()
+            .ok_or("no cache directory, $HOME is unset")?;
+        Self::from_pywal(path)
+    }
+}
@@ -0,0 +1,527 @@
+use crate::{
+    buffer::{Buffer, Cell},
+    enums::{BrailleTexture, Side},
+    layout::{Position, Rect},
+    structs::{
+        border_segment::Segment,
+        border_symbols::{Symbol, SymbolSet},
+    },
+    style::Color,
+    to_ratatui_color,
+    types::G,
+    widgets::Padding,
+};
+use std::iter::repeat_n;
+/// Renders one side of a block.
+///
+/// The segment holds everything configured for the side: its symbols,
+/// gradient and padding. `area` is the block's area inside its margin,
+/// and the side runs along the edge of it that `side` names. `phase`
+/// shifts the gradient along the segment (0.0..1.0, wrapping), for
+/// animations.
+///
+/// [`RuleRenderer`] is used by default. Implement this to draw a side in
+/// a completely different way (double-thickness sides, braille edges, ...)
+/// and set it with `GradientBlock::top_renderer` and friends.
+pub trait SegmentRenderer {
+    fn render_segment(
+        &self,
+        side: Side,
+        seg: &Segment,
+        area: Rect,
+        phase: f32,
+        buf: &mut Buffer,
+    );
+}
+/// The default renderer, drawing the side's symbols and gradient
+/// straight into the buffer, see [`draw_segment`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RuleRenderer;
+impl SegmentRenderer for RuleRenderer {
+    fn render_segment(
+        &self,
+        side: Side,
+        seg: &Segment,
+        area: Rect,
+        phase: f32,
+        buf: &mut Buffer,
+    ) {
+        draw_segment(side, seg, area, phase, buf);
+    }
+}
+/// Draws the side with tui_rule's own widget, for apps relying on its
+/// exact output. Slower than [`RuleRenderer`], as tui_rule builds the
+/// line as strings and spans every frame.
+#[cfg(feature = "tui-rule-compat")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TuiRuleRenderer;
+#[cfg(feature = "tui-rule-compat")]
+impl SegmentRenderer for TuiRuleRenderer {
+    fn render_segment(
+        &self,
+        side: Side,
+        seg: &Segment,
+        area: Rect,
+        phase: f32,
+        buf: &mut Buffer,
+    ) {
+        use crate::widgets::WidgetRef;
+        let Some(gradient) =
+            seg.gradient.as_ref().filter(|_| phase != 0.0)
+        else {
+            seg.to_rule(side).render_ref(area, buf);
+            return;
+        };
+        let cells =
+            layer(area, |l| draw_segment(side, seg, area, 0.0, l));
+        let last = cells.len().saturating_sub(1).max(1) as f32;
+        for (i, (pos, mut cell)) in cells.into_iter().enumerate() {
+            cell.fg = sample(gradient, i as f32 / last, phase);
+            if let Some(c) = buf.cell_mut(pos) {
+                *c = cell;
+            }
+        }
+    }
+}
+/// A high resolution renderer built from half block glyphs.
+///
+/// The left and right sides use `▀` with the foreground and background
+/// sampled separately, so the gradient gets two colors per cell and
+/// twice the vertical resolution. The top and bottom sides become thin
+/// `▀`/`▄` lines hugging the outside of the block.
+///
+/// Blank symbols (hidden corners, padding) stay blank.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HalfBlockRenderer;
+impl SegmentRenderer for HalfBlockRenderer {
+    fn render_segment(
+        &self,
+        side: Side,
+        seg: &Segment,
+        area: Rect,
+        phase: f32,
+        buf: &mut Buffer,
+    ) {
+        let cells: Vec<_> =
+            layer(area, |l| draw_segment(side, seg, area, 0.0, l))
+                .into_iter()
+                .filter(|(_, cell)| cell.symbol() != " ")
+                .collect();
+        let halves =
+            (cells.len() * 2).saturating_sub(1).max(1) as f32;
+        for (i, (pos, mut cell)) in cells.into_iter().enumerate() {
+            let (upper, lower) = (i * 2, i * 2 + 1);
+            match (&seg.gradient, side) {
+                (Some(g), Side::Left | Side::Right) => {
+                    cell.set_char('▀');
+                    cell.fg = sample(g, upper as f32 / halves, phase);
+                    cell.bg = sample(g, lower as f32 / halves, phase);
+                }
+                (None, Side::Left | Side::Right) => {
+                    cell.set_char('█');
+                }
+                (g, Side::Top | Side::Bottom) => {
+                    cell.set_char(match side {
+                        Side::Top => '▀',
+                        _ => '▄',
+                    });
+                    if let Some(g) = g {
+                        cell.fg =
+                            sample(g, upper as f32 / halves, phase);
+                    }
+                }
+            }
+            if let Some(c) = buf.cell_mut(pos) {
+                *c = cell;
+            }
+        }
+    }
+}
+/// Draws the sides as bands of block elements instead of line glyphs,
+/// for header bars and modal frames that need more weight.
+///
+/// Corners become `█`, center symbols `▒` and everything else `▓`, all
+/// colored from the side's gradient. Blank symbols stay blank.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HeavyRenderer;
+impl SegmentRenderer for HeavyRenderer {
+    fn render_segment(
+        &self,
+        side: Side,
+        seg: &Segment,
+        area: Rect,
+        phase: f32,
+        buf: &mut Buffer,
+    ) {
+        let set = &seg.symbols;
+        let cells: Vec<_> =
+            layer(area, |l| draw_segment(side, seg, area, 0.0, l))
+                .into_iter()
+                .filter(|(_, cell)| cell.symbol() != " ")
+                .collect();
+        let last = cells.len().saturating_sub(1);
+        for (i, (pos, mut cell)) in cells.into_iter().enumerate() {
+            let symbol = cell.symbol();
+            cell.set_char(match i {
+                0 => '█',
+                _ if i == last => '█',
+                _ if symbol == set.center.as_str()
+                    && set.center != set.rep_1 =>
+                {
+                    '▒'
+                }
+                _ => '▓',
+            });
+            if let Some(g) = &seg.gradient {
+                cell.fg =
+                    sample(g, i as f32 / last.max(1) as f32, phase);
+            }
+            if let Some(c) = buf.cell_mut(pos) {
+                *c = cell;
+            }
+        }
+    }
+}
+/// A fine resolution renderer built from braille patterns.
+///
+/// Every cell of a side is a grid of 2x4 dots, and the line runs along
+/// its outermost row or column of dots, two dots per cell on the top and
+/// bottom and four on the left and right. The [`BrailleTexture`] picks
+/// which dots are lit, so lines can be dotted or dashed within a cell,
+/// and each cell is colored from the gradient at the dots it lights
+/// rather than at the cell.
+///
+/// Corner symbols light the dots of both lines meeting there, whatever
+/// the texture. Blank symbols (hidden corners, padding) stay blank.
+/// Meant for [`crate::preset::BRAILLE`], but draws any set.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BrailleRenderer {
+    pub texture: BrailleTexture,
+}
+impl BrailleRenderer {
+    pub const fn new(texture: BrailleTexture) -> Self {
+        Self { texture }
+    }
+}
+impl SegmentRenderer for BrailleRenderer {
+    fn render_segment(
+        &self,
+        side: Side,
+        seg: &Segment,
+        area: Rect,
+        phase: f32,
+        buf: &mut Buffer,
+    ) {
+        let set = &seg.symbols;
+        let cells: Vec<_> =
+            layer(area, |l| draw_segment(side, seg, area, 0.0, l))
+                .into_iter()
+                .filter(|(_, cell)| cell.symbol() != " ")
+                .collect();
+        let per_cell = match side {
+            Side::Top | Side::Bottom => 2,
+            Side::Left | Side::Right => 4,
+        };
+        let last = cells.len().saturating_sub(1);
+        let last_dot = (cells.len() * per_cell).saturating_sub(1);
+        for (i, (pos, mut cell)) in cells.into_iter().enumerate() {
+            let symbol = cell.symbol();
+            // a spanned end uses the repeated symbol, and gets no corner
+            let corner = match i {
+                0 => (set.start != set.rep_1
+                    && symbol == set.start.as_str())
+                .then_some(true),
+                _ if i == last => (set.end != set.rep_2
+                    && symbol == set.end.as_str())
+                .then_some(false),
+                _ => None,
+            };
+            let (mut bits, mut lit, mut sum) = (0, 0, 0);
+            for k in 0..per_cell {
+                let index = i * per_cell + k;
+                if corner.is_some() || self.texture.lit(index) {
+                    bits |= line_dot(side, k as u8);
+                    lit += 1;
+                    sum += index;
+                }
+            }
+            if let Some(start) = corner {
+                bits |= corner_dots(side, start);
+            }
+            cell.set_char(
+                char::from_u32(0x2800 + bits as u32).unwrap_or(' '),
+            );
+            if let Some(g) = &seg.gradient {
+                let at = match lit {
+                    0 => (i * per_cell) as f32,
+                    _ => sum as f32 / lit as f32,
+                };
+                cell.fg =
+                    sample(g, at / last_dot.max(1) as f32, phase);
+            }
+            if let Some(c) = buf.cell_mut(pos) {
+                *c = cell;
+            }
+        }
+    }
+}
+/// The bit of the braille dot in column `x` (0..2) and row `y` (0..4)
+/// of a cell
+const fn braille_dot(x: u8, y: u8) -> u8 {
+    match (x, y) {
+        (0, 3) => 0x40,
+        (_, 3) => 0x80,
+        (0, y) => 1 << y,
+        (_, y) => 8 << y,
+    }
+}
+/// The dot at `k` along the line of `side` in one cell, on the row or
+/// column of dots facing away from the block
+const fn line_dot(side: Side, k: u8) -> u8 {
+    match side {
+        Side::Top => braille_dot(k, 0),
+        Side::Bottom => braille_dot(k, 3),
+        Side::Left => braille_dot(0, k),
+        Side::Right => braille_dot(1, k),
+    }
+}
+/// The dots of the line meeting the line of `side` at its start or end
+const fn corner_dots(side: Side, start: bool) -> u8 {
+    let mut bits = 0;
+    let mut k = 0;
+    while k < 4 {
+        bits |= match (side, start) {
+            (Side::Top | Side::Bottom, true) => braille_dot(0, k),
+            (Side::Top | Side::Bottom, false) => braille_dot(1, k),
+            (Side::Left | Side::Right, true) if k < 2 => {
+                braille_dot(k, 0)
+            }
+            (Side::Left | Side::Right, false) if k < 2 => {
+                braille_dot(k, 3)
+            }
+            _ => 0,
+        };
+        k += 1;
+    }
+    bits
+}
+/// Where the line of a side goes and how many of each symbol it has,
+/// laid out the way tui_rule lays it out: the line runs along the edge
+/// of the area `side` names, moved in by the padding across it and
+/// shrunk by the padding along it, and the padding is blank.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RuleLayout {
+    /// The first cell of the line
+    pub origin: Position,
+    pub horizontal: bool,
+    /// Blank cells before the start symbol
+    pub lead: usize,
+    /// `rep_1` symbols between the start and center
+    pub rep_1: usize,
+    /// `rep_2` symbols between the center and end
+    pub rep_2: usize,
+    /// Blank cells after the end symbol
+    pub trail: usize,
+}
+impl RuleLayout {
+    pub fn new(side: Side, padding: Padding, area: Rect) -> Self {
+        let p = padding;
+        let mut area = area;
+        match side {
+            Side::Top => {
+                area.y = area
+                    .y
+                    .saturating_sub(p.bottom)
+                    .saturating_add(p.top)
+            }
+            Side::Bottom => {
+                area.y = area
+                    .bottom()
+                    .saturating_sub(1 + p.bottom)
+                    .saturating_add(p.top)
+            }
+            Side::Left => {
+                area.x = area
+                    .x
+                    .saturating_sub(p.right)
+                    .saturating_add(p.left)
+            }
+            Side::Right => {
+                area.x = area.right().saturating_sub(1 + p.right)
+            }
+        }
+        let horizontal = matches!(side, Side::Top | Side::Bottom);
+        let (p1, p2, len) = match horizontal {
+            true => (p.left as usize, p.right as usize, area.width),
+            false => (p.top as usize, p.bottom as usize, area.height),
+        };
+        // the center symbol sits in the middle, the repeated symbols
+        // fill the rest minus the padding
+        let reps = len as f32 / 2.0 - 1.0;
+        Self {
+            origin: area.as_position(),
+            horizontal,
+            lead: p1,
+            rep_1: (reps.floor() as usize).saturating_sub(p1),
+            rep_2: (reps.round() as usize).saturating_sub(p2 + 1),
+            trail: p2,
+        }
+    }
+    /// The number of cells of the line, blank ones included
+    pub fn len(&self) -> usize {
+        self.lead + self.rep_1 + self.rep_2 + self.trail + 3
+    }
+    /// Never, a line always has its start, center and end symbols
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+    /// The symbols of the line in order with the offset and cell each
+    /// starts at, blank for the padding.
+    ///
+    /// The start, center and end symbols stay where one cell wide
+    /// symbols would be, wide corners reaching towards the middle and a
+    /// wide center centered on its cell, and
+    /// the repeated symbols fill the room left between them. Cells too
+    /// few for another repeated symbol stay blank, so the corners line
+    /// up whatever the symbols' widths. Along the left and right sides
+    /// every symbol takes one row.
+    pub fn symbols(
+        &self,
+        set: &SymbolSet,
+    ) -> impl Iterator<Item = (usize, Position, Symbol)> + use<> {
+        self.sized_symbols(set)
+            .map(|(i, pos, symbol, _)| (i, pos, symbol))
+    }
+    /// [`Self::symbols`] along with the width of each symbol, which is
+    /// worked out once per set rather than for every cell
+    fn sized_symbols(
+        &self,
+        set: &SymbolSet,
+    ) -> impl Iterator<Item = (usize, Position, Symbol, usize)> + use<>
+    {
+        let Self {
+            origin, horizontal, ..
+        } = *self;
+        let sized = |s: Symbol| (s, s.width());
+        let [start_s, center_s, end_s, rep_1_s, rep_2_s] =
+            [set.start, set.center, set.end, set.rep_1, set.rep_2]
+                .map(sized);
+        // along the left and right sides every symbol takes one row
+        let along = |(_, w): (Symbol, usize)| match horizontal {
+            true => w,
+            false => 1,
+        };
+        let (w_start, w_center, w_end) =
+            (along(start_s), along(center_s), along(end_s));
+        let (w_1, w_2) = (along(rep_1_s), along(rep_2_s));
+        let start = self.lead;
+        let center = start + 1 + self.rep_1;
+        let end = center + 1 + self.rep_2;
+        let center =
+            center.saturating_sub(w_center / 2).max(start + w_start);
+        let end =
+            (end + 1).saturating_sub(w_end).max(center + w_center);
+        let (gap_1, gap_2) =
+            (center - start - w_start, end - center - w_center);
+        let len = self.len();
+        let blank = (Symbol::BLANK, 1);
+        [
+            (blank, self.lead, 1),
+            (start_s, 1, w_start),
+            (rep_1_s, gap_1 / w_1, w_1),
+            (blank, gap_1 % w_1, 1),
+            (center_s, 1, w_center),
+            (rep_2_s, gap_2 / w_2, w_2),
+            (blank, gap_2 % w_2, 1),
+            (end_s, 1, w_end),
+            (blank, self.trail, 1),
+        ]
+        .into_iter()
+        .flat_map(|(symbol, n, w)| repeat_n((symbol, w), n))
+        .scan(0, |offset, (symbol, w)| {
+            let at = *offset;
+            *offset += w;
+            Some((at, w, symbol))
+        })
+        .take_while(move |(at, w, _)| at + w <= len)
+        .map_while(move |(i, _, (symbol, width))| {
+            let i = u16::try_from(i).ok()?;
+            let pos = match horizontal {
+                true => {
+                    Position::new(origin.x.checked_add(i)?, origin.y)
+                }
+                false => {
+                    Position::new(origin.x, origin.y.checked_add(i)?)
+                }
+            };
+            Some((i as usize, pos, symbol, width))
+        })
+    }
+}
+/// Draws `seg` along `side` of `area` one cell at a time, with its
+/// gradient shifted by `phase`, laid out by [`RuleLayout::symbols`].
+/// The cells a wide symbol covers after its first are reset, the way
+/// ratatui writes wide text. Wide symbols on a right side are moved in
+/// to stay inside `area`. Cells outside the buffer are skipped.
+pub fn draw_segment(
+    side: Side,
+    seg: &Segment,
+    area: Rect,
+    phase: f32,
+    buf: &mut Buffer,
+) {
+    let layout = RuleLayout::new(side, seg.padding, area);
+    let last = layout.len().saturating_sub(1).max(1) as f32;
+    for (i, pos, symbol, width) in layout.sized_symbols(&seg.symbols)
+    {
+        let width = width as u16;
+        let pos = match side {
+            Side::Right => {
+                Position::new(pos.x.saturating_sub(width - 1), pos.y)
+            }
+            _ => pos,
+        };
+        let Some(cell) = buf.cell_mut(pos) else {
+            continue;
+        };
+        cell.set_symbol(symbol.as_str());
+        if let Some(gradient) = &seg.gradient {
+            cell.fg = sample(gradient, i as f32 / last, phase);
+        }
+        for k in 1..width {
+            let covered =
+                Position::new(pos.x.saturating_add(k), pos.y);
+            if let Some(cell) = buf.cell_mut(covered) {
+                cell.reset();
+            }
+        }
+    }
+}
+/// Samples a gradient at `t` (0.0..=1.0) shifted by `phase`, wrapping
+/// around its domain
+pub fn sample(gradient: &G, t: f32, phase: f32) -> Color {
+    let (dmin, dmax) = gradient.domain();
+    // the end of the gradient stays at the end, only values past it wrap
+    let t = match t + phase {
+        t if (0.0..=1.0).contains(&t) => t,
+        t => t.rem_euclid(1.0),
+    };
+    to_ratatui_color!(gradient.at(t * (dmax - dmin) + dmin).clamp())
+}
+/// Runs `render` on a scratch layer covering `area` and returns the cells
+/// it touched, row by row.
+pub fn layer(
+    area: Rect,
+    render: impl FnOnce(&mut Buffer),
+) -> Vec<(Position, Cell)> {
+    const UNTOUCHED: &str = "\u{0}";
+    if area.is_empty() {
+        return Vec::new();
+    }
+    let mut layer = Buffer::filled(area, Cell::new(UNTOUCHED));
+    render(&mut layer);
+    area.positions()
+        .filter(|&pos| layer[pos].symbol() != UNTOUCHED)
+        .map(|pos| (pos, layer[pos].clone()))
+        .collect()
+}
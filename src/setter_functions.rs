@@ -1,12 +1,53 @@
 use crate::{
     enums,
     gradient_block::{self, SS},
-    structs::flags,
+    gradient_text::gradient_line,
+    prelude, preset,
+    renderer::{
+        BrailleRenderer, HalfBlockRenderer, HeavyRenderer,
+        RuleRenderer, SegmentRenderer,
+    },
+    structs::{
+        badge::Badge,
+        border_segment::{Segment, SegmentSpan},
+        border_symbols::Symbol,
+        effect::Effect,
+        flags,
+        gauge::GaugeConfig,
+        glow::GlowConfig,
+        gradient::{
+            DomainGradient, GradientOpts, GradientScope,
+            SteppedGradient,
+        },
+        hints::HintsConfig,
+        indicators::{IndicatorState, Indicators},
+        pattern::Pattern,
+        progress::ProgressConfig,
+        pulse::PulseConfig,
+        reveal::RevealConfig,
+        ruler::Ruler,
+        scrollbar::{ScrollPosition, ScrollbarConfig},
+        shadow::ShadowConfig,
+        shimmer::ShimmerConfig,
+        sparkle::SparkleConfig,
+        spinner::SpinnerConfig,
+        tabs::TabsConfig,
+        ticks::Ticks,
+        title::TitleBackground,
+        value_label::ValueLabel,
+    },
+    style::Style,
     text::Line,
     types::G,
     widgets::{self, block::title::Position},
 };
-use tui_rule::{create_raw_spans, generate_gradient_text};
+use std::rc::Rc;
+fn with_opts(gradient: Option<G>, opts: GradientOpts) -> Option<G> {
+    gradient.map(|g| Box::new(DomainGradient::new(g, opts)) as G)
+}
+fn with_steps(gradient: Option<G>, steps: usize) -> Option<G> {
+    gradient.map(|g| Box::new(SteppedGradient::new(g, steps)) as G)
+}
 impl<'a> gradient_block::GradientBlock<'a> {
     pub fn with_gradient(
         mut self,
@@ -19,24 +60,24 @@ impl<'a> gradient_block::GradientBlock<'a> {
             .bottom_gradient(gradient.bottom);
         self
     }
-    /// sets the right segment
-    pub fn right(mut self, seg: tui_rule::Rule) -> Self {
-        self.border_segments.right.seg = seg;
+    /// sets the right segment, see [`Segment`]
+    pub fn right<S: Into<Segment>>(mut self, seg: S) -> Self {
+        self.border_segments.right.set_segment(seg.into());
         self
     }
-    /// sets the left segment
-    pub fn left(mut self, seg: tui_rule::Rule) -> Self {
-        self.border_segments.left.seg = seg;
+    /// sets the left segment, see [`Segment`]
+    pub fn left<S: Into<Segment>>(mut self, seg: S) -> Self {
+        self.border_segments.left.set_segment(seg.into());
         self
     }
-    /// sets the top segment
-    pub fn top(mut self, seg: tui_rule::Rule) -> Self {
-        self.border_segments.top.seg = seg;
+    /// sets the top segment, see [`Segment`]
+    pub fn top<S: Into<Segment>>(mut self, seg: S) -> Self {
+        self.border_segments.top.set_segment(seg.into());
         self
     }
-    /// sets bottom segment
-    pub fn bottom(mut self, seg: tui_rule::Rule) -> Self {
-        self.border_segments.bottom.seg = seg;
+    /// sets the bottom segment, see [`Segment`]
+    pub fn bottom<S: Into<Segment>>(mut self, seg: S) -> Self {
+        self.border_segments.bottom.set_segment(seg.into());
         self
     }
     /// Sets gradient of the right segment of the border.
@@ -59,31 +100,774 @@ impl<'a> gradient_block::GradientBlock<'a> {
         self.border_segments.bottom.seg.gradient = Some(gradient);
         self
     }
+    /// Applies domain controls (scale, offset, repeat) to the gradient of
+    /// the right segment. Has no effect if the segment has no gradient.
+    pub fn right_gradient_opts(mut self, opts: GradientOpts) -> Self {
+        let seg = &mut self.border_segments.right.seg;
+        seg.gradient = with_opts(seg.gradient.take(), opts);
+        self
+    }
+    /// Applies domain controls to the gradient of the left segment.
+    pub fn left_gradient_opts(mut self, opts: GradientOpts) -> Self {
+        let seg = &mut self.border_segments.left.seg;
+        seg.gradient = with_opts(seg.gradient.take(), opts);
+        self
+    }
+    /// Applies domain controls to the gradient of the top segment.
+    pub fn top_gradient_opts(mut self, opts: GradientOpts) -> Self {
+        let seg = &mut self.border_segments.top.seg;
+        seg.gradient = with_opts(seg.gradient.take(), opts);
+        self
+    }
+    /// Applies domain controls to the gradient of the bottom segment.
+    pub fn bottom_gradient_opts(
+        mut self,
+        opts: GradientOpts,
+    ) -> Self {
+        let seg = &mut self.border_segments.bottom.seg;
+        seg.gradient = with_opts(seg.gradient.take(), opts);
+        self
+    }
+    /// Applies the same domain controls to all four segments.
+    pub fn gradient_opts(self, opts: GradientOpts) -> Self {
+        self.right_gradient_opts(opts)
+            .left_gradient_opts(opts)
+            .top_gradient_opts(opts)
+            .bottom_gradient_opts(opts)
+    }
+    /// Quantizes the gradient of the right segment into `steps` solid
+    /// bands. Has no effect if the segment has no gradient.
+    pub fn right_steps(mut self, steps: usize) -> Self {
+        let seg = &mut self.border_segments.right.seg;
+        seg.gradient = with_steps(seg.gradient.take(), steps);
+        self
+    }
+    /// Quantizes the gradient of the left segment into `steps` bands.
+    pub fn left_steps(mut self, steps: usize) -> Self {
+        let seg = &mut self.border_segments.left.seg;
+        seg.gradient = with_steps(seg.gradient.take(), steps);
+        self
+    }
+    /// Quantizes the gradient of the top segment into `steps` bands.
+    pub fn top_steps(mut self, steps: usize) -> Self {
+        let seg = &mut self.border_segments.top.seg;
+        seg.gradient = with_steps(seg.gradient.take(), steps);
+        self
+    }
+    /// Quantizes the gradient of the bottom segment into `steps` bands.
+    pub fn bottom_steps(mut self, steps: usize) -> Self {
+        let seg = &mut self.border_segments.bottom.seg;
+        seg.gradient = with_steps(seg.gradient.take(), steps);
+        self
+    }
+    /// Quantizes the gradients of all four segments into `steps` bands.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// // green, yellow and red zones along every side
+    /// # use tui_gradient_block::{gradient, structs::gradient::GradientVariation};
+    /// # let severity = GradientVariation::uniform(gradient!((0, 200, 0), (255, 200, 0), (220, 0, 0)));
+    /// let block = GradientBlock::new()
+    ///     .with_gradient(severity)
+    ///     .steps(3);
+    /// ```
+    pub fn steps(self, steps: usize) -> Self {
+        self.right_steps(steps)
+            .left_steps(steps)
+            .top_steps(steps)
+            .bottom_steps(steps)
+    }
+    /// Sets a closure that colors every border cell, overriding the
+    /// segment gradients. It is called with the cell's `x` and `y` and
+    /// `t`, the position along its segment from 0.0 to 1.0.
+    /// # Example
+    /// ```
+    /// # use ratatui::style::Color;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new().cell_shader(|x, y, t| {
+    ///     Color::Rgb((t * 255.0) as u8, (x * 8) as u8, (y * 8) as u8)
+    /// });
+    /// ```
+    pub fn cell_shader<F>(mut self, shader: F) -> Self
+    where
+        F: Fn(u16, u16, f32) -> ratatui::style::Color + 'static,
+    {
+        self.cell_shader = Some(Box::new(shader));
+        self
+    }
+    /// Shifts the gradients along their segments by `phase`
+    /// (0.0..1.0, wrapping). Changing it every frame animates the border.
+    pub const fn phase(mut self, phase: f32) -> Self {
+        self.phase = phase;
+        self
+    }
+    /// Renders the border in high resolution with half block glyphs,
+    /// see [`HalfBlockRenderer`].
+    pub fn half_blocks(self) -> Self {
+        self.renderer(HalfBlockRenderer)
+    }
+    /// Draws the border with [`preset::BRAILLE`] and
+    /// [`BrailleRenderer`], its lines lit according to `texture`
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::enums::BrailleTexture;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new().braille(BrailleTexture::Dashed);
+    /// ```
+    pub fn braille(self, texture: enums::BrailleTexture) -> Self {
+        self.with_set(preset::BRAILLE)
+            .renderer(BrailleRenderer::new(texture))
+    }
+    /// Sets the visual weight of the border.
+    ///
+    /// `BorderWeight::Heavy` draws the border with block elements using
+    /// [`HeavyRenderer`], `BorderWeight::Normal` goes back to line glyphs.
+    pub fn border_weight(self, weight: enums::BorderWeight) -> Self {
+        match weight {
+            enums::BorderWeight::Normal => {
+                self.renderer(RuleRenderer)
+            }
+            enums::BorderWeight::Heavy => {
+                self.renderer(HeavyRenderer)
+            }
+        }
+    }
+    /// Sets the renderer used for the right segment
+    pub fn right_renderer<S: SegmentRenderer + 'static>(
+        mut self,
+        renderer: S,
+    ) -> Self {
+        self.border_segments.right.renderer = Rc::new(renderer);
+        self
+    }
+    /// Sets the renderer used for the left segment
+    pub fn left_renderer<S: SegmentRenderer + 'static>(
+        mut self,
+        renderer: S,
+    ) -> Self {
+        self.border_segments.left.renderer = Rc::new(renderer);
+        self
+    }
+    /// Sets the renderer used for the top segment
+    pub fn top_renderer<S: SegmentRenderer + 'static>(
+        mut self,
+        renderer: S,
+    ) -> Self {
+        self.border_segments.top.renderer = Rc::new(renderer);
+        self
+    }
+    /// Sets the renderer used for the bottom segment
+    pub fn bottom_renderer<S: SegmentRenderer + 'static>(
+        mut self,
+        renderer: S,
+    ) -> Self {
+        self.border_segments.bottom.renderer = Rc::new(renderer);
+        self
+    }
+    /// Sets the renderer used for all four segments
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::{enums::Side, renderer::{RuleRenderer, SegmentRenderer}};
+    /// # use ratatui::{buffer::Buffer, layout::Rect};
+    /// # use tui_gradient_block::structs::border_segment::Segment;
+    /// struct Braille;
+    /// impl SegmentRenderer for Braille {
+    ///     fn render_segment(
+    ///         &self,
+    ///         side: Side,
+    ///         seg: &Segment,
+    ///         area: Rect,
+    ///         phase: f32,
+    ///         buf: &mut Buffer,
+    ///     ) {
+    ///         // draw the side with braille dots
+    /// #       RuleRenderer.render_segment(side, seg, area, phase, buf);
+    ///     }
+    /// }
+    /// let block = GradientBlock::new().renderer(Braille);
+    /// ```
+    pub fn renderer<S: SegmentRenderer + 'static>(
+        mut self,
+        renderer: S,
+    ) -> Self {
+        let renderer: Rc<dyn SegmentRenderer> = Rc::new(renderer);
+        let segs = &mut self.border_segments;
+        segs.right.renderer = Rc::clone(&renderer);
+        segs.left.renderer = Rc::clone(&renderer);
+        segs.top.renderer = Rc::clone(&renderer);
+        segs.bottom.renderer = renderer;
+        self
+    }
+    /// Renders a drop shadow under the block, offset to the right and
+    /// bottom by default, giving it visual depth.
+    pub const fn shadow(mut self, shadow: ShadowConfig) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+    /// Adds a glow around the border, colored from the border's
+    /// gradients.
+    pub const fn glow(mut self, glow: GlowConfig) -> Self {
+        self.glow = Some(glow);
+        self
+    }
+    /// Draws the border as a progress ring: only the first `value`
+    /// (0.0..=1.0) of the perimeter keeps its gradient, the rest uses
+    /// the track color. Keeps the start corner and track color already
+    /// set with [`Self::progress_config`].
+    pub fn progress(mut self, value: f32) -> Self {
+        let mut progress = self.progress.unwrap_or_default();
+        progress.value = value;
+        self.progress = Some(progress);
+        self
+    }
+    /// Sets the progress ring, including its start corner and track
+    /// color
+    pub const fn progress_config(
+        mut self,
+        progress: ProgressConfig,
+    ) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+    /// Turns the block into a gauge filled to `ratio` (0.0..=1.0) from
+    /// left to right, with `label` in the bottom border. Keeps the track
+    /// color already set with [`Self::gauge_config`].
+    pub fn gauge<S: Into<String>>(
+        mut self,
+        ratio: f32,
+        label: S,
+    ) -> Self {
+        let gauge = self.gauge.take().unwrap_or_default();
+        self.gauge = Some(GaugeConfig {
+            value: ratio,
+            label: Some(label.into()),
+            ..gauge
+        });
+        self
+    }
+    /// Like [`Self::gauge`], labeled with the percentage
+    pub fn gauge_percent(mut self, ratio: f32) -> Self {
+        let gauge = self.gauge.take().unwrap_or_default();
+        self.gauge = Some(GaugeConfig {
+            value: ratio,
+            label: None,
+            ..gauge
+        });
+        self
+    }
+    /// Sets the gauge, including its track color
+    pub fn gauge_config(mut self, gauge: GaugeConfig) -> Self {
+        self.gauge = Some(gauge);
+        self
+    }
+    /// Shows a value in a slot of the border of `side`, formatted with
+    /// `format` every time the block is rendered. The value is set with
+    /// [`Self::label_value`], or every frame with
+    /// `GradientBlockState::set_value`. See [`ValueLabel`].
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::enums::Side;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new()
+    ///     .value_label(Side::Bottom, |v| format!("{v:.1} GiB/s"));
+    /// ```
+    pub fn value_label<F: Fn(f64) -> String + 'static>(
+        mut self,
+        side: enums::Side,
+        format: F,
+    ) -> Self {
+        let value =
+            self.value_label.as_ref().map_or(0.0, |l| l.value);
+        self.value_label =
+            Some(ValueLabel::new(side, format).value(value));
+        self
+    }
+    /// Sets the value label, including where its slot goes and how
+    /// wide it is
+    pub fn value_label_config(mut self, label: ValueLabel) -> Self {
+        self.value_label = Some(label);
+        self
+    }
+    /// Sets the value shown by the value label when the block is
+    /// rendered without a state holding one
+    pub fn label_value(mut self, value: f64) -> Self {
+        if let Some(label) = &mut self.value_label {
+            label.value = value;
+        }
+        self
+    }
+    /// Shows a scrollbar in the right border for content of
+    /// `content_length` rows scrolled to `position`. Keeps the thumb and
+    /// track already set with [`Self::scrollbar_config`].
+    pub fn scrollbar(
+        mut self,
+        content_length: usize,
+        position: usize,
+    ) -> Self {
+        let scrollbar = self.scrollbar.unwrap_or_default();
+        self.scrollbar = Some(ScrollbarConfig {
+            scroll: ScrollPosition::new(content_length, position),
+            ..scrollbar
+        });
+        self
+    }
+    /// Sets the scrollbar, including its thumb and track
+    pub const fn scrollbar_config(
+        mut self,
+        scrollbar: ScrollbarConfig,
+    ) -> Self {
+        self.scrollbar = Some(scrollbar);
+        self
+    }
+    /// Shows an animated spinner in a corner of the border. Render the
+    /// block with a `GradientBlockState` and tick it to animate.
+    pub fn spinner(mut self, spinner: SpinnerConfig) -> Self {
+        self.spinner = Some(spinner);
+        self
+    }
+    /// Shows a tab bar in the top border with the `selected` tab
+    /// highlighted. Keeps the divider and highlight already set with
+    /// [`Self::tabs_config`].
+    pub fn tabs<S: ToString>(
+        mut self,
+        labels: &[S],
+        selected: usize,
+    ) -> Self {
+        let tabs = self.tabs.take().unwrap_or_default();
+        self.tabs = Some(TabsConfig {
+            labels: labels.iter().map(ToString::to_string).collect(),
+            selected,
+            ..tabs
+        });
+        self
+    }
+    /// Sets the tab bar, including its divider and highlight
+    pub fn tabs_config(mut self, tabs: TabsConfig) -> Self {
+        self.tabs = Some(tabs);
+        self
+    }
+    /// Shows keybinding hints in the bottom border, like
+    /// `─ q:quit ─ s:save ─`. When narrow, the later hints are dropped
+    /// first; use [`Self::hints_config`] to give them priorities.
+    pub fn hints<K: ToString, L: ToString>(
+        mut self,
+        hints: &[(K, L)],
+    ) -> Self {
+        self.hints = Some(HintsConfig::new(hints));
+        self
+    }
+    /// Sets the keybinding hints, see [`HintsConfig`]
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::structs::hints::Hint;
+    /// # use tui_gradient_block::structs::hints::HintsConfig;
+    /// let block = GradientBlock::new().hints_config(
+    ///     HintsConfig::default()
+    ///         .hint(Hint::new("q", "quit").priority(2))
+    ///         .hint(Hint::new("/", "search"))
+    ///         .hint(Hint::new("?", "help").priority(1)),
+    /// );
+    /// ```
+    pub fn hints_config(mut self, hints: HintsConfig) -> Self {
+        self.hints = Some(hints);
+        self
+    }
+    /// Shows a row of status dots on `side`, one per item, replacing
+    /// the ones already there. See [`Indicators`].
+    pub fn indicators(
+        mut self,
+        side: enums::Side,
+        states: &[IndicatorState],
+    ) -> Self {
+        self.indicators.retain(|i| i.side != side);
+        self.indicators.push(Indicators::new(side, states));
+        self
+    }
+    /// Sets what the block does when its area is too small for its
+    /// border and titles, see [`enums::MinSizePolicy`]
+    pub const fn min_size_policy(
+        mut self,
+        policy: enums::MinSizePolicy,
+    ) -> Self {
+        self.min_size_policy = policy;
+        self
+    }
+    /// Sets what happens to titles and fill text wider than their room,
+    /// see [`enums::Overflow`]
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::enums::Overflow;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new()
+    ///     .title_top(" /home/user/projects/some/deeply/nested/dir ")
+    ///     .overflow(Overflow::Ellipsis);
+    /// ```
+    pub const fn overflow(
+        mut self,
+        overflow: enums::Overflow,
+    ) -> Self {
+        self.overflow = overflow;
+        self
+    }
+    /// Draws the border progressively and fades the titles in when the
+    /// block appears. Render the block with a `GradientBlockState` and
+    /// advance it to animate.
+    pub const fn reveal(mut self, reveal: RevealConfig) -> Self {
+        self.reveal = Some(reveal);
+        self
+    }
+    /// Modulates the brightness of the border over time, on top of its
+    /// gradients. Render the block with a `GradientBlockState` and
+    /// advance it to animate.
+    pub const fn pulse(mut self, pulse: PulseConfig) -> Self {
+        self.pulse = Some(pulse);
+        self
+    }
+    /// Sweeps a highlight band around the border, on top of its
+    /// gradients. Render the block with a `GradientBlockState` and
+    /// advance it to animate.
+    pub const fn shimmer(mut self, shimmer: ShimmerConfig) -> Self {
+        self.shimmer = Some(shimmer);
+        self
+    }
+    /// Makes single border cells twinkle at random, on top of its
+    /// gradients and the pulse and shimmer. Render the block with a
+    /// `GradientBlockState` and advance it to animate.
+    pub const fn sparkle(mut self, sparkle: SparkleConfig) -> Self {
+        self.sparkle = Some(sparkle);
+        self
+    }
+    /// Colors the border from a gradient laid over the whole frame
+    /// instead of the segment gradients, so blocks sharing the scope
+    /// look like one continuous gradient.
+    pub fn gradient_scope(mut self, scope: GradientScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+    /// Draws a side over only part of its edge, with its gradient
+    /// running over the drawn part. Cut ends use the repeated symbol
+    /// instead of the corner. Left and right sides need a span of at
+    /// least three rows to be drawn.
+    pub fn segment_span(
+        mut self,
+        side: enums::Side,
+        span: SegmentSpan,
+    ) -> Self {
+        self.border_segments.get_mut(side).span = Some(span);
+        self
+    }
+    /// Draws the top over a fraction of its width
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// // a tab: only the left half of the top is drawn
+    /// let block = GradientBlock::new().top_span(0.0..0.5);
+    /// ```
+    pub fn top_span(self, span: std::ops::Range<f32>) -> Self {
+        self.segment_span(
+            enums::Side::Top,
+            SegmentSpan::Fraction(span),
+        )
+    }
+    /// Draws the bottom over a fraction of its width
+    pub fn bottom_span(self, span: std::ops::Range<f32>) -> Self {
+        self.segment_span(
+            enums::Side::Bottom,
+            SegmentSpan::Fraction(span),
+        )
+    }
+    /// Draws the left side over a fraction of its height
+    pub fn left_span(self, span: std::ops::Range<f32>) -> Self {
+        self.segment_span(
+            enums::Side::Left,
+            SegmentSpan::Fraction(span),
+        )
+    }
+    /// Draws the right side over a fraction of its height
+    pub fn right_span(self, span: std::ops::Range<f32>) -> Self {
+        self.segment_span(
+            enums::Side::Right,
+            SegmentSpan::Fraction(span),
+        )
+    }
+    /// Draws the top over the given cells, counted from its left end
+    pub fn top_range(
+        self,
+        range: std::ops::RangeInclusive<u16>,
+    ) -> Self {
+        self.segment_span(enums::Side::Top, SegmentSpan::Cells(range))
+    }
+    /// Draws the bottom over the given cells, counted from its left end
+    pub fn bottom_range(
+        self,
+        range: std::ops::RangeInclusive<u16>,
+    ) -> Self {
+        self.segment_span(
+            enums::Side::Bottom,
+            SegmentSpan::Cells(range),
+        )
+    }
+    /// Draws the left side over the given cells, counted from its top
+    pub fn left_range(
+        self,
+        range: std::ops::RangeInclusive<u16>,
+    ) -> Self {
+        self.segment_span(
+            enums::Side::Left,
+            SegmentSpan::Cells(range),
+        )
+    }
+    /// Draws the right side over the given cells, counted from its top
+    pub fn right_range(
+        self,
+        range: std::ops::RangeInclusive<u16>,
+    ) -> Self {
+        self.segment_span(
+            enums::Side::Right,
+            SegmentSpan::Cells(range),
+        )
+    }
+    /// Leaves gaps in a side where nothing is drawn, so other widgets
+    /// (buttons, tab labels, breadcrumbs) can sit in the border line.
+    /// Each gap is `(offset, length)` in cells from the start of the
+    /// edge (left or top). The gradient keeps running across the gaps.
+    pub fn segment_gaps(
+        mut self,
+        side: enums::Side,
+        gaps: &[(u16, u16)],
+    ) -> Self {
+        self.border_segments.get_mut(side).gaps = gaps.to_vec();
+        self
+    }
+    /// Leaves gaps in the top, see [`Self::segment_gaps`]
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new().top_gaps(&[(10, 4), (30, 6)]);
+    /// ```
+    pub fn top_gaps(self, gaps: &[(u16, u16)]) -> Self {
+        self.segment_gaps(enums::Side::Top, gaps)
+    }
+    /// Leaves gaps in the bottom, see [`Self::segment_gaps`]
+    pub fn bottom_gaps(self, gaps: &[(u16, u16)]) -> Self {
+        self.segment_gaps(enums::Side::Bottom, gaps)
+    }
+    /// Leaves gaps in the left side, see [`Self::segment_gaps`]
+    pub fn left_gaps(self, gaps: &[(u16, u16)]) -> Self {
+        self.segment_gaps(enums::Side::Left, gaps)
+    }
+    /// Leaves gaps in the right side, see [`Self::segment_gaps`]
+    pub fn right_gaps(self, gaps: &[(u16, u16)]) -> Self {
+        self.segment_gaps(enums::Side::Right, gaps)
+    }
+    /// Forces the glyph of a corner to `color`, while the rest of the
+    /// border keeps its gradient
+    /// # Example
+    /// ```
+    /// # use ratatui::style::Color;
+    /// # use tui_gradient_block::enums::Corner;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// // a red corner for unsaved changes
+    /// let block = GradientBlock::new().corner_color(Corner::TopRight, Color::Red);
+    /// ```
+    pub fn corner_color(
+        mut self,
+        corner: enums::Corner,
+        color: ratatui::style::Color,
+    ) -> Self {
+        self.corner_colors.retain(|(c, _)| *c != corner);
+        self.corner_colors.push((corner, color));
+        self
+    }
+    /// Draws `text` over the glyph of a corner, like a notification
+    /// count. Replaces the badge already in that corner; empty `text`
+    /// removes it. See [`Badge`].
+    /// # Example
+    /// ```
+    /// # use ratatui::style::Color;
+    /// # use ratatui::style::Style;
+    /// # use tui_gradient_block::enums::Corner;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new().badge(
+    ///     Corner::TopRight,
+    ///     "3",
+    ///     Style::new().fg(Color::Black).bg(Color::Yellow),
+    /// );
+    /// ```
+    pub fn badge(
+        mut self,
+        corner: enums::Corner,
+        text: impl Into<String>,
+        style: Style,
+    ) -> Self {
+        let text = text.into();
+        self.badges.retain(|b| b.corner != corner);
+        if !text.is_empty() {
+            self.badges.push(Badge::new(corner, text, style));
+        }
+        self
+    }
+    /// Draws tick marks along a side, see [`Ticks`]
+    pub fn segment_ticks(
+        mut self,
+        side: enums::Side,
+        ticks: Ticks,
+    ) -> Self {
+        self.border_segments.get_mut(side).ticks = Some(ticks);
+        self
+    }
+    /// Draws tick marks along the top, see [`Ticks`]
+    pub fn top_ticks(self, ticks: Ticks) -> Self {
+        self.segment_ticks(enums::Side::Top, ticks)
+    }
+    /// Draws tick marks along the bottom, see [`Ticks`]
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::structs::ticks::Ticks;
+    /// let block = GradientBlock::new().bottom_ticks(Ticks::every(10, '┴'));
+    /// ```
+    pub fn bottom_ticks(self, ticks: Ticks) -> Self {
+        self.segment_ticks(enums::Side::Bottom, ticks)
+    }
+    /// Draws tick marks along the left side, see [`Ticks`]
+    pub fn left_ticks(self, ticks: Ticks) -> Self {
+        self.segment_ticks(enums::Side::Left, ticks)
+    }
+    /// Draws tick marks along the right side, see [`Ticks`]
+    pub fn right_ticks(self, ticks: Ticks) -> Self {
+        self.segment_ticks(enums::Side::Right, ticks)
+    }
+    /// Draws a ruler (labeled ticks) along a side, see [`Ruler`]
+    pub fn segment_ruler(
+        mut self,
+        side: enums::Side,
+        ruler: Ruler,
+    ) -> Self {
+        self.border_segments.get_mut(side).ruler = Some(ruler);
+        self
+    }
+    /// Draws a ruler along the top, usually counting columns
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::structs::ruler::Ruler;
+    /// let block = GradientBlock::new().top_ruler(Ruler::new(10, '┬'));
+    /// ```
+    pub fn top_ruler(self, ruler: Ruler) -> Self {
+        self.segment_ruler(enums::Side::Top, ruler)
+    }
+    /// Draws a ruler along the bottom
+    pub fn bottom_ruler(self, ruler: Ruler) -> Self {
+        self.segment_ruler(enums::Side::Bottom, ruler)
+    }
+    /// Draws a ruler along the left side, usually counting rows
+    pub fn left_ruler(self, ruler: Ruler) -> Self {
+        self.segment_ruler(enums::Side::Left, ruler)
+    }
+    /// Draws a ruler along the right side
+    pub fn right_ruler(self, ruler: Ruler) -> Self {
+        self.segment_ruler(enums::Side::Right, ruler)
+    }
+    /// Makes the chosen sides `thickness` cells thick (at least 1).
+    /// The extra rows/columns are drawn inside the border with the
+    /// repeated symbols and gradient of the side, and [`Self::inner`]
+    /// shrinks accordingly.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::{gradient_block::GradientBlock, structs::flags::Sides};
+    /// let block = GradientBlock::new()
+    ///     .border_thickness(Sides::TOP | Sides::BOTTOM, 2);
+    /// ```
+    pub fn border_thickness(
+        mut self,
+        sides: flags::Sides,
+        thickness: u16,
+    ) -> Self {
+        use flags::Sides as S;
+        let thickness = thickness.max(1);
+        let segs = &mut self.border_segments;
+        if sides.contains(S::TOP) {
+            segs.top.thickness = thickness;
+        }
+        if sides.contains(S::BOTTOM) {
+            segs.bottom.thickness = thickness;
+        }
+        if sides.contains(S::LEFT) {
+            segs.left.thickness = thickness;
+        }
+        if sides.contains(S::RIGHT) {
+            segs.right.thickness = thickness;
+        }
+        self
+    }
     pub fn margin(mut self, horizontal: u16, vertical: u16) -> Self {
         let marg = ratatui::prelude::layout::Margin::new(
             horizontal, vertical,
         );
         let segs = &mut self.border_segments;
-        segs.bottom.seg.area_margin = marg;
-        segs.top.seg.area_margin = marg;
-        segs.right.seg.area_margin = marg;
-        segs.left.seg.area_margin = marg;
+        segs.bottom.area_margin = marg;
+        segs.top.area_margin = marg;
+        segs.right.area_margin = marg;
+        segs.left.area_margin = marg;
+        self
+    }
+    /// Sets a margin per side, applied on top of [`Self::margin`] and
+    /// reflected in [`Self::inner`], for asymmetric insets like status
+    /// bars and sidebars.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// // leave the first column free for a gutter
+    /// let block = GradientBlock::new().margin_sides(1, 0, 0, 0);
+    /// ```
+    pub const fn margin_sides(
+        mut self,
+        left: u16,
+        right: u16,
+        top: u16,
+        bottom: u16,
+    ) -> Self {
+        self.side_margins =
+            widgets::Padding::new(left, right, top, bottom);
+        self
+    }
+    /// Sets the margin of the left side, see [`Self::margin_sides`]
+    pub const fn left_margin(mut self, margin: u16) -> Self {
+        self.side_margins.left = margin;
+        self
+    }
+    /// Sets the margin of the right side, see [`Self::margin_sides`]
+    pub const fn right_margin(mut self, margin: u16) -> Self {
+        self.side_margins.right = margin;
+        self
+    }
+    /// Sets the margin of the top side, see [`Self::margin_sides`]
+    pub const fn top_margin(mut self, margin: u16) -> Self {
+        self.side_margins.top = margin;
+        self
+    }
+    /// Sets the margin of the bottom side, see [`Self::margin_sides`]
+    pub const fn bottom_margin(mut self, margin: u16) -> Self {
+        self.side_margins.bottom = margin;
         self
     }
     pub fn horizontal_margin(mut self, margin: u16) -> Self {
         let segs = &mut self.border_segments;
-        segs.bottom.seg.area_margin.horizontal = margin;
-        segs.top.seg.area_margin.horizontal = margin;
-        segs.right.seg.area_margin.horizontal = margin;
-        segs.left.seg.area_margin.horizontal = margin;
+        segs.bottom.area_margin.horizontal = margin;
+        segs.top.area_margin.horizontal = margin;
+        segs.right.area_margin.horizontal = margin;
+        segs.left.area_margin.horizontal = margin;
         self
     }
     pub fn vertical_margin(mut self, margin: u16) -> Self {
         let segs = &mut self.border_segments;
-        segs.bottom.seg.area_margin.vertical = margin;
-        segs.top.seg.area_margin.vertical = margin;
-        segs.right.seg.area_margin.vertical = margin;
-        segs.left.seg.area_margin.vertical = margin;
+        segs.bottom.area_margin.vertical = margin;
+        segs.top.area_margin.vertical = margin;
+        segs.right.area_margin.vertical = margin;
+        segs.left.area_margin.vertical = margin;
         self
     }
     pub fn right_padding(mut self, padding: u16) -> Self {
@@ -130,7 +914,8 @@ impl<'a> gradient_block::GradientBlock<'a> {
         }
         macro_rules! set_corner {
             ($seg:ident, $val:ident) => {
-                self.border_segments.$seg.seg.symbol_set.$val = ' '
+                self.border_segments.$seg.seg.symbols.$val =
+                    Symbol::BLANK
             };
         }
         if !borders.contains(B::RIGHT) {
@@ -174,7 +959,8 @@ impl<'a> gradient_block::GradientBlock<'a> {
         use flags::Corners as C;
         macro_rules! set_corner {
             ($seg:ident, $corner:ident) => {
-                self.border_segments.$seg.seg.symbol_set.$corner = ' '
+                self.border_segments.$seg.seg.symbols.$corner =
+                    Symbol::BLANK
             };
         }
         if !corners.contains(C::TOP_RIGHT) {
@@ -212,7 +998,8 @@ impl<'a> gradient_block::GradientBlock<'a> {
         use flags::CenterSymbols as S;
         macro_rules! set_symb {
             ($seg:ident) => {
-                self.border_segments.$seg.seg.symbol_set.center = ' '
+                self.border_segments.$seg.seg.symbols.center =
+                    Symbol::BLANK
             };
         }
         if !symbols.contains(S::LEFT_CENTER) {
@@ -235,10 +1022,12 @@ impl<'a> gradient_block::GradientBlock<'a> {
         }
         self
     }
+    /// Adds a title to the top side, like `Block::title_top`
     pub fn title_top<I: Into<Line<'a>>>(mut self, title: I) -> Self {
         self.titles.push((title.into(), Position::Top));
         self
     }
+    /// Adds a title to the bottom side, like `Block::title_bottom`
     pub fn title_bottom<I: Into<Line<'a>>>(
         mut self,
         title: I,
@@ -246,107 +1035,314 @@ impl<'a> gradient_block::GradientBlock<'a> {
         self.titles.push((title.into(), Position::Bottom));
         self
     }
-    /// Sets the border style for the block.
-    ///
-    /// If this function is not called, the border will be plain by default.
-    ///
-    /// # Parameters
-    /// - `style`: A `BorderStyle` enum value that determines the appearance of the border.
-    ///   - `BorderStyle::New`: Empty to be set manually.
-    ///   - `BorderStyle::Custom`: Custom border from `SegmentSet` struct
-    ///
-    /// # Example 1: Using a standard border style
+    /// Adds a title on the side `position` with `alignment`, so the
+    /// alignment doesn't have to be set on the `Line` beforehand. The
+    /// `title_*_left/center/right` methods are shorthands for it.
+    /// # Example
     /// ```
-    /// let border = GradientBlock::new().border_style(BorderStyle::Double);
+    /// # use ratatui::widgets::block::Position;
+    /// # use ratatui::layout::Alignment;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new()
+    ///     .title_at(Position::Top, Alignment::Right, "v1.2")
+    ///     .title_bottom_center("q to quit");
     /// ```
-    ///
-    /// # Example 2: Using a miscellaneous border style
+    pub fn title_at<I: Into<Line<'a>>>(
+        mut self,
+        position: Position,
+        alignment: prelude::Alignment,
+        title: I,
+    ) -> Self {
+        let title = title.into().alignment(alignment);
+        self.titles.push((title, position));
+        self
+    }
+    /// Adds a title at the left of the top side
+    pub fn title_top_left<I: Into<Line<'a>>>(self, title: I) -> Self {
+        self.title_at(Position::Top, prelude::Alignment::Left, title)
+    }
+    /// Adds a title in the middle of the top side
+    pub fn title_top_center<I: Into<Line<'a>>>(
+        self,
+        title: I,
+    ) -> Self {
+        self.title_at(
+            Position::Top,
+            prelude::Alignment::Center,
+            title,
+        )
+    }
+    /// Adds a title at the right of the top side
+    pub fn title_top_right<I: Into<Line<'a>>>(
+        self,
+        title: I,
+    ) -> Self {
+        self.title_at(Position::Top, prelude::Alignment::Right, title)
+    }
+    /// Adds a title at the left of the bottom side
+    pub fn title_bottom_left<I: Into<Line<'a>>>(
+        self,
+        title: I,
+    ) -> Self {
+        self.title_at(
+            Position::Bottom,
+            prelude::Alignment::Left,
+            title,
+        )
+    }
+    /// Adds a title in the middle of the bottom side
+    pub fn title_bottom_center<I: Into<Line<'a>>>(
+        self,
+        title: I,
+    ) -> Self {
+        self.title_at(
+            Position::Bottom,
+            prelude::Alignment::Center,
+            title,
+        )
+    }
+    /// Adds a title at the right of the bottom side
+    pub fn title_bottom_right<I: Into<Line<'a>>>(
+        self,
+        title: I,
+    ) -> Self {
+        self.title_at(
+            Position::Bottom,
+            prelude::Alignment::Right,
+            title,
+        )
+    }
+    /// Adds a label on the line of `side`, centered on `at` (0.0..=1.0)
+    /// of its length, for axis labels and markers anywhere along a side.
+    /// Labels are styled like titles.
+    /// # Example
     /// ```
-    /// let border = GradientBlock::new().with_border_style(BorderStyle::Custom(preset::MISC3));
+    /// # use tui_gradient_block::enums::Side;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new()
+    ///     .label(Side::Bottom, 0.25, "Q1")
+    ///     .label(Side::Bottom, 0.75, "Q3");
     /// ```
-    ///
-    /// # Example 3: Using a custom border type
+    pub fn label<I: Into<Line<'a>>>(
+        mut self,
+        side: enums::Side,
+        at: f32,
+        label: I,
+    ) -> Self {
+        self.labels.push((side, at, label.into()));
+        self
+    }
+    /// Uses the symbols of a ratatui `BorderType`, like
+    /// `Block::border_type`.
+    /// # Example
     /// ```
-    /// let border = GradientBlock::new()
-    ///     .with_border_style(BorderStyle::New)
-    ///     .top_left('╔')
-    ///     .top_right('╗')
-    ///     .bottom_left('╚')
-    ///     .bottom_right('╝');
+    /// # use ratatui::widgets::BorderType;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new().border_type(BorderType::Rounded);
     /// ```
-    /// Sets the border style of the block.
-    ///
-    /// This function allows setting a predefined border style or a custom one.
-    ///
-    /// # Parameters
-    /// - `style`: A `BorderStyle` enum variant specifying the desired border style.
-    ///
-    /// # Behavior
-    /// - `BorderStyle::CustomBorderType`: Does not set predefined symbols, allowing manual customization.
-    /// - `BorderStyle::MiscBorder(MiscBorderTypes)`: Uses a predefined miscellaneous border style.
-    /// - `BorderStyle::Plain`, `BorderStyle::Double`, `BorderStyle::Thick`, `BorderStyle::Rounded`:
-    ///   Sets the block's borders to one of these predefined styles.
-    ///
+    pub fn border_type(
+        self,
+        border_type: widgets::BorderType,
+    ) -> Self {
+        self.with_set(SS::from_ratatui_set(
+            border_type.to_border_set(),
+        ))
+    }
+    /// Sets the style of the whole block area, like `Block::style`.
+    /// The border gradients and title styles are drawn on top of it.
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+    /// Sets the style of the border cells, like `Block::border_style`.
+    /// Its foreground only shows on segments without a gradient.
+    pub const fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+    /// Sets the style of the titles, like `Block::title_style`. Styles
+    /// of the titles' own spans (gradients) take precedence.
+    pub const fn title_style(mut self, style: Style) -> Self {
+        self.title_style = style;
+        self
+    }
+    /// Sets the alignment of titles that don't have one, like
+    /// `Block::title_alignment`.
+    pub const fn title_alignment(
+        mut self,
+        alignment: prelude::Alignment,
+    ) -> Self {
+        self.title_alignment = Some(alignment);
+        self
+    }
+    /// Paints a background of `color` behind every title, so titles
+    /// stay readable on a busy gradient border.
     /// # Example
-    /// ```let
-    /// let block = GradientBlock::new().border_style(BorderStyle::Double);
     /// ```
-    pub fn with_border_style(
+    /// # use ratatui::style::Color;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new()
+    ///     .title_top(" Logs ")
+    ///     .title_bg(Color::Rgb(30, 30, 46));
+    /// ```
+    pub const fn title_bg(
+        self,
+        color: ratatui::style::Color,
+    ) -> Self {
+        self.title_background(TitleBackground::new(color))
+    }
+    /// Like [`Self::title_bg`], with rounded ends drawn with
+    /// [`TitleBackground::ROUND_CAPS`]. Needs a powerline or Nerd Font.
+    pub const fn title_pill(
+        self,
+        color: ratatui::style::Color,
+    ) -> Self {
+        self.title_background(TitleBackground::pill(color))
+    }
+    /// Sets the background painted behind the titles, see
+    /// [`TitleBackground`]
+    pub const fn title_background(
         mut self,
+        background: TitleBackground,
+    ) -> Self {
+        self.title_background = Some(background);
+        self
+    }
+    /// Moves the titles added so far to `position`, like
+    /// `Block::title_position`. Titles always have a position here, so
+    /// call it after adding the titles it should move.
+    pub fn title_position(mut self, position: Position) -> Self {
+        for (_, pos) in &mut self.titles {
+            *pos = position;
+        }
+        self
+    }
+    /// Sets the border symbols from a [`enums::BorderStyle`]: one of
+    /// ratatui's border types by name, a [`SS`], or a blank border to
+    /// fill in symbol by symbol. See [`Self::with_set`] for sets.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::enums::BorderStyle;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new().with_border_style(BorderStyle::Double);
+    /// let custom = GradientBlock::new()
+    ///     .with_border_style(BorderStyle::NewSet)
+    ///     .top_left('╔')
+    ///     .top_right('╗');
+    /// ```
+    pub fn with_border_style(
+        self,
         style: enums::BorderStyle,
     ) -> Self {
+        use enums::BorderStyle as S;
+        use widgets::BorderType as T;
         match style {
-            enums::BorderStyle::CustomSet(t) => {
-                self.border_segments =
-                    self.border_segments.from_segment_set(t);
+            S::CustomSet(set) => self.with_set(set),
+            S::NewSet => self.with_set(crate::preset::EMPTY),
+            S::RatatuiSet(set) => {
+                self.with_set(SS::from_ratatui_set(set))
             }
-            enums::BorderStyle::NewSet => {
-                self.border_segments = self
-                    .border_segments
-                    .from_segment_set(crate::preset::EMPTY);
+            S::Plain => self.border_type(T::Plain),
+            S::Double => self.border_type(T::Double),
+            S::Thick => self.border_type(T::Thick),
+            S::Rounded => self.border_type(T::Rounded),
+            S::QuadrantInside => self.border_type(T::QuadrantInside),
+            S::QuadrantOutside => {
+                self.border_type(T::QuadrantOutside)
             }
-            enums::BorderStyle::RatatuiSet(t) => {
-                self.border_segments = self
-                    .border_segments
-                    .from_segment_set(SS::from_ratatui_set(t));
-            }
-        };
-        self
+        }
     }
 
-    /// Sets the titles that appear at the bottom of the border.
-    ///
-    /// # Parameters
-    /// - `titles`: A vector of tuples where each tuple contains:
-    ///   - A `String` representing the title text.
-    ///   - A `Alignment` indicating how the title should be aligned (e.g., left, center, right).
-    ///   - An optional tuple containing a vector of RGB colors and a gradient factor (f32).
-    ///
+    /// Replaces the titles with `titles`, each a line and the side it
+    /// is drawn on
     /// # Example
     /// ```
-    /// let border = Border::new().bottom_titles(vec![
-    ///     ("Footer", Alignment::Center, Some((vec![(255, 0, 0), (190, 3, 252)], 0.5))),
-    /// ]);
+    /// # use ratatui::widgets::block::Position;
+    /// # use ratatui::text::Line;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let titles = [(Line::from("Logs"), Position::Top)];
+    /// let block = GradientBlock::new().titles(&titles);
     /// ```
     pub fn titles(mut self, titles: &'a [(Line, Position)]) -> Self {
         self.titles = titles.to_vec();
         self
     }
+    /// Adds a title on the side `pos`; see [`Self::title_top`] and
+    /// [`Self::title_bottom`] for anything that converts into a `Line`
     pub fn title(mut self, title: Line<'a>, pos: Position) -> Self {
         self.titles.push((title, pos));
         self
     }
+    /// Adds titles from an iterator of `(title, position)` pairs
+    /// # Example
+    /// ```
+    /// # use ratatui::widgets::block::Position;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let block = GradientBlock::new().with_titles([
+    ///     ("Logs", Position::Top),
+    ///     ("q to quit", Position::Bottom),
+    /// ]);
+    /// ```
+    pub fn with_titles<L, I>(mut self, titles: I) -> Self
+    where
+        L: Into<Line<'a>>,
+        I: IntoIterator<Item = (L, Position)>,
+    {
+        self.titles.extend(
+            titles
+                .into_iter()
+                .map(|(title, pos)| (title.into(), pos)),
+        );
+        self
+    }
+    /// Removes every title, in place, for blocks kept around between
+    /// frames
+    pub fn clear_titles(&mut self) {
+        self.titles.clear();
+    }
+    /// Replaces the title at `index` (in the order titles were added) in
+    /// place, returning the old one. Does nothing and returns `None` if
+    /// there is no such title.
+    /// # Example
+    /// ```
+    /// # use ratatui::widgets::block::Position;
+    /// # let items = ["a", "b"];
+    /// # let mut block = tui_gradient_block::gradient_block::GradientBlock::new()
+    /// #     .title_top("Files")
+    /// #     .title_bottom("");
+    /// block.replace_title(1, format!("{} items", items.len()), Position::Bottom);
+    /// ```
+    pub fn replace_title<L: Into<Line<'a>>>(
+        &mut self,
+        index: usize,
+        title: L,
+        pos: Position,
+    ) -> Option<(Line<'a>, Position)> {
+        self.titles
+            .get_mut(index)
+            .map(|old| std::mem::replace(old, (title.into(), pos)))
+    }
+    /// Removes the title at `index` in place, returning it
+    pub fn remove_title(
+        &mut self,
+        index: usize,
+    ) -> Option<(Line<'a>, Position)> {
+        (index < self.titles.len()).then(|| self.titles.remove(index))
+    }
     /// Sets the symbol for the top-right corner of the border.
     /// # Parameters
     /// - `symb`: A `char` representing the symbol to be used in the top-right corner.
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let border = GradientBlock::new().top_right('#');
     /// ```
     pub const fn top_right(mut self, symb: char) -> Self {
-        self.border_segments.right.seg.symbol_set.start = symb;
-        self.border_segments.top.seg.symbol_set.end = symb;
+        self.border_segments.right.seg.symbols.start =
+            Symbol::from_char(symb);
+        self.border_segments.top.seg.symbols.end =
+            Symbol::from_char(symb);
         self
     }
 
@@ -357,11 +1353,14 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let border = GradientBlock::new().top_left('*');
     /// ```
     pub const fn top_left(mut self, symb: char) -> Self {
-        self.border_segments.left.seg.symbol_set.start = symb;
-        self.border_segments.top.seg.symbol_set.start = symb;
+        self.border_segments.left.seg.symbols.start =
+            Symbol::from_char(symb);
+        self.border_segments.top.seg.symbols.start =
+            Symbol::from_char(symb);
         self
     }
 
@@ -372,11 +1371,14 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let border = GradientBlock::new().bottom_right('%');
     /// ```
     pub const fn bottom_right(mut self, symb: char) -> Self {
-        self.border_segments.bottom.seg.symbol_set.end = symb;
-        self.border_segments.right.seg.symbol_set.end = symb;
+        self.border_segments.bottom.seg.symbols.end =
+            Symbol::from_char(symb);
+        self.border_segments.right.seg.symbols.end =
+            Symbol::from_char(symb);
         self
     }
 
@@ -387,11 +1389,14 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let border = GradientBlock::new().bottom_left('@');
     /// ```
     pub const fn bottom_left(mut self, symb: char) -> Self {
-        self.border_segments.bottom.seg.symbol_set.start = symb;
-        self.border_segments.left.seg.symbol_set.end = symb;
+        self.border_segments.bottom.seg.symbols.start =
+            Symbol::from_char(symb);
+        self.border_segments.left.seg.symbols.end =
+            Symbol::from_char(symb);
         self
     }
 
@@ -402,14 +1407,17 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
-    /// let border = GradientBlockr::new().bottom_horizontal_symbol('-');
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let border = GradientBlock::new().bottom_horizontal_symbol('-');
     /// ```
     pub const fn bottom_horizontal_symbol(
         mut self,
         symb: char,
     ) -> Self {
-        self.border_segments.bottom.seg.symbol_set.rep_1 = symb;
-        self.border_segments.bottom.seg.symbol_set.rep_2 = symb;
+        self.border_segments.bottom.seg.symbols.rep_1 =
+            Symbol::from_char(symb);
+        self.border_segments.bottom.seg.symbols.rep_2 =
+            Symbol::from_char(symb);
 
         self
     }
@@ -421,11 +1429,14 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
-    /// let border = Border::new().top_horizontal_symbol('=');
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let border = GradientBlock::new().top_horizontal_symbol('=');
     /// ```
     pub const fn top_horizontal_symbol(mut self, symb: char) -> Self {
-        self.border_segments.top.seg.symbol_set.rep_1 = symb;
-        self.border_segments.top.seg.symbol_set.rep_2 = symb;
+        self.border_segments.top.seg.symbols.rep_1 =
+            Symbol::from_char(symb);
+        self.border_segments.top.seg.symbols.rep_2 =
+            Symbol::from_char(symb);
         self
     }
 
@@ -436,22 +1447,28 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let border = GradientBlock::new().right_vertical_symbol('|');
     /// ```
     pub const fn right_vertical_symbol(mut self, symb: char) -> Self {
-        self.border_segments.right.seg.symbol_set.rep_1 = symb;
-        self.border_segments.right.seg.symbol_set.rep_2 = symb;
+        self.border_segments.right.seg.symbols.rep_1 =
+            Symbol::from_char(symb);
+        self.border_segments.right.seg.symbols.rep_2 =
+            Symbol::from_char(symb);
         self
     }
     /// Sets the left vertical border symbol.
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let widget = GradientBlock::new().left_vertical_symbol('|');
     /// ```
     pub const fn left_vertical_symbol(mut self, symb: char) -> Self {
-        self.border_segments.left.seg.symbol_set.rep_1 = symb;
-        self.border_segments.left.seg.symbol_set.rep_2 = symb;
+        self.border_segments.left.seg.symbols.rep_1 =
+            Symbol::from_char(symb);
+        self.border_segments.left.seg.symbols.rep_2 =
+            Symbol::from_char(symb);
         self
     }
 
@@ -459,10 +1476,12 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let widget = GradientBlock::new().top_center_symbol('─');
     /// ```
     pub const fn top_center_symbol(mut self, symb: char) -> Self {
-        self.border_segments.top.seg.symbol_set.center = symb;
+        self.border_segments.top.seg.symbols.center =
+            Symbol::from_char(symb);
         self
     }
 
@@ -470,10 +1489,12 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let widget = GradientBlock::new().bottom_center_symbol('═');
     /// ```
     pub const fn bottom_center_symbol(mut self, symb: char) -> Self {
-        self.border_segments.bottom.seg.symbol_set.center = symb;
+        self.border_segments.bottom.seg.symbols.center =
+            Symbol::from_char(symb);
         self
     }
 
@@ -481,10 +1502,12 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let widget = GradientBlock::new().left_center_symbol('+');
     /// ```
     pub const fn left_center_symbol(mut self, symb: char) -> Self {
-        self.border_segments.left.seg.symbol_set.center = symb;
+        self.border_segments.left.seg.symbols.center =
+            Symbol::from_char(symb);
         self
     }
 
@@ -492,10 +1515,12 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let widget = GradientBlock::new().right_center_symbol('+');
     /// ```
     pub const fn right_center_symbol(mut self, symb: char) -> Self {
-        self.border_segments.right.seg.symbol_set.center = symb;
+        self.border_segments.right.seg.symbols.center =
+            Symbol::from_char(symb);
         self
     }
 
@@ -503,23 +1528,27 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let widget = GradientBlock::new().top_horizontal_right_symbol('┐');
     /// ```
     pub fn top_horizontal_right_symbol(mut self, symb: char) -> Self {
-        self.border_segments.top.seg.symbol_set.rep_2 = symb;
+        self.border_segments.top.seg.symbols.rep_2 =
+            Symbol::from_char(symb);
         self
     }
     /// Sets the symbol used for the repeated section of the bottom horizontal border (right side).
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let block = GradientBlock::new().bottom_horizontal_right_symbol('*');
     /// ```
     pub const fn bottom_horizontal_right_symbol(
         mut self,
         symb: char,
     ) -> Self {
-        self.border_segments.bottom.seg.symbol_set.rep_2 = symb;
+        self.border_segments.bottom.seg.symbols.rep_2 =
+            Symbol::from_char(symb);
         self
     }
 
@@ -527,13 +1556,15 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let block = GradientBlock::new().top_horizontal_left_symbol('=');
     /// ```
     pub const fn top_horizontal_left_symbol(
         mut self,
         symb: char,
     ) -> Self {
-        self.border_segments.top.seg.symbol_set.rep_1 = symb;
+        self.border_segments.top.seg.symbols.rep_1 =
+            Symbol::from_char(symb);
         self
     }
 
@@ -541,13 +1572,15 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let block = GradientBlock::new().bottom_horizontal_left_symbol('=');
     /// ```
     pub const fn bottom_horizontal_left_symbol(
         mut self,
         symb: char,
     ) -> Self {
-        self.border_segments.bottom.seg.symbol_set.rep_1 = symb;
+        self.border_segments.bottom.seg.symbols.rep_1 =
+            Symbol::from_char(symb);
         self
     }
 
@@ -555,13 +1588,15 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let block = GradientBlock::new().top_vertical_right_symbol('|');
     /// ```
     pub const fn top_vertical_right_symbol(
         mut self,
         symb: char,
     ) -> Self {
-        self.border_segments.right.seg.symbol_set.rep_1 = symb;
+        self.border_segments.right.seg.symbols.rep_1 =
+            Symbol::from_char(symb);
         self
     }
 
@@ -569,13 +1604,15 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let block = GradientBlock::new().bottom_vertical_right_symbol('|');
     /// ```
     pub const fn bottom_vertical_right_symbol(
         mut self,
         symb: char,
     ) -> Self {
-        self.border_segments.right.seg.symbol_set.rep_2 = symb;
+        self.border_segments.right.seg.symbols.rep_2 =
+            Symbol::from_char(symb);
         self
     }
 
@@ -583,18 +1620,33 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let block = GradientBlock::new().top_vertical_left_symbol('|');
     /// ```
     pub const fn top_vertical_left_symbol(
         mut self,
         symb: char,
     ) -> Self {
-        self.border_segments.left.seg.symbol_set.rep_1 = symb;
+        self.border_segments.left.seg.symbols.rep_1 =
+            Symbol::from_char(symb);
         self
     }
+    /// Uses the symbols of `set` for every side, replacing any symbols
+    /// set before. Pass [`crate::preset::EMPTY`] to start from a blank
+    /// border and set the symbols one by one.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::preset;
+    /// let block = GradientBlock::new().with_set(preset::MISC3);
+    /// let custom = GradientBlock::new()
+    ///     .with_set(preset::EMPTY)
+    ///     .top_left('╔')
+    ///     .top_right('╗');
+    /// ```
     pub fn with_set(mut self, set: SS) -> Self {
-        self = self
-            .with_border_style(enums::BorderStyle::CustomSet(set));
+        self.border_segments =
+            self.border_segments.from_segment_set(set);
         self
     }
 
@@ -602,22 +1654,158 @@ impl<'a> gradient_block::GradientBlock<'a> {
     ///
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let block = GradientBlock::new().bottom_vertical_left_symbol('|');
     /// ```
     pub const fn bottom_vertical_left_symbol(
         mut self,
         symb: char,
     ) -> Self {
-        self.border_segments.left.seg.symbol_set.rep_2 = symb;
+        self.border_segments.left.seg.symbols.rep_2 =
+            Symbol::from_char(symb);
+        self
+    }
+    /// Sets how the block is blended with what is already in the buffer.
+    ///
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::enums::BlendMode;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// let modal = GradientBlock::new().blend_mode(BlendMode::Multiply);
+    /// ```
+    pub const fn blend_mode(
+        mut self,
+        mode: enums::BlendMode,
+    ) -> Self {
+        self.blend_mode = mode;
+        self
+    }
+    /// Blanks the whole area of the block before drawing, like
+    /// ratatui's `Clear`, so popups don't show stale content between the
+    /// border and the fill. See [`Self::clear_mode`] to only blank the
+    /// inside of the border.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(20, 5)).unwrap();
+    /// # terminal.draw(|f| {
+    /// # let popup_area = f.area();
+    /// f.render_widget(GradientBlock::new().clear(true), popup_area);
+    /// # }).unwrap();
+    /// ```
+    pub const fn clear(mut self, clear: bool) -> Self {
+        self.clear = match clear {
+            true => enums::ClearMode::Area,
+            false => enums::ClearMode::None,
+        };
+        self
+    }
+    /// Sets what the block blanks before drawing
+    pub const fn clear_mode(
+        mut self,
+        mode: enums::ClearMode,
+    ) -> Self {
+        self.clear = mode;
+        self
+    }
+    /// Sets how the block treats cells that already have content.
+    /// `Compositing::PreserveContent` only draws onto blank cells, for
+    /// decorative frames on top of existing widgets.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::enums::Compositing;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # let table = ratatui::widgets::Table::default();
+    /// # let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(20, 5)).unwrap();
+    /// # terminal.draw(|f| {
+    /// # let area = f.area();
+    /// f.render_widget(table, area);
+    /// f.render_widget(
+    ///     GradientBlock::new().compositing(Compositing::PreserveContent),
+    ///     area,
+    /// );
+    /// # }).unwrap();
+    /// ```
+    pub const fn compositing(
+        mut self,
+        compositing: enums::Compositing,
+    ) -> Self {
+        self.compositing = compositing;
+        self
+    }
+    /// Renders the block without any colors, only the plain glyphs.
+    ///
+    /// This also happens automatically when `NO_COLOR` is set.
+    pub const fn monochrome(mut self, monochrome: bool) -> Self {
+        self.monochrome = monochrome;
+        self
+    }
+    /// Renders the border for low vision: every border color is snapped
+    /// to a color of maximal contrast against the background, keeping
+    /// its hue where possible, and the glyphs are heavy and bold. Glow
+    /// is left out, as it only adds mid-tones.
+    pub const fn high_contrast(mut self, enabled: bool) -> Self {
+        self.high_contrast = enabled;
+        self
+    }
+    /// When rendering in monochrome, keeps the gradient's intensity as
+    /// `DIM`/`BOLD` modifiers instead of dropping it entirely.
+    pub const fn intensity_modifiers(
+        mut self,
+        enabled: bool,
+    ) -> Self {
+        self.intensity_modifiers = enabled;
+        self
+    }
+    /// Limits the colors the block renders with to the given depth,
+    /// mapping rgb gradients to the closest palette colors.
+    pub const fn color_depth(
+        mut self,
+        depth: enums::ColorDepth,
+    ) -> Self {
+        self.color_depth = depth;
         self
     }
     pub fn fill<L: Into<Line<'a>>>(mut self, fill: L) -> Self {
         self.fill = fill.into();
         self
     }
+    /// Animates the background of the inner area with `effect`, see
+    /// [`Effect`]. Render the block with a state to make it move.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::structs::effect::Effect;
+    /// # use tui_gradient_block::theme_presets::dark::t_midnight_blurple;
+    /// let block = GradientBlock::new()
+    ///     .fill_effect(Effect::Plasma(t_midnight_blurple::d_to_l()));
+    /// ```
+    pub fn fill_effect(mut self, effect: Effect) -> Self {
+        self.effect = Some(effect);
+        self
+    }
+    /// Tiles the inner area with `pattern`, under the fill
+    /// # Example
+    /// ```
+    /// # use ratatui::style::Color;
+    /// # use tui_gradient_block::enums::GradientDirection;
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
+    /// # use tui_gradient_block::structs::pattern::Pattern;
+    /// let placeholder = GradientBlock::new().fill_pattern(Pattern::Stripes {
+    ///     chars: ['╱', ' '],
+    ///     colors: [Color::DarkGray, Color::Reset],
+    ///     width: 2,
+    ///     direction: GradientDirection::Diagonal,
+    /// });
+    /// ```
+    pub const fn fill_pattern(mut self, pattern: Pattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
     /// Sets the fill gradient
     /// # Example
     /// ```
+    /// # use tui_gradient_block::gradient_block::GradientBlock;
     /// let block = GradientBlock::new().fill_gradient(colorgrad::preset::warm());
     /// ```
     pub fn fill_gradient<GR: colorgrad::Gradient>(
@@ -625,7 +1813,7 @@ impl<'a> gradient_block::GradientBlock<'a> {
         gradient: GR,
     ) -> Self {
         self.fill =
-            Line::from(generate_gradient_text!(self.fill, gradient));
+            gradient_line(std::mem::take(&mut self.fill), &gradient);
         self
     }
 }
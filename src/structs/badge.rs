@@ -0,0 +1,86 @@
+use crate::enums::Corner;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Margin, Rect},
+    style::Style,
+    text::Span,
+};
+/// A short label, like a notification count, drawn over a corner of the
+/// border.
+///
+/// The badge covers the corner glyph and runs along the top or bottom
+/// side, away from the corner, so it never leaves the block's area.
+/// # Example
+/// ```
+/// # use ratatui::style::Color;
+/// # use ratatui::style::Style;
+/// # use tui_gradient_block::enums::Corner;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # let unread = 3;
+/// let inbox = GradientBlock::new().badge(
+///     Corner::TopRight,
+///     unread.to_string(),
+///     Style::new().fg(Color::Black).bg(Color::Red),
+/// );
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Badge {
+    pub corner: Corner,
+    pub text: String,
+    pub style: Style,
+}
+impl Badge {
+    pub fn new(
+        corner: Corner,
+        text: impl Into<String>,
+        style: Style,
+    ) -> Self {
+        Self {
+            corner,
+            text: text.into(),
+            style,
+        }
+    }
+    /// The cells the badge takes up for a block rendered in `area` with
+    /// `margin` around its border, cut off to the width of the block.
+    /// `None` if the block is empty.
+    pub fn area(&self, area: Rect, margin: Margin) -> Option<Rect> {
+        let area = area.inner(margin);
+        if area.is_empty() {
+            return None;
+        }
+        let width = (Span::raw(self.text.as_str()).width() as u16)
+            .min(area.width);
+        let pos = self.corner.position(area);
+        let x = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => pos.x,
+            Corner::TopRight | Corner::BottomRight => {
+                (pos.x + 1).saturating_sub(width)
+            }
+        };
+        Some(Rect::new(x, pos.y, width, 1))
+    }
+    /// Draws the badge over the border
+    pub fn render(
+        &self,
+        area: Rect,
+        margin: Margin,
+        buf: &mut Buffer,
+    ) {
+        let Some(badge) = self.area(area, margin) else {
+            return;
+        };
+        buf.set_style(badge, self.style);
+        buf.set_stringn(
+            badge.x,
+            badge.y,
+            &self.text,
+            badge.width as usize,
+            self.style,
+        );
+    }
+}
@@ -1,11 +1,157 @@
-use crate::structs::border_symbols::SegmentSet;
-use ratatui::prelude::Alignment;
-use tui_rule::{
-    presets::borders::plain::*, Rule, Set, VerticalAlignment,
+use crate::{
+    enums::Side,
+    preset,
+    renderer::{self, RuleRenderer, SegmentRenderer},
+    structs::{
+        border_symbols::{SegmentSet, SymbolSet},
+        ruler::Ruler,
+        ticks::Ticks,
+    },
+    types::G,
 };
+use ratatui::{
+    buffer::Buffer,
+    layout::{Margin, Position, Rect},
+    widgets::Padding,
+};
+use std::rc::Rc;
+#[cfg(feature = "tui-rule-compat")]
+use tui_rule::{Rule, VerticalAlignment};
 pub struct BorderSegment {
     pub should_be_rendered: bool,
-    pub seg: Rule,
+    /// The symbols, gradient and padding the side is drawn with
+    pub seg: Segment,
+    /// The margin around the block, inside which the side is drawn
+    pub area_margin: Margin,
+    pub side: Side,
+    pub renderer: Rc<dyn SegmentRenderer>,
+    /// How many rows (top/bottom) or columns (left/right) the segment
+    /// takes up. 1 by default
+    pub thickness: u16,
+    /// The part of its edge the segment covers, the whole edge if `None`
+    pub span: Option<SegmentSpan>,
+    /// Runs of `(offset, length)` cells along the edge, counted from its
+    /// start inside the margin, where nothing is drawn
+    pub gaps: Vec<(u16, u16)>,
+    /// Tick marks along the line
+    pub ticks: Option<Ticks>,
+    /// Labeled ticks along the line, drawn over `ticks`
+    pub ruler: Option<Ruler>,
+}
+/// The look of one side, as passed to `GradientBlock::top` and
+/// friends: its symbols, gradient and padding. Where the side is drawn
+/// (orientation, alignment, margin) follows from the side it is set on.
+///
+/// Converts from a `tui_rule::Rule` with the `tui-rule-compat` feature,
+/// but keeps `tui_rule` out of the block's API so apps don't need the
+/// exact same version of it.
+/// # Example
+/// ```
+/// # use tui_gradient_block::{gradient_block::GradientBlock, preset};
+/// # use tui_gradient_block::structs::border_segment::Segment;
+/// # use tui_gradient_block::structs::border_symbols::Symbol;
+/// # use tui_gradient_block::structs::border_symbols::SymbolSet;
+/// let block = GradientBlock::new().top(
+///     Segment::new(SymbolSet {
+///         start: '╭'.into(),
+///         center: Symbol::new("🔥").unwrap(),
+///         end: '╮'.into(),
+///         ..preset::ROUNDED.top
+///     })
+///         .gradient(Box::new(colorgrad::preset::warm())),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Segment {
+    pub symbols: SymbolSet,
+    pub gradient: Option<G>,
+    pub padding: Padding,
+}
+impl Segment {
+    pub fn new(symbols: SymbolSet) -> Self {
+        Self {
+            symbols,
+            gradient: None,
+            padding: Padding::ZERO,
+        }
+    }
+    pub fn gradient(mut self, gradient: G) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+}
+#[cfg(feature = "tui-rule-compat")]
+impl From<Rule> for Segment {
+    fn from(rule: Rule) -> Self {
+        Self {
+            symbols: rule.symbol_set.into(),
+            gradient: rule.gradient,
+            padding: rule.padding,
+        }
+    }
+}
+#[cfg(feature = "tui-rule-compat")]
+impl Segment {
+    /// The segment as a `tui_rule::Rule` drawing `side` of an area the
+    /// margin was already taken off. Symbols are cut down to their
+    /// first char.
+    pub fn to_rule(&self, side: Side) -> Rule {
+        let rule = Rule::from_set(self.symbols.into())
+            .area_margin(Margin::new(0, 0));
+        let mut rule = match side {
+            Side::Top | Side::Bottom => rule.horizontal(),
+            Side::Left | Side::Right => rule.vertical(),
+        };
+        match side {
+            Side::Top => {
+                rule.vertical_alignment = VerticalAlignment::Top
+            }
+            Side::Bottom => {
+                rule.vertical_alignment = VerticalAlignment::Bottom
+            }
+            Side::Left => {
+                rule.horizontal_alignment =
+                    ratatui::layout::Alignment::Left
+            }
+            Side::Right => {
+                rule.horizontal_alignment =
+                    ratatui::layout::Alignment::Right
+            }
+        }
+        rule.gradient = self.gradient.clone();
+        rule.padding = self.padding;
+        rule
+    }
+}
+/// The part of its edge a segment covers, for tab-like blocks and
+/// accents that don't frame the whole widget
+#[derive(Clone, Debug, PartialEq)]
+pub enum SegmentSpan {
+    /// A fraction of the edge, from its start (left or top)
+    Fraction(std::ops::Range<f32>),
+    /// Cells of the edge, counted from its start
+    Cells(std::ops::RangeInclusive<u16>),
+}
+impl SegmentSpan {
+    /// The offset and length of the span along an edge of `len` cells,
+    /// clamped to the edge
+    pub fn resolve(&self, len: u16) -> (u16, u16) {
+        let (start, end) = match self {
+            Self::Fraction(r) => {
+                let at = |f: f32| {
+                    (f.clamp(0.0, 1.0) * len as f32).round() as u16
+                };
+                (at(r.start), at(r.end))
+            }
+            Self::Cells(r) => (*r.start(), r.end().saturating_add(1)),
+        };
+        let (start, end) = (start.min(len), end.min(len));
+        (start, end.saturating_sub(start))
+    }
 }
 /// A collection of border segments representing different parts of a bordered structure.  
 ///
@@ -32,39 +178,537 @@ impl BorderSegments {
     /// A `BorderSegments` instance with all segments initialized at their respective positions.
     pub fn new() -> Self {
         let mut new_self = Self {
-            top: BorderSegment::new(false, TOP),
-            bottom: BorderSegment::new(false, BOTTOM),
-            left: BorderSegment::new(true, LEFT),
-            right: BorderSegment::new(true, RIGHT),
+            top: BorderSegment::new(false, preset::PLAIN.top),
+            bottom: BorderSegment::new(false, preset::PLAIN.bottom),
+            left: BorderSegment::new(true, preset::PLAIN.left),
+            right: BorderSegment::new(true, preset::PLAIN.right),
         };
-        new_self.right.seg.horizontal_alignment = Alignment::Right;
-        new_self.left.seg.horizontal_alignment = Alignment::Left;
-        new_self.bottom.seg.vertical_alignment =
-            VerticalAlignment::Bottom;
-        new_self.top.seg.vertical_alignment = VerticalAlignment::Top;
+        new_self.bottom.side = Side::Bottom;
+        new_self.right.side = Side::Right;
         new_self
     }
+    /// The segments that will be rendered, in rendering order
+    pub fn rendered(&self) -> impl Iterator<Item = &BorderSegment> {
+        [&self.left, &self.right, &self.top, &self.bottom]
+            .into_iter()
+            .filter(|s| s.should_be_rendered)
+    }
+    /// Every cell the rendered segments draw in `area`, row by row
+    pub fn cells(&self, area: Rect) -> Vec<(Position, String)> {
+        drawn_cells(area, |layer| {
+            for seg in self.rendered() {
+                self.render_side(seg.side, area, 0.0, layer);
+            }
+        })
+    }
+    /// Every cell one side draws in `area`, row by row
+    pub fn side_cells(
+        &self,
+        side: Side,
+        area: Rect,
+    ) -> Vec<(Position, String)> {
+        drawn_cells(area, |layer| {
+            self.render_side(side, area, 0.0, layer)
+        })
+    }
+    pub fn get(&self, side: Side) -> &BorderSegment {
+        match side {
+            Side::Top => &self.top,
+            Side::Bottom => &self.bottom,
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        }
+    }
+    pub fn get_mut(&mut self, side: Side) -> &mut BorderSegment {
+        match side {
+            Side::Top => &mut self.top,
+            Side::Bottom => &mut self.bottom,
+            Side::Left => &mut self.left,
+            Side::Right => &mut self.right,
+        }
+    }
+    /// How many rows or columns a side takes up, 0 when it is hidden
+    pub fn thickness(&self, side: Side) -> u16 {
+        let seg = self.get(side);
+        match seg.should_be_rendered {
+            true => seg.thickness,
+            false => 0,
+        }
+    }
+    /// Renders a side, including the extra bands of a side thicker than
+    /// one cell. The bands are drawn inside the border with the repeated
+    /// symbols of the segment and its gradient.
+    ///
+    /// Safe at any size: nothing is drawn outside `area`, and a side is
+    /// left out when `area` is too small for it (see
+    /// [`BorderSegment::fits`]). A single row gets only the top, two
+    /// rows the top and bottom, and the sides need three rows.
+    pub fn render_side(
+        &self,
+        side: Side,
+        area: Rect,
+        phase: f32,
+        buf: &mut Buffer,
+    ) {
+        self.render_side_in(
+            side,
+            area,
+            phase,
+            &mut Buffer::default(),
+            buf,
+        );
+    }
+    /// [`Self::render_side`] drawing on `scratch`, which is resized to
+    /// `area` and can be reused for the next side or frame
+    pub fn render_side_in(
+        &self,
+        side: Side,
+        area: Rect,
+        phase: f32,
+        scratch: &mut Buffer,
+        buf: &mut Buffer,
+    ) {
+        let visible = area.intersection(buf.area);
+        if visible.is_empty() {
+            return;
+        }
+        // drawn on a copy of the area, so the side can't touch anything
+        // outside of it
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            ?side,
+            reused = scratch.area.area() == area.area(),
+            "side scratch buffer"
+        );
+        scratch.resize(area);
+        if visible != area {
+            scratch.reset();
+        }
+        // copied a row at a time, indexing cell by cell costs more than
+        // drawing the side itself
+        let cells = |buf: &Buffer, row: Rect| {
+            let start = buf.index_of(row.x, row.y);
+            start..start + row.width as usize
+        };
+        for row in visible.rows() {
+            let (from, to) = (cells(buf, row), cells(scratch, row));
+            scratch.content[to].clone_from_slice(&buf.content[from]);
+        }
+        self.draw_side(side, area, phase, scratch);
+        let seg = self.get(side);
+        if seg.gaps.is_empty() {
+            for row in visible.rows() {
+                let (to, from) =
+                    (cells(buf, row), cells(scratch, row));
+                buf.content[to]
+                    .clone_from_slice(&scratch.content[from]);
+            }
+            return;
+        }
+        for pos in visible.positions() {
+            if !seg.in_gap(area, pos) {
+                buf[pos].clone_from(&scratch[pos]);
+            }
+        }
+    }
+    fn draw_side(
+        &self,
+        side: Side,
+        area: Rect,
+        phase: f32,
+        buf: &mut Buffer,
+    ) {
+        let seg = self.get(side);
+        // only a spanned segment needs its own copy of the segment
+        let spanned = seg.span.as_ref().map(|_| seg.spanned(area));
+        let (segment, seg_area) = match &spanned {
+            Some((segment, seg_area)) => (segment, *seg_area),
+            None => (&seg.seg, area),
+        };
+        if !seg.fits(segment, seg_area) {
+            return;
+        }
+        seg.renderer.render_segment(
+            side,
+            segment,
+            seg_area.inner(seg.area_margin),
+            phase,
+            buf,
+        );
+        if let Some(ticks) = &seg.ticks {
+            seg.render_ticks(ticks, area, seg_area, buf);
+        }
+        if let Some(ruler) = &seg.ruler {
+            seg.render_ruler(ruler, area, seg_area, buf);
+        }
+        if seg.thickness <= 1 {
+            return;
+        }
+        let band = seg.band();
+        // bands run between the outer corners, meeting the bands of the
+        // other sides
+        let edge = |side| self.thickness(side).min(1);
+        let (l, r) = (edge(Side::Left), edge(Side::Right));
+        let (t, b) = (edge(Side::Top), edge(Side::Bottom));
+        for k in 1..seg.thickness {
+            let band_area = match side {
+                Side::Top | Side::Bottom => Rect {
+                    x: area.x.saturating_add(l),
+                    y: match side {
+                        Side::Top => area.y.saturating_add(k),
+                        _ => area.y,
+                    },
+                    width: area.width.saturating_sub(l + r),
+                    height: area.height.saturating_sub(k),
+                },
+                Side::Left | Side::Right => Rect {
+                    x: match side {
+                        Side::Left => area.x.saturating_add(k),
+                        _ => area.x,
+                    },
+                    y: area.y.saturating_add(t),
+                    width: area.width.saturating_sub(k),
+                    height: area.height.saturating_sub(t + b),
+                },
+            };
+            let band_area = match seg.span {
+                Some(_) => band_area.intersection(seg_area),
+                None => band_area,
+            };
+            if seg.fits(&band, band_area) {
+                seg.renderer.render_segment(
+                    side,
+                    &band,
+                    band_area.inner(seg.area_margin),
+                    phase,
+                    buf,
+                );
+            }
+        }
+    }
     pub fn from_segment_set(mut self, set: SegmentSet) -> Self {
-        self.right.seg = self.right.seg.with_set(set.right);
-        self.left.seg = self.left.seg.with_set(set.left);
-        self.top.seg = self.top.seg.with_set(set.top);
-        self.bottom.seg = self.bottom.seg.with_set(set.bottom);
+        self.right.seg.symbols = set.right;
+        self.left.seg.symbols = set.left;
+        self.top.seg.symbols = set.top;
+        self.bottom.seg.symbols = set.bottom;
         self
     }
 }
 impl BorderSegment {
-    /// The segment starts with a plain rule
-    /// x and y are 0 by default,
+    /// Takes the symbols, gradient and padding of `seg`, keeping where
+    /// the side is drawn
+    pub fn set_segment(&mut self, seg: Segment) {
+        self.seg = seg;
+    }
+    /// The segment starts with `set` and no gradient, padding or margin
     /// # Returns
     /// A `BorderSegment` instance with default values
-    pub fn new(is_vertical: bool, set: Set) -> Self {
+    pub fn new(is_vertical: bool, set: SymbolSet) -> Self {
         Self {
             should_be_rendered: true,
-            seg: match is_vertical {
-                true => Rule::from_set(set).vertical(),
-                false => Rule::from_set(set).horizontal(),
+            side: match is_vertical {
+                true => Side::Left,
+                false => Side::Top,
+            },
+            renderer: Rc::new(RuleRenderer),
+            thickness: 1,
+            span: None,
+            gaps: Vec::new(),
+            ticks: None,
+            ruler: None,
+            seg: Segment::new(set),
+            area_margin: Margin::new(0, 0),
+        }
+    }
+    /// The cells this segment draws in `area` and their symbols,
+    /// in order along the segment (left to right or top to bottom).
+    pub fn cells(&self, area: Rect) -> Vec<(Position, String)> {
+        drawn_cells(area, |layer| self.render(area, 0.0, layer))
+    }
+    /// A copy of the segment for the extra bands of a thick segment,
+    /// with [`Self::band_symbols`] as its symbols
+    pub fn band(&self) -> Segment {
+        Segment {
+            symbols: self.band_symbols(),
+            ..self.seg.clone()
+        }
+    }
+    /// The symbols of the extra bands of a thick segment: only the
+    /// repeated symbols, no corners or center
+    pub fn band_symbols(&self) -> SymbolSet {
+        let s = self.seg.symbols;
+        SymbolSet {
+            start: s.rep_1,
+            center: s.rep_1,
+            end: s.rep_2,
+            ..s
+        }
+    }
+    /// The segment and area it draws with in `area` once its
+    /// [`SegmentSpan`] is applied. The area is cut down to the span
+    /// along the edge, so the gradient runs over the drawn part only,
+    /// and ends of the span that don't reach a corner use the repeated
+    /// symbols instead of the corner ones.
+    pub fn spanned(&self, area: Rect) -> (Segment, Rect) {
+        let mut seg = self.seg.clone();
+        let Some(span) = &self.span else {
+            return (seg, area);
+        };
+        let horizontal =
+            matches!(self.side, Side::Top | Side::Bottom);
+        // the span is measured inside the margin, which is taken off
+        // again when drawing
+        let m = self.area_margin;
+        let len = match horizontal {
+            true => area.width.saturating_sub(m.horizontal * 2),
+            false => area.height.saturating_sub(m.vertical * 2),
+        };
+        let (offset, span_len) = span.resolve(len);
+        seg.symbols = self.spanned_symbols(area);
+        let area = match horizontal {
+            true => Rect {
+                x: area.x + offset,
+                width: span_len + m.horizontal * 2,
+                ..area
+            },
+            false => Rect {
+                y: area.y + offset,
+                height: span_len + m.vertical * 2,
+                ..area
+            },
+        };
+        (seg, area)
+    }
+    /// The symbols the segment draws with in `area` once its
+    /// [`SegmentSpan`] is applied, see [`Self::spanned`]
+    pub fn spanned_symbols(&self, area: Rect) -> SymbolSet {
+        let mut symbols = self.seg.symbols;
+        let Some(span) = &self.span else {
+            return symbols;
+        };
+        let (offset, span_len) = span.resolve(self.edge_len(area));
+        if offset > 0 {
+            symbols.start = symbols.rep_1;
+        }
+        if offset + span_len < self.edge_len(area) {
+            symbols.end = symbols.rep_2;
+        }
+        symbols
+    }
+    /// The cell of the segment's line at `offset` cells from the start of
+    /// the edge in `area` (inside the margin), `None` past its end
+    pub fn line_position(
+        &self,
+        area: Rect,
+        offset: u16,
+    ) -> Option<Position> {
+        let inner = area.inner(self.area_margin);
+        let p = self.seg.padding;
+        if inner.is_empty() {
+            return None;
+        }
+        let pos = match self.side {
+            Side::Top | Side::Bottom => {
+                let x = inner.x.checked_add(offset)?;
+                let y = match self.side {
+                    Side::Top => inner.y.saturating_add(p.top),
+                    _ => inner.bottom().checked_sub(p.bottom + 1)?,
+                };
+                (x >= inner.x + p.left
+                    && x < inner.right().saturating_sub(p.right))
+                .then_some(Position::new(x, y))?
+            }
+            Side::Left | Side::Right => {
+                let y = inner.y.checked_add(offset)?;
+                let x = match self.side {
+                    Side::Left => inner.x.saturating_add(p.left),
+                    _ => inner.right().checked_sub(p.right + 1)?,
+                };
+                (y >= inner.y + p.top
+                    && y < inner.bottom().saturating_sub(p.bottom))
+                .then_some(Position::new(x, y))?
+            }
+        };
+        inner.contains(pos).then_some(pos)
+    }
+    /// The length of the segment's edge in `area`, inside the margin
+    pub fn edge_len(&self, area: Rect) -> u16 {
+        let inner = area.inner(self.area_margin);
+        match self.side {
+            Side::Top | Side::Bottom => inner.width,
+            Side::Left | Side::Right => inner.height,
+        }
+    }
+    /// The offsets of the first run of cells on the segment's line in
+    /// `area`, between the corners, that `reserved` (like the spans of
+    /// the titles) leaves free
+    pub fn free_run(
+        &self,
+        area: Rect,
+        reserved: &[Rect],
+    ) -> std::ops::Range<u16> {
+        let end = self.edge_len(area).saturating_sub(1);
+        let free = |offset| {
+            self.line_position(area, offset).is_some_and(|pos| {
+                !reserved.iter().any(|r| r.contains(pos))
+            })
+        };
+        let start = (1..end).find(|&o| free(o)).unwrap_or(end);
+        start..(start..end).find(|&o| !free(o)).unwrap_or(end)
+    }
+    /// Draws `ticks` on the segment's line. Offsets are measured over
+    /// the whole edge in `area`, and only the ones inside `drawn` (the
+    /// area the segment was drawn in, less when it has a span) are drawn.
+    fn render_ticks(
+        &self,
+        ticks: &Ticks,
+        area: Rect,
+        drawn: Rect,
+        buf: &mut Buffer,
+    ) {
+        let drawn = drawn.inner(self.area_margin);
+        for offset in ticks.offsets(self.edge_len(area)) {
+            let Some(pos) = self.line_position(area, offset) else {
+                continue;
+            };
+            if !drawn.contains(pos) {
+                continue;
+            }
+            if let Some(cell) = buf.cell_mut(pos) {
+                cell.set_char(ticks.symbol);
+                if let Some(color) = ticks.color {
+                    cell.fg = color;
+                }
+            }
+        }
+    }
+    /// Draws the ticks of `ruler` and writes a label after each, see
+    /// [`Self::render_ticks`]
+    fn render_ruler(
+        &self,
+        ruler: &Ruler,
+        area: Rect,
+        drawn: Rect,
+        buf: &mut Buffer,
+    ) {
+        self.render_ticks(&ruler.ticks, area, drawn, buf);
+        let drawn = drawn.inner(self.area_margin);
+        let len = self.edge_len(area);
+        let offsets = ruler.ticks.offsets(len);
+        let ends = offsets
+            .iter()
+            .skip(1)
+            .copied()
+            .chain([len.saturating_sub(1)]);
+        for (offset, end) in offsets.iter().copied().zip(ends) {
+            let label = ruler.labels.label(offset, len);
+            let Some(positions) = (1..)
+                .zip(label.chars())
+                .map(|(i, c)| {
+                    (offset + i < end)
+                        .then(|| self.line_position(area, offset + i))
+                        .flatten()
+                        .filter(|pos| drawn.contains(*pos))
+                        .map(|pos| (pos, c))
+                })
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            for (pos, c) in positions {
+                if let Some(cell) = buf.cell_mut(pos) {
+                    cell.set_char(c);
+                    if let Some(color) = ruler.color {
+                        cell.fg = color;
+                    }
+                }
             }
-            .area_margin(ratatui::layout::Margin::new(0, 0)),
         }
     }
+    /// Whether `pos` falls in one of the segment's gaps when it is
+    /// drawn in `area`. The gradient still runs over the whole edge, so
+    /// the runs on either side of a gap line up as if the line went on
+    /// behind whatever sits in it.
+    pub fn in_gap(&self, area: Rect, pos: Position) -> bool {
+        if self.gaps.is_empty() {
+            return false;
+        }
+        let inner = area.inner(self.area_margin);
+        let depth = self.thickness.max(1);
+        let (along, across) = match self.side {
+            Side::Top => (
+                pos.x.checked_sub(inner.x),
+                pos.y.checked_sub(inner.y),
+            ),
+            Side::Bottom => (
+                pos.x.checked_sub(inner.x),
+                inner.bottom().checked_sub(pos.y + 1),
+            ),
+            Side::Left => (
+                pos.y.checked_sub(inner.y),
+                pos.x.checked_sub(inner.x),
+            ),
+            Side::Right => (
+                pos.y.checked_sub(inner.y),
+                inner.right().checked_sub(pos.x + 1),
+            ),
+        };
+        let (Some(along), Some(across)) = (along, across) else {
+            return false;
+        };
+        across < depth
+            && self.gaps.iter().any(|&(offset, len)| {
+                along >= offset && along - offset < len
+            })
+    }
+    /// Whether `seg` can be drawn for this segment's side in `area`
+    /// without running out of it: the area inside the margin needs room
+    /// for the padding, and left and right sides need room for their
+    /// whole (padded) line, at least three rows.
+    pub fn fits(&self, seg: &Segment, area: Rect) -> bool {
+        let inner = area.inner(self.area_margin);
+        let p = seg.padding;
+        if inner.is_empty() {
+            return false;
+        }
+        let line_len =
+            || renderer::RuleLayout::new(self.side, p, inner).len();
+        match self.side {
+            Side::Top => inner.height > p.top,
+            Side::Bottom => {
+                inner.height >= 2 && inner.height > p.bottom
+            }
+            Side::Left => {
+                inner.width > p.left
+                    && line_len() <= inner.height as usize
+            }
+            Side::Right => {
+                inner.width > p.right
+                    && line_len() <= inner.height as usize
+            }
+        }
+    }
+    /// Renders the segment with its renderer
+    pub fn render(&self, area: Rect, phase: f32, buf: &mut Buffer) {
+        self.renderer.render_segment(
+            self.side,
+            &self.seg,
+            area.inner(self.area_margin),
+            phase,
+            buf,
+        );
+    }
+}
+/// Renders segments into a scratch layer and returns the cells they
+/// touched and their whole symbols, as `Cell::symbol` has them, row by
+/// row. Where segments overlap the last one wins, like when they are
+/// rendered into a buffer.
+fn drawn_cells(
+    area: Rect,
+    render: impl FnOnce(&mut Buffer),
+) -> Vec<(Position, String)> {
+    renderer::layer(area, render)
+        .into_iter()
+        .map(|(pos, cell)| (pos, cell.symbol().to_string()))
+        .collect()
 }
@@ -1,16 +1,248 @@
+#[cfg(feature = "serde")]
+use crate::types::E;
 use derive_builder::Builder;
 use getset::{Getters, Setters};
-use tui_rule::Set;
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize)
 )]
 #[derive(Getters, Setters, Builder, Clone, Debug)]
 pub struct SegmentSet {
-    pub top: Set,
-    pub bottom: Set,
-    pub right: Set,
-    pub left: Set,
+    pub top: SymbolSet,
+    pub bottom: SymbolSet,
+    pub right: SymbolSet,
+    pub left: SymbolSet,
+}
+/// One border symbol: a single grapheme cluster, such as a box-drawing
+/// char, an emoji or a letter with combining marks.
+///
+/// Stored inline (up to [`Symbol::CAPACITY`] bytes of utf-8), so it is
+/// `Copy` and can be made in constants with [`Symbol::from_char`].
+/// Serializes as a string.
+/// # Example
+/// ```
+/// # use tui_gradient_block::structs::border_symbols::Symbol;
+/// const DOT: Symbol = Symbol::from_char('•');
+/// let fire = Symbol::new("🔥").unwrap();
+/// assert_eq!(fire.width(), 2);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    bytes: [u8; Symbol::CAPACITY],
+    len: u8,
+}
+impl Symbol {
+    /// The most utf-8 bytes a symbol can hold, enough for emoji with
+    /// modifiers and most zwj sequences
+    pub const CAPACITY: usize = 23;
+    /// A space, drawn for hidden corners and center symbols
+    pub const BLANK: Self = Self::from_char(' ');
+    pub const fn from_char(c: char) -> Self {
+        let mut bytes = [0; Self::CAPACITY];
+        let len = c.encode_utf8(&mut bytes).len();
+        Self {
+            bytes,
+            len: len as u8,
+        }
+    }
+    /// The symbol made of `s`, or [`Symbol::BLANK`] if it is empty or
+    /// longer than [`Symbol::CAPACITY`] bytes. Usable in constants.
+    pub const fn new_or_blank(s: &str) -> Self {
+        let b = s.as_bytes();
+        if b.is_empty() || b.len() > Self::CAPACITY {
+            return Self::BLANK;
+        }
+        let mut bytes = [0; Self::CAPACITY];
+        let mut i = 0;
+        while i < b.len() {
+            bytes[i] = b[i];
+            i += 1;
+        }
+        Self {
+            bytes,
+            len: b.len() as u8,
+        }
+    }
+    /// The symbol made of `s`, `None` if it is empty or longer than
+    /// [`Symbol::CAPACITY`] bytes
+    pub fn new(s: &str) -> Option<Self> {
+        if s.is_empty() || s.len() > Self::CAPACITY {
+            return None;
+        }
+        let mut bytes = [0; Self::CAPACITY];
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+        Some(Self {
+            bytes,
+            len: s.len() as u8,
+        })
+    }
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..self.len as usize])
+            .unwrap_or(" ")
+    }
+    /// The first char of the symbol, for code that only handles chars
+    pub fn first_char(&self) -> char {
+        self.as_str().chars().next().unwrap_or(' ')
+    }
+    /// The number of terminal cells the symbol takes up, at least 1
+    pub fn width(&self) -> usize {
+        match self.len {
+            1 => 1,
+            _ => {
+                ratatui::text::Span::raw(self.as_str()).width().max(1)
+            }
+        }
+    }
+}
+impl Default for Symbol {
+    fn default() -> Self {
+        Self::BLANK
+    }
+}
+impl From<char> for Symbol {
+    fn from(c: char) -> Self {
+        Self::from_char(c)
+    }
+}
+impl std::fmt::Debug for Symbol {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+impl std::fmt::Display for Symbol {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl PartialEq<char> for Symbol {
+    fn eq(&self, other: &char) -> bool {
+        *self == Self::from_char(*other)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Symbol {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Symbol {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::new(&s).ok_or_else(|| {
+            serde::de::Error::invalid_length(
+                s.len(),
+                &"a symbol of 1 to 23 bytes",
+            )
+        })
+    }
+}
+/// The symbols of one side: the two ends (the corners of the block),
+/// the symbol in the middle and the repeated symbols on either side of
+/// it. Serializes the same as `tui_rule::Set` (chars become strings),
+/// which it converts to and from with the `tui-rule-compat` feature, so
+/// the crate's API doesn't depend on the exact `tui_rule` version an app
+/// uses. As `tui_rule::Set` only holds chars, converting to it keeps the
+/// first char of each symbol.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(
+    Getters, Setters, Builder, Clone, Copy, Debug, PartialEq, Eq,
+)]
+pub struct SymbolSet {
+    pub start: Symbol,
+    pub end: Symbol,
+    pub rep_1: Symbol,
+    pub rep_2: Symbol,
+    pub center: Symbol,
+}
+impl SymbolSet {
+    /// A set with `line` as its center and repeated symbols
+    pub const fn from_chars(
+        start: char,
+        line: char,
+        end: char,
+    ) -> Self {
+        let line = Symbol::from_char(line);
+        Self {
+            start: Symbol::from_char(start),
+            end: Symbol::from_char(end),
+            rep_1: line,
+            rep_2: line,
+            center: line,
+        }
+    }
+    /// [`Self::from_chars`] for symbols
+    pub const fn from_symbols(
+        start: Symbol,
+        line: Symbol,
+        end: Symbol,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            rep_1: line,
+            rep_2: line,
+            center: line,
+        }
+    }
+    /// The set with `center` as its center symbol
+    pub const fn with_center(mut self, center: char) -> Self {
+        self.center = Symbol::from_char(center);
+        self
+    }
+    /// The symbols of a `tui_rule::Set`, usable in constants
+    #[cfg(feature = "tui-rule-compat")]
+    pub const fn from_rule_set(set: tui_rule::Set) -> Self {
+        Self {
+            start: Symbol::from_char(set.start),
+            end: Symbol::from_char(set.end),
+            rep_1: Symbol::from_char(set.rep_1),
+            rep_2: Symbol::from_char(set.rep_2),
+            center: Symbol::from_char(set.center),
+        }
+    }
+    /// Whether `set` has the same symbols, for code that still goes
+    /// through `tui_rule`
+    #[cfg(feature = "tui-rule-compat")]
+    pub fn matches_rule_set(&self, set: &tui_rule::Set) -> bool {
+        self.start == set.start
+            && self.end == set.end
+            && self.rep_1 == set.rep_1
+            && self.rep_2 == set.rep_2
+            && self.center == set.center
+    }
+}
+#[cfg(feature = "tui-rule-compat")]
+impl From<tui_rule::Set> for SymbolSet {
+    fn from(set: tui_rule::Set) -> Self {
+        Self::from_rule_set(set)
+    }
+}
+#[cfg(feature = "tui-rule-compat")]
+impl From<SymbolSet> for tui_rule::Set {
+    fn from(set: SymbolSet) -> Self {
+        Self {
+            start: set.start.first_char(),
+            end: set.end.first_char(),
+            rep_1: set.rep_1.first_char(),
+            rep_2: set.rep_2.first_char(),
+            center: set.center.first_char(),
+        }
+    }
 }
 #[cfg_attr(
     feature = "serde",
@@ -28,51 +260,76 @@ impl SegmentSet {
     pub fn from_json(path: &str) -> Result<Self, E> {
         crate::generate_from_json!(path, Self)
     }
-    pub fn from_ratatui_set(
-        set: ratatui::symbols::border::Set,
+    /// A set with the given corners and one symbol for the top and
+    /// bottom and one for the sides, usable in constants
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::structs::border_symbols::SegmentSet;
+    /// const DASHED: SegmentSet =
+    ///     SegmentSet::from_chars('┌', '┐', '└', '┘', '╌', '╎');
+    /// ```
+    pub const fn from_chars(
+        top_left: char,
+        top_right: char,
+        bottom_left: char,
+        bottom_right: char,
+        horizontal: char,
+        vertical: char,
     ) -> Self {
-        macro_rules! parsed {
-            ($s:expr) => {
-                $s.parse::<char>().unwrap_or(' ')
-            };
+        Self {
+            top: SymbolSet::from_chars(
+                top_left, horizontal, top_right,
+            ),
+            bottom: SymbolSet::from_chars(
+                bottom_left,
+                horizontal,
+                bottom_right,
+            ),
+            right: SymbolSet::from_chars(
+                top_right,
+                vertical,
+                bottom_right,
+            ),
+            left: SymbolSet::from_chars(
+                top_left,
+                vertical,
+                bottom_left,
+            ),
         }
-        let top = parsed!(set.horizontal_top);
-        let bottom = parsed!(set.horizontal_bottom);
-        let right = parsed!(set.vertical_right);
-        let left = parsed!(set.vertical_left);
-        let top_right = parsed!(set.top_right);
-        let top_left = parsed!(set.top_left);
-        let bottom_right = parsed!(set.bottom_right);
-        let bottom_left = parsed!(set.bottom_left);
+    }
+    /// The set of a ratatui border set, every symbol kept as it is,
+    /// including the block elements of the quadrant, one eighth and
+    /// proportional sets. Usable in constants, so
+    /// `SegmentSet::from_ratatui_set(border::ROUNDED)` costs nothing at
+    /// runtime.
+    pub const fn from_ratatui_set(
+        set: ratatui::symbols::border::Set,
+    ) -> Self {
+        let top = Symbol::new_or_blank(set.horizontal_top);
+        let bottom = Symbol::new_or_blank(set.horizontal_bottom);
+        let right = Symbol::new_or_blank(set.vertical_right);
+        let left = Symbol::new_or_blank(set.vertical_left);
+        let top_right = Symbol::new_or_blank(set.top_right);
+        let top_left = Symbol::new_or_blank(set.top_left);
+        let bottom_right = Symbol::new_or_blank(set.bottom_right);
+        let bottom_left = Symbol::new_or_blank(set.bottom_left);
         Self {
-            top: Set {
-                start: top_left,
-                rep_1: top,
-                center: top,
-                rep_2: top,
-                end: top_right,
-            },
-            bottom: Set {
-                start: bottom_left,
-                rep_1: bottom,
-                center: bottom,
-                rep_2: bottom,
-                end: bottom_right,
-            },
-            right: Set {
-                start: top_right,
-                rep_1: right,
-                center: right,
-                rep_2: right,
-                end: bottom_right,
-            },
-            left: Set {
-                start: top_left,
-                rep_1: left,
-                center: left,
-                rep_2: left,
-                end: bottom_left,
-            },
+            top: SymbolSet::from_symbols(top_left, top, top_right),
+            bottom: SymbolSet::from_symbols(
+                bottom_left,
+                bottom,
+                bottom_right,
+            ),
+            right: SymbolSet::from_symbols(
+                top_right,
+                right,
+                bottom_right,
+            ),
+            left: SymbolSet::from_symbols(
+                top_left,
+                left,
+                bottom_left,
+            ),
         }
     }
 }
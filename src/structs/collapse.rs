@@ -0,0 +1,178 @@
+use crate::gradient_block::GradientBlock;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    symbols::line,
+};
+/// Collapses the borders of adjacent blocks into shared lines.
+///
+/// Takes the areas of a layout (rects that touch each other, like the
+/// output of `Layout::split`) and grows each area by one cell towards the
+/// areas right of and below it, so neighbours draw their common edge on
+/// the same line. The corners of each block are replaced with the
+/// junction glyphs (`├ ┬ ┤ ┴ ┼`) of the lines meeting there.
+/// # Example
+/// ```
+/// # use ratatui::layout::Constraint;
+/// # use ratatui::layout::Layout;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::collapse::BorderCollapse;
+/// # let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(30, 5)).unwrap();
+/// # terminal.draw(|f| {
+/// # let area = f.area();
+/// let rects = Layout::horizontal([Constraint::Fill(1); 3]).split(area);
+/// let collapse = BorderCollapse::new(rects.iter().copied());
+/// for (i, area) in collapse.areas().iter().enumerate() {
+///     let block = collapse.apply(i, GradientBlock::new());
+///     f.render_widget(block, *area);
+/// }
+/// collapse.render_junctions(f.buffer_mut());
+/// # }).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct BorderCollapse {
+    areas: Vec<Rect>,
+    line_set: line::Set,
+}
+/// Which lines leave a junction
+#[derive(Clone, Copy, Default)]
+struct Arms {
+    up: bool,
+    down: bool,
+    left: bool,
+    right: bool,
+}
+impl BorderCollapse {
+    /// Collapses the borders of `rects`, using plain lines for the
+    /// junctions
+    pub fn new(rects: impl IntoIterator<Item = Rect>) -> Self {
+        let rects: Vec<Rect> = rects.into_iter().collect();
+        let areas = rects
+            .iter()
+            .map(|a| {
+                let overlaps_v =
+                    |b: &Rect| b.y < a.bottom() && a.y < b.bottom();
+                let overlaps_h =
+                    |b: &Rect| b.x < a.right() && a.x < b.right();
+                let right = rects
+                    .iter()
+                    .any(|b| b.x == a.right() && overlaps_v(b));
+                let below = rects
+                    .iter()
+                    .any(|b| b.y == a.bottom() && overlaps_h(b));
+                Rect {
+                    width: a.width.saturating_add(right as u16),
+                    height: a.height.saturating_add(below as u16),
+                    ..*a
+                }
+            })
+            .collect();
+        Self {
+            areas,
+            line_set: line::NORMAL,
+        }
+    }
+    /// Sets the lines the junction glyphs are taken from
+    /// (`line::THICK`, `line::DOUBLE`, ...)
+    pub const fn line_set(mut self, line_set: line::Set) -> Self {
+        self.line_set = line_set;
+        self
+    }
+    /// The areas to render the blocks in, in the order they were given
+    pub fn areas(&self) -> &[Rect] {
+        &self.areas
+    }
+    /// The lines leaving `pos` along the edges of every area
+    fn arms(&self, pos: Position) -> Arms {
+        let mut arms = Arms::default();
+        for a in self.areas.iter().filter(|a| !a.is_empty()) {
+            let (right, bottom) = (a.right() - 1, a.bottom() - 1);
+            let on_col = pos.y >= a.y && pos.y <= bottom;
+            let on_row = pos.x >= a.x && pos.x <= right;
+            if on_col && (pos.x == a.x || pos.x == right) {
+                arms.up |= pos.y > a.y;
+                arms.down |= pos.y < bottom;
+            }
+            if on_row && (pos.y == a.y || pos.y == bottom) {
+                arms.left |= pos.x > a.x;
+                arms.right |= pos.x < right;
+            }
+        }
+        arms
+    }
+    /// The junction glyph at `pos`
+    pub fn junction(&self, pos: Position) -> char {
+        let l = &self.line_set;
+        let arms = self.arms(pos);
+        match (arms.up, arms.down, arms.left, arms.right) {
+            (true, true, true, true) => l.cross,
+            (true, true, false, true) => l.vertical_right,
+            (true, true, true, false) => l.vertical_left,
+            (false, true, true, true) => l.horizontal_down,
+            (true, false, true, true) => l.horizontal_up,
+            (false, true, false, true) => l.top_left,
+            (false, true, true, false) => l.top_right,
+            (true, false, false, true) => l.bottom_left,
+            (true, false, true, false) => l.bottom_right,
+            (true, true, false, false)
+            | (true, false, false, false) => l.vertical,
+            (false, true, false, false) => l.vertical,
+            _ => l.horizontal,
+        }
+        .chars()
+        .next()
+        .unwrap_or(' ')
+    }
+    /// The corners of an area: top left, top right, bottom left,
+    /// bottom right
+    fn corners(a: Rect) -> [Position; 4] {
+        let (right, bottom) = (
+            a.right().saturating_sub(1),
+            a.bottom().saturating_sub(1),
+        );
+        [
+            Position::new(a.x, a.y),
+            Position::new(right, a.y),
+            Position::new(a.x, bottom),
+            Position::new(right, bottom),
+        ]
+    }
+    /// Replaces the corners of the block rendered in the `index`th area
+    /// with the junction glyphs. Out of range indexes leave the block
+    /// as it is.
+    pub fn apply<'a>(
+        &self,
+        index: usize,
+        mut block: GradientBlock<'a>,
+    ) -> GradientBlock<'a> {
+        let Some(area) = self.areas.get(index) else {
+            return block;
+        };
+        let [tl, tr, bl, br] =
+            Self::corners(*area).map(|p| self.junction(p));
+        let segs = &mut block.border_segments;
+        segs.top.seg.symbols.start = tl.into();
+        segs.left.seg.symbols.start = tl.into();
+        segs.top.seg.symbols.end = tr.into();
+        segs.right.seg.symbols.start = tr.into();
+        segs.bottom.seg.symbols.start = bl.into();
+        segs.left.seg.symbols.end = bl.into();
+        segs.bottom.seg.symbols.end = br.into();
+        segs.right.seg.symbols.end = br.into();
+        block
+    }
+    /// Redraws the junction glyph at every corner, keeping the colors
+    /// already there. Call after rendering all the blocks, as a block
+    /// drawn later can cover the junctions of its neighbours with its
+    /// straight edges.
+    pub fn render_junctions(&self, buf: &mut Buffer) {
+        for area in self.areas.iter().filter(|a| !a.is_empty()) {
+            for pos in Self::corners(*area) {
+                let glyph = self.junction(pos);
+                if let Some(cell) = buf.cell_mut(pos) {
+                    cell.set_char(glyph);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{renderer::sample, types::G};
+use ratatui::{buffer::Buffer, layout::Rect};
+use std::time::Duration;
+/// An animated background for the inner area of a block, for splash
+/// screens and music players.
+///
+/// Effects only set the background of the cells, so the fill, patterns
+/// and content drawn afterwards stay on top. They move with the
+/// `elapsed` time of the
+/// [`GradientBlockState`](crate::structs::state::GradientBlockState)
+/// the block is rendered with and stand still without one.
+/// # Example
+/// ```no_run
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::effect::Effect;
+/// # use tui_gradient_block::{clock::SystemClock, structs::state::GradientBlockState};
+/// # fn main() -> std::io::Result<()> {
+/// # let mut terminal = ratatui::init();
+/// # let mut state = GradientBlockState::default();
+/// # let clock = SystemClock::new();
+/// let splash = GradientBlock::new()
+///     .fill_effect(Effect::Plasma(Box::new(colorgrad::preset::sinebow())));
+/// loop {
+///     state.tick_with(&clock);
+///     terminal.draw(|f| f.render_stateful_widget(&splash, f.area(), &mut state))?;
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub enum Effect {
+    /// The classic demoscene plasma: overlapping sine waves, colored
+    /// from the palette
+    Plasma(G),
+}
+impl Effect {
+    /// Colors the backgrounds of `area` as the effect looks after
+    /// `elapsed`; cells outside the buffer are skipped
+    pub fn render(
+        &self,
+        area: Rect,
+        elapsed: Duration,
+        buf: &mut Buffer,
+    ) {
+        match self {
+            Self::Plasma(palette) => {
+                let time = elapsed.as_secs_f32();
+                for pos in area.intersection(buf.area).positions() {
+                    let x = (pos.x - area.x) as f32;
+                    // cells are about twice as tall as they are wide
+                    let y = (pos.y - area.y) as f32 * 2.0;
+                    buf[pos].bg =
+                        sample(palette, plasma(x, y, time), 0.0);
+                }
+            }
+        }
+    }
+}
+/// The plasma at cell `(x, y)` after `time` seconds, 0.0..=1.0
+fn plasma(x: f32, y: f32, time: f32) -> f32 {
+    let (cx, cy) =
+        (x + 8.0 * (time * 0.3).sin(), y + 8.0 * (time * 0.4).cos());
+    let v = (x * 0.16 + time).sin()
+        + (y * 0.12 - time * 0.7).sin()
+        + ((x + y) * 0.09 + time * 0.5).sin()
+        + ((cx * cx + cy * cy).sqrt() * 0.14 - time).sin();
+    (v / 4.0 + 1.0) / 2.0
+}
@@ -22,6 +22,17 @@ bitflags! {
         const ALL = Self::TOP_CENTER.bits() | Self::RIGHT_CENTER.bits() | Self::BOTTOM_CENTER.bits() | Self::LEFT_CENTER.bits();
     }
 }
+bitflags! {
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub struct Sides: u32 {
+        const NONE = 0;
+        const TOP = 1;
+        const BOTTOM = 2;
+        const LEFT = 4;
+        const RIGHT = 8;
+        const ALL = Self::TOP.bits() | Self::BOTTOM.bits() | Self::LEFT.bits() | Self::RIGHT.bits();
+    }
+}
 bitflags! {
     #[derive(PartialEq)]
     struct RepBorderSymbols: u32 {
@@ -0,0 +1,72 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::Color,
+};
+/// Turns the block into a horizontal gauge, for resource monitors.
+///
+/// The border keeps its gradient up to `value` (0.0..=1.0) of the
+/// block's width and is drawn in the `track` color past it. `label`
+/// is shown in the middle of the bottom border, in the colors of the
+/// gauge beneath it; with no label the value is shown as a percentage.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// let cpu = GradientBlock::new().gauge(0.42, "CPU 42%");
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GaugeConfig {
+    pub value: f32,
+    pub label: Option<String>,
+    pub track: Color,
+}
+impl Default for GaugeConfig {
+    /// Empty, labeled with its percentage, with a dark gray track
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            label: None,
+            track: Color::DarkGray,
+        }
+    }
+}
+impl GaugeConfig {
+    /// The label shown in the bottom border
+    pub fn label(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => {
+                format!("{:.0}%", self.value.clamp(0.0, 1.0) * 100.0)
+            }
+        }
+    }
+    /// How far across `area` a position is, from 0.0 at the left edge
+    /// to 1.0 at the right edge
+    pub fn offset(&self, area: Rect, pos: Position) -> f32 {
+        let w = area.width.saturating_sub(1).max(1) as f32;
+        pos.x.saturating_sub(area.x) as f32 / w
+    }
+    /// Recolors the border `cells` of a block rendered in `area` past
+    /// the gauge value with the track color. A full gauge keeps the
+    /// whole border.
+    pub fn render(
+        &self,
+        area: Rect,
+        cells: impl IntoIterator<Item = Position>,
+        buf: &mut Buffer,
+    ) {
+        let value = self.value.clamp(0.0, 1.0);
+        for pos in cells {
+            if value >= 1.0 || self.offset(area, pos) <= value {
+                continue;
+            }
+            if let Some(cell) = buf.cell_mut(pos) {
+                cell.fg = self.track;
+            }
+        }
+    }
+}
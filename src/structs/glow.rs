@@ -0,0 +1,102 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Margin, Position, Rect},
+    style::Color,
+};
+/// A neon-like glow around a block's border.
+///
+/// `width` rings of cells (1 or 2) around the border get a background
+/// sampled from the closest border cell, faded by `intensity` and
+/// further for each ring.
+///
+/// When `inset` is true the glow takes up the outer rings of the block's
+/// own area and the border is drawn inside them, otherwise the glow is
+/// drawn outside the block's area (clipped to the buffer).
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::glow::GlowConfig;
+/// # use tui_gradient_block::theme_presets::cool::t_misty_blue;
+/// let focused = GradientBlock::new()
+///     .with_gradient(t_misty_blue::up())
+///     .glow(GlowConfig::default());
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GlowConfig {
+    pub width: u16,
+    pub intensity: f32,
+    pub inset: bool,
+}
+impl Default for GlowConfig {
+    fn default() -> Self {
+        Self {
+            width: 1,
+            intensity: 0.5,
+            inset: false,
+        }
+    }
+}
+impl GlowConfig {
+    /// The number of rings drawn, `width` clamped to 1..=2
+    pub fn rings(&self) -> u16 {
+        self.width.clamp(1, 2)
+    }
+    /// The area the border is drawn in when the block is given `area`
+    pub fn block_area(&self, area: Rect) -> Rect {
+        match self.inset {
+            true => {
+                area.inner(Margin::new(self.rings(), self.rings()))
+            }
+            false => area,
+        }
+    }
+    /// The area covered by the glow of a border drawn in `block_area`,
+    /// the border included, clipped to `bounds`
+    pub fn area(&self, block_area: Rect, bounds: Rect) -> Rect {
+        let w = self.rings();
+        Rect {
+            x: block_area.x.saturating_sub(w),
+            y: block_area.y.saturating_sub(w),
+            width: block_area.width.saturating_add(w * 2),
+            height: block_area.height.saturating_add(w * 2),
+        }
+        .intersection(bounds)
+    }
+    /// Draws the glow around a border that has already been rendered
+    /// in `block_area`
+    pub fn render(&self, block_area: Rect, buf: &mut Buffer) {
+        if block_area.is_empty() {
+            return;
+        }
+        for pos in self.area(block_area, buf.area).positions() {
+            if block_area.contains(pos) {
+                continue;
+            }
+            let nearest = Position::new(
+                pos.x
+                    .clamp(block_area.left(), block_area.right() - 1),
+                pos.y
+                    .clamp(block_area.top(), block_area.bottom() - 1),
+            );
+            let Color::Rgb(r, g, b) = buf[nearest].fg else {
+                continue;
+            };
+            let ring = (nearest.x.abs_diff(pos.x))
+                .max(nearest.y.abs_diff(pos.y));
+            let f = (self.intensity / ring as f32).clamp(0.0, 1.0);
+            let cell = &mut buf[pos];
+            let (br, bg, bb) = match cell.bg {
+                Color::Rgb(r, g, b) => (r, g, b),
+                _ => (0, 0, 0),
+            };
+            let mix = |c: u8, base: u8| {
+                (base as f32 + (c as f32 - base as f32) * f) as u8
+            };
+            cell.bg = Color::Rgb(mix(r, br), mix(g, bg), mix(b, bb));
+        }
+    }
+}
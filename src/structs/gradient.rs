@@ -1,4 +1,11 @@
-use crate::types::G;
+#[cfg(feature = "serde")]
+use crate::types::E;
+use crate::{
+    enums::Interpolation, gradient_utils::BlendMode, types::G,
+};
+use colorgrad::Color;
+use derive_builder::Builder;
+#[derive(Clone)]
 pub struct GradientTheme {
     pub top_left: GradientVariation,
     pub top_right: GradientVariation,
@@ -15,9 +22,891 @@ pub struct GradientTheme {
     pub misc1: GradientVariation,
     pub misc2: GradientVariation,
 }
+/// The gradients of the four sides of a block.
+///
+/// Sides left out of a [`GradientVariationBuilder`] fall back to
+/// [`GradientVariation::FALLBACK`] as a solid color.
+/// # Example
+/// ```
+/// # use tui_gradient_block::structs::gradient::{GradientDescriptor, GradientVariationBuilder};
+/// let variation = GradientVariationBuilder::default()
+///     .top(GradientDescriptor::new(vec![(0, 255, 255), (255, 0, 255)]))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Builder, Clone)]
+#[builder(pattern = "owned")]
 pub struct GradientVariation {
+    #[builder(
+        setter(into),
+        default = "GradientVariation::fallback()"
+    )]
     pub left: G,
+    #[builder(
+        setter(into),
+        default = "GradientVariation::fallback()"
+    )]
     pub right: G,
+    #[builder(
+        setter(into),
+        default = "GradientVariation::fallback()"
+    )]
     pub bottom: G,
+    #[builder(
+        setter(into),
+        default = "GradientVariation::fallback()"
+    )]
     pub top: G,
 }
+impl GradientVariation {
+    /// The color of sides a builder leaves unset
+    pub const FALLBACK: (u8, u8, u8) = (255, 255, 255);
+    fn fallback() -> G {
+        GradientDescriptor::new(vec![Self::FALLBACK]).build()
+    }
+    /// A variation using one color on every side
+    pub fn solid(color: (u8, u8, u8)) -> Self {
+        Self::uniform(GradientDescriptor::new(vec![color]).build())
+    }
+    /// A variation using the same gradient on every side
+    pub fn uniform(gradient: G) -> Self {
+        Self {
+            left: gradient.clone(),
+            right: gradient.clone(),
+            bottom: gradient.clone(),
+            top: gradient,
+        }
+    }
+    /// Applies `f` to the gradient of every side
+    pub fn map(self, f: impl Fn(G) -> G) -> Self {
+        Self {
+            left: f(self.left),
+            right: f(self.right),
+            bottom: f(self.bottom),
+            top: f(self.top),
+        }
+    }
+    /// The variation `t` of the way (0.0..=1.0) to `other`, side by
+    /// side, see [`MixGradient`]
+    pub fn mix(&self, other: &Self, t: f32) -> Self {
+        let mix = |from: &G, to: &G| -> G {
+            Box::new(MixGradient::new(from.clone(), to.clone(), t))
+        };
+        Self {
+            left: mix(&self.left, &other.left),
+            right: mix(&self.right, &other.right),
+            bottom: mix(&self.bottom, &other.bottom),
+            top: mix(&self.top, &other.top),
+        }
+    }
+}
+impl std::ops::Index<crate::enums::ThemeVariant> for GradientTheme {
+    type Output = GradientVariation;
+    fn index(
+        &self,
+        which: crate::enums::ThemeVariant,
+    ) -> &Self::Output {
+        self.variation(which)
+    }
+}
+impl GradientTheme {
+    /// Every variation of the theme, in the order of
+    /// [`ThemeVariant::ALL`](crate::enums::ThemeVariant::ALL)
+    pub fn variations(
+        &self,
+    ) -> impl Iterator<
+        Item = (crate::enums::ThemeVariant, &GradientVariation),
+    > {
+        crate::enums::ThemeVariant::ALL
+            .into_iter()
+            .map(|v| (v, self.variation(v)))
+    }
+    /// The variation of the theme named by `which`
+    pub fn variation(
+        &self,
+        which: crate::enums::ThemeVariant,
+    ) -> &GradientVariation {
+        use crate::enums::ThemeVariant as V;
+        match which {
+            V::TopLeft => &self.top_left,
+            V::TopRight => &self.top_right,
+            V::BottomLeft => &self.bottom_left,
+            V::BottomRight => &self.bottom_right,
+            V::DoubleCornersRight => &self.double_corners_right,
+            V::DoubleCornersLeft => &self.double_corners_left,
+            V::Vertical => &self.vertical,
+            V::Horizontal => &self.horizontal,
+            V::Up => &self.up,
+            V::Down => &self.down,
+            V::Left => &self.left,
+            V::Right => &self.right,
+            V::Misc1 => &self.misc1,
+            V::Misc2 => &self.misc2,
+        }
+    }
+    /// A theme using the same gradient everywhere
+    pub fn uniform(gradient: G) -> Self {
+        let v = GradientVariation::uniform(gradient);
+        Self {
+            top_left: v.clone(),
+            top_right: v.clone(),
+            bottom_left: v.clone(),
+            bottom_right: v.clone(),
+            double_corners_right: v.clone(),
+            double_corners_left: v.clone(),
+            vertical: v.clone(),
+            horizontal: v.clone(),
+            up: v.clone(),
+            down: v.clone(),
+            left: v.clone(),
+            right: v.clone(),
+            misc1: v.clone(),
+            misc2: v,
+        }
+    }
+    /// A theme laid out like the presets from a palette: the stops are
+    /// ordered dark to light (Oklch lightness) and resampled to the five
+    /// colors each preset is built from. An empty palette gives a
+    /// uniform [`GradientVariation::FALLBACK`] theme.
+    /// # Example
+    /// ```
+    /// # use colorgrad::Color;
+    /// # use tui_gradient_block::structs::gradient::GradientTheme;
+    /// let theme = GradientTheme::from_stops(&[
+    ///     Color::from_rgba8(28, 123, 186, 255),
+    ///     Color::from_rgba8(189, 215, 234, 255),
+    /// ]);
+    /// ```
+    pub fn from_stops(stops: &[Color]) -> Self {
+        if stops.is_empty() {
+            return Self::uniform(GradientVariation::fallback());
+        }
+        let mut stops = stops.to_vec();
+        stops.sort_by(|a, b| {
+            a.to_oklcha()[0].total_cmp(&b.to_oklcha()[0])
+        });
+        let through = crate::gradient_utils::build(
+            stops,
+            crate::enums::GradientKind::Linear,
+        )
+        .unwrap();
+        let colors = crate::gradient_utils::resample(&through, 5);
+        Self::from_colors(colors.try_into().unwrap())
+    }
+    /// A theme laid out like the presets from the five colors they are
+    /// built from, `COLOR_1` to `COLOR_5`, in that order. The presets go
+    /// from dark (`COLOR_1`, also used for solid sides) to light.
+    pub fn from_colors(colors: [Color; 5]) -> Self {
+        Self::from_colors_in(colors, BlendMode::Rgb)
+    }
+    /// [`Self::from_colors`] with every gradient blended in the color
+    /// space `mode`. Presets made of five colors can be rebuilt this
+    /// way to blend in Oklab, for example.
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::gradient_utils::BlendMode;
+    /// # use tui_gradient_block::structs::gradient::GradientTheme;
+    /// # use tui_gradient_block::theme_presets::cool::t_misty_blue::*;
+    /// let theme = GradientTheme::from_colors_in(
+    ///     [COLOR_1, COLOR_2, COLOR_3, COLOR_4, COLOR_5],
+    ///     BlendMode::Oklab,
+    /// );
+    /// ```
+    pub fn from_colors_in(
+        colors: [Color; 5],
+        mode: BlendMode,
+    ) -> Self {
+        // indexed from 1 like the presets' COLOR_1..=COLOR_5
+        let g = |idx: &[usize]| -> G {
+            let stops = idx.iter().map(|&i| colors[i - 1].clone());
+            crate::gradient_utils::build_in(
+                stops,
+                crate::enums::GradientKind::Linear,
+                mode,
+            )
+            .unwrap()
+        };
+        let solid = |i: usize| g(&[i]);
+        let d_to_l = || g(&[1, 2, 3, 4, 5]);
+        let l_to_d = || crate::gradient_utils::reverse(d_to_l());
+        let d_to_l_d = || g(&[1, 1, 2, 3, 4, 5]);
+        let l_to_d_d = || crate::gradient_utils::reverse(d_to_l_d());
+        let v = |top: G, right: G, bottom: G, left: G| {
+            GradientVariation {
+                left,
+                right,
+                bottom,
+                top,
+            }
+        };
+        Self {
+            top_left: v(l_to_d(), solid(1), solid(1), l_to_d()),
+            top_right: v(d_to_l(), l_to_d(), solid(1), solid(1)),
+            bottom_left: v(solid(1), solid(1), l_to_d(), d_to_l()),
+            bottom_right: v(solid(1), d_to_l(), d_to_l(), solid(1)),
+            double_corners_right: v(
+                d_to_l(),
+                l_to_d_d(),
+                l_to_d(),
+                d_to_l_d(),
+            ),
+            double_corners_left: v(
+                l_to_d(),
+                d_to_l_d(),
+                d_to_l(),
+                l_to_d_d(),
+            ),
+            vertical: v(
+                solid(1),
+                g(&[1, 2, 4, 5, 4, 2, 1]),
+                solid(1),
+                g(&[1, 2, 4, 5, 4, 2, 1]),
+            ),
+            horizontal: v(
+                g(&[1, 2, 3, 4, 5, 5, 4, 3, 2, 1]),
+                solid(1),
+                g(&[1, 2, 3, 4, 5, 5, 4, 3, 2, 1]),
+                solid(1),
+            ),
+            up: v(solid(5), l_to_d(), solid(1), l_to_d()),
+            down: v(solid(1), d_to_l(), solid(5), d_to_l()),
+            left: v(l_to_d(), solid(1), l_to_d(), solid(5)),
+            right: v(d_to_l(), solid(5), d_to_l(), solid(1)),
+            misc1: v(
+                g(&[5, 3, 2, 4]),
+                g(&[4, 3, 2]),
+                g(&[2, 3, 4, 3, 2]),
+                g(&[5, 3, 5, 2]),
+            ),
+            misc2: v(g(&[3, 2]), g(&[2, 1, 2]), solid(2), g(&[3, 2])),
+        }
+    }
+    /// A theme that stands out against the terminal background `bg`
+    /// (see [`crate::capabilities::query_background`] to read it).
+    ///
+    /// The hues are centered on the complement of the background's, or
+    /// on blue for a gray background, and the lightness is kept at least
+    /// 0.3 (Oklch) away from it, lighter than a dark background and
+    /// darker than a light one, so no border fades into it the way a
+    /// preset of a similar color would. A `Reset` background is taken to
+    /// be a dark terminal.
+    /// # Example
+    /// ```
+    /// # use ratatui::style::Color;
+    /// # use tui_gradient_block::structs::gradient::GradientTheme;
+    /// let theme = GradientTheme::for_background(Color::Rgb(40, 42, 54));
+    /// ```
+    pub fn for_background(bg: ratatui::style::Color) -> Self {
+        let [bg_l, bg_chroma, bg_hue, _] = to_colorgrad_color(bg)
+            .unwrap_or(Color::new(0.0, 0.0, 0.0, 1.0))
+            .to_oklcha();
+        let (min, max) = match bg_l < 0.5 {
+            true => {
+                let min = (bg_l + 0.3).max(0.65);
+                (min, (min + 0.3).min(0.97))
+            }
+            false => {
+                let max = (bg_l - 0.3).min(0.55);
+                ((max - 0.3).max(0.1), max)
+            }
+        };
+        let hue = match bg_chroma < 0.03 {
+            true => 250_f32.to_radians(),
+            false => bg_hue + std::f32::consts::PI,
+        };
+        let stops = (0..5)
+            .map(|i| {
+                let t = i as f32 / 4.0;
+                Color::from_oklcha(
+                    min + t * (max - min),
+                    0.12,
+                    hue + (t - 0.5) * 40_f32.to_radians(),
+                    1.0,
+                )
+                .clamp()
+            })
+            .collect::<Vec<_>>();
+        Self::from_stops(&stops)
+    }
+    /// The theme named by the `TUI_GRADIENT_THEME` environment variable
+    /// if it is set and valid, otherwise `default`
+    /// (see [`crate::theme_presets::from_env`])
+    pub fn from_env_or(default: Self) -> Self {
+        crate::theme_presets::from_env().unwrap_or(default)
+    }
+    /// A variant of the theme for dark terminal backgrounds: the
+    /// lightness of every color is remapped into 0.65..=0.95 (Oklch),
+    /// keeping hue, chroma and the relative lightness of the stops
+    pub fn to_dark(self) -> Self {
+        self.with_lightness(0.65, 0.95)
+    }
+    /// A variant of the theme for light terminal backgrounds: the
+    /// lightness of every color is remapped into 0.25..=0.55 (Oklch)
+    pub fn to_light(self) -> Self {
+        self.with_lightness(0.25, 0.55)
+    }
+    /// Applies `adjust` to every color of every variation, see
+    /// [`Adjust`]
+    /// # Example
+    /// ```
+    /// # use tui_gradient_block::structs::gradient::Adjust;
+    /// # use tui_gradient_block::theme_presets::cool::t_minty_green;
+    /// # use tui_gradient_block::theme_presets::cool::t_misty_blue;
+    /// let dimmed = t_misty_blue::theme().adjust(Adjust {
+    ///     saturation: -0.5,
+    ///     lightness: -0.3,
+    ///     ..Default::default()
+    /// });
+    /// let autumn = t_minty_green::theme().adjust(Adjust {
+    ///     hue_shift: -120.0,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn adjust(self, adjust: Adjust) -> Self {
+        self.map(|g| Box::new(AdjustedGradient::new(g, adjust)))
+    }
+    /// Remaps the lightness of every color into `min..=max` (Oklch,
+    /// 0.0..=1.0), see [`LightnessGradient`]
+    pub fn with_lightness(self, min: f32, max: f32) -> Self {
+        self.map(|g| Box::new(LightnessGradient::new(g, min, max)))
+    }
+    /// Applies `f` to every gradient of every variation
+    pub fn map(self, f: impl Fn(G) -> G) -> Self {
+        let f = &f;
+        Self {
+            top_left: self.top_left.map(f),
+            top_right: self.top_right.map(f),
+            bottom_left: self.bottom_left.map(f),
+            bottom_right: self.bottom_right.map(f),
+            double_corners_right: self.double_corners_right.map(f),
+            double_corners_left: self.double_corners_left.map(f),
+            vertical: self.vertical.map(f),
+            horizontal: self.horizontal.map(f),
+            up: self.up.map(f),
+            down: self.down.map(f),
+            left: self.left.map(f),
+            right: self.right.map(f),
+            misc1: self.misc1.map(f),
+            misc2: self.misc2.map(f),
+        }
+    }
+}
+/// Converts a ratatui color to a colorgrad color, the other way around
+/// from [`crate::to_ratatui_color`]. Named and indexed colors use their
+/// usual xterm values; `Reset` has no color and gives `None`.
+pub fn to_colorgrad_color(c: ratatui::style::Color) -> Option<Color> {
+    crate::degrade::to_rgb(c)
+        .map(|(r, g, b)| Color::from_rgba8(r, g, b, 255))
+}
+/// A gradient through ratatui colors, evenly spaced
+/// (see [`GradientDescriptor::from_ratatui`])
+/// # Example
+/// ```
+/// # use ratatui::style::Color;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::gradient::gradient_from_ratatui;
+/// let block = GradientBlock::new().top_gradient(gradient_from_ratatui(&[
+///     Color::Cyan,
+///     Color::Magenta,
+/// ]));
+/// ```
+pub fn gradient_from_ratatui(colors: &[ratatui::style::Color]) -> G {
+    GradientDescriptor::from_ratatui(colors).build()
+}
+/// A plain-data description of a gradient: evenly spaced rgb color
+/// stops and the color space they are blended in.
+///
+/// It implements `colorgrad::Gradient` itself, so it can be used
+/// anywhere a gradient is accepted, and (with the `serde` feature)
+/// saved to and loaded from json.
+/// # Example
+/// ```
+/// # use tui_gradient_block::enums::Interpolation;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::gradient::GradientDescriptor;
+/// let g = GradientDescriptor::new(vec![(48, 174, 209), (225, 22, 247)])
+///     .interpolation(Interpolation::Oklab);
+/// let block = GradientBlock::new().top_gradient(g.build());
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradientDescriptor {
+    pub colors: Vec<(u8, u8, u8)>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub interpolation: Interpolation,
+}
+impl GradientDescriptor {
+    pub fn new(colors: Vec<(u8, u8, u8)>) -> Self {
+        Self {
+            colors,
+            interpolation: Interpolation::Rgb,
+        }
+    }
+    /// A descriptor with ratatui colors as its stops. Named and indexed
+    /// colors use their usual xterm values; `Reset` has no color and is
+    /// left out.
+    pub fn from_ratatui(colors: &[ratatui::style::Color]) -> Self {
+        Self::new(
+            colors
+                .iter()
+                .filter_map(|c| crate::degrade::to_rgb(*c))
+                .collect(),
+        )
+    }
+    /// A descriptor approximating `gradient` with `stops` evenly spaced
+    /// samples over its domain (at least 2), for saving or inspecting
+    /// gradients that are only available as trait objects
+    pub fn sample(gradient: &G, stops: usize) -> Self {
+        let (dmin, dmax) = gradient.domain();
+        let n = stops.max(2);
+        Self::new(
+            (0..n)
+                .map(|i| {
+                    let t = dmin
+                        + (dmax - dmin) * i as f32 / (n - 1) as f32;
+                    let [r, g, b, _] = gradient.at(t).to_rgba8();
+                    (r, g, b)
+                })
+                .collect(),
+        )
+    }
+    /// Sets the color space the stops are blended in
+    pub fn interpolation(
+        mut self,
+        interpolation: Interpolation,
+    ) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+    /// Boxes the descriptor so it can be passed to the gradient setters
+    pub fn build(&self) -> G {
+        Box::new(self.clone())
+    }
+    #[cfg(feature = "serde")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", err)
+    )]
+    pub fn from_json(path: &str) -> Result<Self, E> {
+        crate::generate_from_json!(path, Self)
+    }
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        crate::generate_to_json!(self)
+    }
+}
+impl colorgrad::Gradient for GradientDescriptor {
+    fn at(&self, t: f32) -> Color {
+        let stop = |i: usize| {
+            let (r, g, b) = self.colors[i];
+            Color::from_rgba8(r, g, b, 255)
+        };
+        match self.colors.len() {
+            0 => return Color::new(0.0, 0.0, 0.0, 1.0),
+            1 => return stop(0),
+            _ => {}
+        }
+        let pos = t.clamp(0.0, 1.0) * (self.colors.len() - 1) as f32;
+        let i = (pos.floor() as usize).min(self.colors.len() - 2);
+        let (a, b, t) = (stop(i), stop(i + 1), pos - i as f32);
+        match self.interpolation {
+            Interpolation::Rgb => a.interpolate_rgb(&b, t),
+            Interpolation::LinearRgb => {
+                a.interpolate_linear_rgb(&b, t)
+            }
+            Interpolation::Oklab => a.interpolate_oklab(&b, t),
+            Interpolation::Lab => a.interpolate_lab(&b, t),
+            Interpolation::Hsv => a.interpolate_hsv(&b, t),
+        }
+    }
+}
+impl From<GradientDescriptor> for G {
+    fn from(value: GradientDescriptor) -> Self {
+        Box::new(value)
+    }
+}
+/// Controls how a gradient is laid out along a segment.
+///
+/// The position along the segment (0.0..=1.0) is multiplied by `scale`,
+/// shifted by `offset`, and then wrapped according to `repeat`.
+/// # Example
+/// ```
+/// # use tui_gradient_block::enums::Repeat;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::gradient::GradientOpts;
+/// // the gradient runs twice along the top, back and forth
+/// let block = GradientBlock::new()
+///     .top_gradient(Box::new(colorgrad::preset::rainbow()))
+///     .top_gradient_opts(GradientOpts {
+///         scale: 2.0,
+///         offset: 0.0,
+///         repeat: Repeat::Mirror,
+///     });
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradientOpts {
+    pub scale: f32,
+    pub offset: f32,
+    pub repeat: crate::enums::Repeat,
+}
+impl Default for GradientOpts {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            offset: 0.0,
+            repeat: crate::enums::Repeat::Clamp,
+        }
+    }
+}
+/// A gradient with [`GradientOpts`] applied to its domain
+#[derive(Clone)]
+pub struct DomainGradient {
+    pub inner: G,
+    pub opts: GradientOpts,
+}
+impl DomainGradient {
+    pub fn new(inner: G, opts: GradientOpts) -> Self {
+        Self { inner, opts }
+    }
+}
+impl colorgrad::Gradient for DomainGradient {
+    fn at(&self, t: f32) -> Color {
+        use crate::enums::Repeat;
+        let (dmin, dmax) = self.inner.domain();
+        let t = dmin
+            + (t * self.opts.scale + self.opts.offset)
+                * (dmax - dmin);
+        match self.opts.repeat {
+            Repeat::Clamp => self.inner.at(t.clamp(dmin, dmax)),
+            Repeat::Repeat => self.inner.repeat_at(t),
+            Repeat::Mirror => self.inner.reflect_at(t),
+        }
+    }
+}
+/// A gradient quantized into `steps` solid bands with hard boundaries.
+///
+/// The bands take the colors at evenly spaced positions of the inner
+/// gradient, including both ends, so `steps: 3` over a green to red
+/// gradient gives a green, a yellow and a red band.
+#[derive(Clone)]
+pub struct SteppedGradient {
+    pub inner: G,
+    pub steps: usize,
+}
+impl SteppedGradient {
+    pub fn new(inner: G, steps: usize) -> Self {
+        Self { inner, steps }
+    }
+}
+impl colorgrad::Gradient for SteppedGradient {
+    fn at(&self, t: f32) -> Color {
+        let (dmin, dmax) = self.inner.domain();
+        if self.steps <= 1 {
+            return self.inner.at(dmin);
+        }
+        let n = self.steps as f32;
+        let band = (t.clamp(0.0, 1.0) * n).floor().min(n - 1.0);
+        self.inner.at(dmin + band / (n - 1.0) * (dmax - dmin))
+    }
+}
+/// Colors of the inner gradient picked by seeded value noise instead of
+/// a smooth ramp, for borders and fills with organic color variation.
+///
+/// The noise is two dimensional: `t` runs along `scale` noise cells and
+/// `phase` is the second axis, so moving `phase` a little every frame
+/// makes the colors drift in place rather than scroll (which the
+/// block's own phase does). `amount` mixes the noise with the plain
+/// ramp, 0.0 being the inner gradient unchanged. The same seed always
+/// gives the same colors.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::gradient::NoiseGradient;
+/// # use tui_gradient_block::theme_presets::cool::t_zombie_dreams;
+/// # let state = tui_gradient_block::structs::state::GradientBlockState::default();
+/// // every frame
+/// let noise = NoiseGradient::new(t_zombie_dreams::d_to_l(), 7)
+///     .phase(state.elapsed.as_secs_f32() * 0.5);
+/// let block = GradientBlock::new().top_gradient(Box::new(noise));
+/// ```
+#[derive(Clone)]
+pub struct NoiseGradient {
+    pub inner: G,
+    pub seed: u32,
+    /// Noise cells across 0.0..=1.0, 4.0 by default
+    pub scale: f32,
+    /// Layers of finer noise added on top, each half as strong; 3 by
+    /// default
+    pub octaves: u8,
+    /// How much of the noise is mixed into the ramp, 0.0..=1.0; 1.0 by
+    /// default
+    pub amount: f32,
+    /// Where along the second axis of the noise the colors are picked
+    pub phase: f32,
+}
+impl NoiseGradient {
+    pub fn new(inner: G, seed: u32) -> Self {
+        Self {
+            inner,
+            seed,
+            scale: 4.0,
+            octaves: 3,
+            amount: 1.0,
+            phase: 0.0,
+        }
+    }
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+    pub fn octaves(mut self, octaves: u8) -> Self {
+        self.octaves = octaves;
+        self
+    }
+    pub fn amount(mut self, amount: f32) -> Self {
+        self.amount = amount;
+        self
+    }
+    pub fn phase(mut self, phase: f32) -> Self {
+        self.phase = phase;
+        self
+    }
+    /// The noise at `(x, y)`, 0.0..=1.0
+    pub fn noise(&self, x: f32, y: f32) -> f32 {
+        let (mut sum, mut weight, mut total) = (0.0, 1.0, 0.0);
+        let mut freq = 1.0;
+        for octave in 0..self.octaves.max(1) as u32 {
+            let seed = self.seed.wrapping_add(octave);
+            sum += value_noise(x * freq, y * freq, seed) * weight;
+            total += weight;
+            weight *= 0.5;
+            freq *= 2.0;
+        }
+        // layered noise keeps to the middle, stretched so the colors
+        // reach both ends of the gradient
+        ((sum / total - 0.5) * 1.5 + 0.5).clamp(0.0, 1.0)
+    }
+}
+impl colorgrad::Gradient for NoiseGradient {
+    fn at(&self, t: f32) -> Color {
+        let (dmin, dmax) = self.inner.domain();
+        let n = self.noise(t * self.scale, self.phase);
+        let amount = self.amount.clamp(0.0, 1.0);
+        let t = t.clamp(0.0, 1.0) * (1.0 - amount) + n * amount;
+        self.inner.at(dmin + t * (dmax - dmin))
+    }
+}
+/// Value noise at `(x, y)`: random values on the integer lattice,
+/// blended with a smooth fade in between, 0.0..=1.0
+fn value_noise(x: f32, y: f32, seed: u32) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let fade = |t: f32| t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+    let (fx, fy) = (fade(x - x0), fade(y - y0));
+    let (ix, iy) = (x0 as i32, y0 as i32);
+    let at = |dx, dy| {
+        lattice(ix.wrapping_add(dx), iy.wrapping_add(dy), seed)
+    };
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+    let top = lerp(at(0, 0), at(1, 0), fx);
+    let bottom = lerp(at(0, 1), at(1, 1), fx);
+    lerp(top, bottom, fy)
+}
+/// The random value of a lattice point, 0.0..=1.0
+pub(crate) fn lattice(x: i32, y: i32, seed: u32) -> f32 {
+    let mut h = (x as u32).wrapping_mul(0x27d4_eb2d)
+        ^ (y as u32).wrapping_mul(0x1656_67b1)
+        ^ seed.wrapping_mul(0x9e37_79b9);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2c1b_3c6d);
+    h ^= h >> 12;
+    h = h.wrapping_mul(0x297a_2d39);
+    h ^= h >> 15;
+    h as f32 / u32::MAX as f32
+}
+/// A gradient with the lightness of its colors remapped linearly from
+/// 0.0..=1.0 into `min..=max`, in the perceptual Oklch color space.
+///
+/// Hue and chroma are kept, and colors that end up outside of the rgb
+/// gamut are clamped.
+#[derive(Clone)]
+pub struct LightnessGradient {
+    pub inner: G,
+    pub min: f32,
+    pub max: f32,
+}
+impl LightnessGradient {
+    pub fn new(inner: G, min: f32, max: f32) -> Self {
+        Self { inner, min, max }
+    }
+}
+impl colorgrad::Gradient for LightnessGradient {
+    fn at(&self, t: f32) -> Color {
+        let c = self.inner.at(t);
+        let l = c.to_oklcha()[0].clamp(0.0, 1.0);
+        crate::color::with_lightness(
+            &c,
+            self.min + l * (self.max - self.min),
+        )
+    }
+    fn domain(&self) -> (f32, f32) {
+        self.inner.domain()
+    }
+}
+/// A hue, saturation and lightness adjustment, made in the Oklch color
+/// space with the functions of [`crate::color`]. The default changes
+/// nothing.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Adjust {
+    /// Degrees to rotate the hue by
+    pub hue_shift: f32,
+    /// Scales the chroma by `1.0 + saturation`; -1.0 turns the colors
+    /// gray
+    pub saturation: f32,
+    /// -1.0..=1.0, how far of the way to white (positive) or black
+    /// (negative) the colors are moved
+    pub lightness: f32,
+}
+impl Adjust {
+    /// `c` with the adjustment applied
+    pub fn apply(&self, c: &Color) -> Color {
+        if *self == Self::default() {
+            return c.clone();
+        }
+        let c = crate::color::shift_hue(c, self.hue_shift);
+        let c = crate::color::saturate(&c, self.saturation);
+        match self.lightness {
+            l if l >= 0.0 => crate::color::lighten(&c, l.min(1.0)),
+            l => crate::color::darken(&c, (-l).min(1.0)),
+        }
+    }
+}
+/// A gradient with an [`Adjust`] applied to its colors
+#[derive(Clone)]
+pub struct AdjustedGradient {
+    pub inner: G,
+    pub adjust: Adjust,
+}
+impl AdjustedGradient {
+    pub fn new(inner: G, adjust: Adjust) -> Self {
+        Self { inner, adjust }
+    }
+}
+impl colorgrad::Gradient for AdjustedGradient {
+    fn at(&self, t: f32) -> Color {
+        self.adjust.apply(&self.inner.at(t))
+    }
+    fn domain(&self) -> (f32, f32) {
+        self.inner.domain()
+    }
+}
+/// Two gradients mixed together, `t` of the way from `from` to `to`
+/// (0.0..=1.0), blended in Oklab so the mix doesn't go muddy.
+///
+/// Both are sampled over their own domain, so gradients with different
+/// domains line up. The domain of the mix is 0.0..=1.0.
+#[derive(Clone)]
+pub struct MixGradient {
+    pub from: G,
+    pub to: G,
+    pub t: f32,
+}
+impl MixGradient {
+    pub fn new(from: G, to: G, t: f32) -> Self {
+        Self { from, to, t }
+    }
+}
+impl colorgrad::Gradient for MixGradient {
+    fn at(&self, t: f32) -> Color {
+        let at = |g: &G| {
+            let (dmin, dmax) = g.domain();
+            g.at(dmin + t * (dmax - dmin))
+        };
+        at(&self.from)
+            .interpolate_oklab(&at(&self.to), self.t.clamp(0.0, 1.0))
+    }
+}
+/// A gradient laid over a whole frame instead of along each border
+/// segment.
+///
+/// Every block using the same scope samples the part of the gradient
+/// under its own border, so a dashboard of tiles looks like a single
+/// continuous gradient. The frame defaults to the area of the buffer
+/// being rendered into, which is the whole terminal when rendering
+/// through a `Frame`.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::gradient::GradientScope;
+/// # use ratatui::layout::{Constraint, Layout};
+/// # let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(30, 5)).unwrap();
+/// # terminal.draw(|f| {
+/// # let tiles = Layout::horizontal([Constraint::Fill(1); 3]).split(f.area()).to_vec();
+/// let scope = GradientScope::global(Box::new(colorgrad::preset::turbo()));
+/// for area in tiles {
+///     f.render_widget(
+///         GradientBlock::new().gradient_scope(scope.clone()),
+///         area,
+///     );
+/// }
+/// # }).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct GradientScope {
+    pub gradient: G,
+    pub frame: Option<ratatui::layout::Rect>,
+    pub direction: crate::enums::GradientDirection,
+}
+impl GradientScope {
+    /// A horizontal gradient over the whole buffer
+    pub fn global(gradient: G) -> Self {
+        Self {
+            gradient,
+            frame: None,
+            direction: crate::enums::GradientDirection::Horizontal,
+        }
+    }
+    /// Lays the gradient over `frame` instead of the whole buffer
+    pub fn frame(mut self, frame: ratatui::layout::Rect) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+    pub fn direction(
+        mut self,
+        direction: crate::enums::GradientDirection,
+    ) -> Self {
+        self.direction = direction;
+        self
+    }
+    /// Where a position falls along the gradient over `frame`,
+    /// 0.0..=1.0
+    pub fn t(
+        &self,
+        frame: ratatui::layout::Rect,
+        pos: ratatui::layout::Position,
+    ) -> f32 {
+        use crate::enums::GradientDirection as D;
+        let along = |p: u16, start: u16, len: u16| {
+            p.saturating_sub(start) as f32
+                / len.saturating_sub(1).max(1) as f32
+        };
+        let x = along(pos.x, frame.x, frame.width);
+        let y = along(pos.y, frame.y, frame.height);
+        match self.direction {
+            D::Horizontal => x,
+            D::Vertical => y,
+            D::Diagonal => (x + y) / 2.0,
+        }
+        .clamp(0.0, 1.0)
+    }
+}
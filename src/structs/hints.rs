@@ -0,0 +1,127 @@
+use crate::structs::border_segment::BorderSegment;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+use unicode_width::UnicodeWidthStr;
+/// A key and what it does, shown in a [`HintsConfig`] bar
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hint {
+    pub key: String,
+    pub label: String,
+    /// Hints with a lower priority are dropped first when the bar
+    /// doesn't fit
+    pub priority: u8,
+}
+impl Hint {
+    pub fn new(key: impl ToString, label: impl ToString) -> Self {
+        Self {
+            key: key.to_string(),
+            label: label.to_string(),
+            priority: 0,
+        }
+    }
+    pub const fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+    /// The number of cells the hint takes up, with a space on both
+    /// sides
+    pub fn width(&self) -> usize {
+        self.key.width() + self.label.width() + 3
+    }
+}
+/// A bar of keybinding hints drawn in the bottom border, like
+/// `─ q:quit ─ s:save ─`.
+///
+/// Each hint is drawn as ` key:label ` with one cell of the border line
+/// left between them, and keeps the border gradient; keys are bold.
+/// The bar starts after the bottom left corner, or after a title there,
+/// and when the hints don't fit before the next title or the bottom
+/// right corner the ones with the lowest priority are dropped, the
+/// later ones first among equal priorities, and the rest keep their
+/// order. Wide characters take up two cells.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// let block = GradientBlock::new()
+///     .hints(&[("q", "quit"), ("s", "save"), ("?", "help")]);
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HintsConfig {
+    pub hints: Vec<Hint>,
+}
+impl HintsConfig {
+    /// Hints of the same priority from `(key, label)` pairs
+    pub fn new<K: ToString, L: ToString>(hints: &[(K, L)]) -> Self {
+        Self {
+            hints: hints
+                .iter()
+                .map(|(k, l)| Hint::new(k.to_string(), l.to_string()))
+                .collect(),
+        }
+    }
+    pub fn hint(mut self, hint: Hint) -> Self {
+        self.hints.push(hint);
+        self
+    }
+    /// The hints shown on a line `width` cells long, corners included
+    pub fn fitting(&self, width: u16) -> Vec<&Hint> {
+        // a corner and a line cell before the first hint, a line cell
+        // after every hint and the other corner
+        let room = (width as usize).saturating_sub(3);
+        let mut shown: Vec<&Hint> = self.hints.iter().collect();
+        let mut used: usize =
+            shown.iter().map(|h| h.width() + 1).sum();
+        while used > room {
+            let Some((i, _)) = shown
+                .iter()
+                .enumerate()
+                .rev()
+                .min_by_key(|(_, h)| h.priority)
+            else {
+                break;
+            };
+            used -= shown.remove(i).width() + 1;
+        }
+        shown
+    }
+    /// Draws the hints on the line of the `bottom` segment of a block
+    /// rendered in `area`, in the first run of cells `titles` leaves
+    /// free (see [`BorderSegment::free_run`]), after its first cell
+    pub fn render(
+        &self,
+        bottom: &BorderSegment,
+        area: Rect,
+        titles: &[Rect],
+        buf: &mut Buffer,
+    ) {
+        let run = bottom.free_run(area, titles);
+        let mut offset = run.start + 1;
+        for hint in self.fitting(run.len() as u16 + 2) {
+            let Some(pos) = bottom.line_position(area, offset) else {
+                break;
+            };
+            let line = Line::from(vec![
+                Span::raw(" "),
+                Span::styled(
+                    hint.key.as_str(),
+                    Style::new().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(":{} ", hint.label)),
+            ]);
+            buf.set_line(pos.x, pos.y, &line, hint.width() as u16);
+            offset += hint.width() as u16 + 1;
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{enums::Side, structs::border_segment::BorderSegment};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::Color,
+};
+/// The status an indicator dot shows
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IndicatorState {
+    /// A hollow dot in the color of the border (default)
+    #[default]
+    Off,
+    /// A green dot
+    Connected,
+    /// A yellow dot
+    Syncing,
+    /// A red dot
+    Error,
+    /// A dot of any color
+    Custom(Color),
+}
+impl IndicatorState {
+    /// The glyph of the dot, `○` when off and `●` otherwise
+    pub const fn glyph(self) -> char {
+        match self {
+            Self::Off => '○',
+            _ => '●',
+        }
+    }
+    /// The color of the dot, `None` to keep the border's color
+    pub const fn color(self) -> Option<Color> {
+        match self {
+            Self::Off => None,
+            Self::Connected => Some(Color::Green),
+            Self::Syncing => Some(Color::Yellow),
+            Self::Error => Some(Color::Red),
+            Self::Custom(color) => Some(color),
+        }
+    }
+}
+/// A row of status dots drawn on the line of a side, like
+/// `──●─●─○─┐`, one per item.
+///
+/// The dots are separated by a cell of the border line and end one cell
+/// before the last corner of the side, so on the top side they stay
+/// clear of left aligned titles. Dots that don't fit are left out.
+/// # Example
+/// ```
+/// # use tui_gradient_block::enums::Side;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::indicators::IndicatorState;
+/// use IndicatorState::*;
+/// let block = GradientBlock::new()
+///     .indicators(Side::Top, &[Connected, Syncing, Error]);
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Indicators {
+    pub side: Side,
+    pub states: Vec<IndicatorState>,
+}
+impl Indicators {
+    pub fn new(side: Side, states: &[IndicatorState]) -> Self {
+        Self {
+            side,
+            states: states.to_vec(),
+        }
+    }
+    /// The cells of the dots on the line of `segment`, the segment of
+    /// [`Self::side`], for a block rendered in `area`, in the order of
+    /// [`Self::states`]. Dots that don't fit are left out from the
+    /// start.
+    pub fn positions(
+        &self,
+        segment: &BorderSegment,
+        area: Rect,
+    ) -> Vec<Option<Position>> {
+        let len = segment.edge_len(area);
+        let n = self.states.len() as u16;
+        // the last dot is followed by a cell of the line and the
+        // corner
+        let last = len.saturating_sub(3);
+        self.states
+            .iter()
+            .enumerate()
+            .map(|(i, _)| {
+                let back = (n - 1 - i as u16) * 2;
+                let offset =
+                    last.checked_sub(back).filter(|o| *o >= 2)?;
+                segment.line_position(area, offset)
+            })
+            .collect()
+    }
+    /// Draws the dots on the line of `segment`
+    pub fn render(
+        &self,
+        segment: &BorderSegment,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let positions = self.positions(segment, area);
+        for (state, pos) in self.states.iter().zip(positions) {
+            let Some(cell) = pos.and_then(|pos| buf.cell_mut(pos))
+            else {
+                continue;
+            };
+            cell.set_char(state.glyph());
+            if let Some(color) = state.color() {
+                cell.fg = color;
+            }
+        }
+    }
+}
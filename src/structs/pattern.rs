@@ -0,0 +1,97 @@
+use crate::enums::GradientDirection;
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+/// A repeating pattern tiled over the inner area of a block, for
+/// placeholder panes and drop zones.
+///
+/// Pattern cells get the pattern's char and foreground and keep their
+/// background, so the block's `bg` and style show through. The tiling
+/// starts at the top left of the inner area, and the fill is drawn on
+/// top of it.
+/// # Example
+/// ```
+/// # use ratatui::style::Color;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::pattern::Pattern;
+/// let drop_zone = GradientBlock::new().fill_pattern(Pattern::Checkerboard {
+///     chars: ['░', ' '],
+///     colors: [Color::DarkGray, Color::Reset],
+/// });
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pattern {
+    /// Cells alternating between the first char and color and the
+    /// second, in both directions
+    Checkerboard {
+        chars: [char; 2],
+        colors: [Color; 2],
+    },
+    /// Bands `width` cells wide alternating between the first char and
+    /// color and the second. Horizontal stripes are rows, vertical ones
+    /// columns and diagonal ones run from the top right to the bottom
+    /// left.
+    Stripes {
+        chars: [char; 2],
+        colors: [Color; 2],
+        width: u16,
+        direction: GradientDirection,
+    },
+    /// `char` in `color` every `spacing.0` columns and `spacing.1`
+    /// rows, leaving the cells in between as they are
+    Dots {
+        char: char,
+        color: Color,
+        spacing: (u16, u16),
+    },
+}
+impl Pattern {
+    /// The char and color of the cell `x` columns and `y` rows from the
+    /// top left of the tiled area, `None` for cells left as they are
+    pub fn cell(&self, x: u16, y: u16) -> Option<(char, Color)> {
+        let (x, y) = (x as usize, y as usize);
+        let pick =
+            |chars: &[char; 2], colors: &[Color; 2], i: usize| {
+                Some((chars[i % 2], colors[i % 2]))
+            };
+        match self {
+            Self::Checkerboard { chars, colors } => {
+                pick(chars, colors, x + y)
+            }
+            Self::Stripes {
+                chars,
+                colors,
+                width,
+                direction,
+            } => {
+                let along = match direction {
+                    GradientDirection::Horizontal => y,
+                    GradientDirection::Vertical => x,
+                    GradientDirection::Diagonal => x + y,
+                };
+                pick(chars, colors, along / (*width).max(1) as usize)
+            }
+            Self::Dots {
+                char,
+                color,
+                spacing: (sx, sy),
+            } => (x.is_multiple_of((*sx).max(1) as usize)
+                && y.is_multiple_of((*sy).max(1) as usize))
+            .then_some((*char, *color)),
+        }
+    }
+    /// Tiles `area` with the pattern; cells outside the buffer are
+    /// skipped
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        for pos in area.intersection(buf.area).positions() {
+            let Some((c, color)) =
+                self.cell(pos.x - area.x, pos.y - area.y)
+            else {
+                continue;
+            };
+            buf[pos].set_char(c).set_fg(color);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::enums::Corner;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::Color,
+};
+/// Turns the border into a progress ring.
+///
+/// The first `value` (0.0..=1.0) of the perimeter, going clockwise from
+/// `start`, keeps its gradient; the rest of the border is drawn in the
+/// `track` color.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// let download = GradientBlock::new().progress(0.42);
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProgressConfig {
+    pub value: f32,
+    pub start: Corner,
+    pub track: Color,
+}
+impl Default for ProgressConfig {
+    /// Empty, starting at the top left corner with a dark gray track
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            start: Corner::TopLeft,
+            track: Color::DarkGray,
+        }
+    }
+}
+impl ProgressConfig {
+    /// How far along the perimeter of `area` a position is, clockwise
+    /// from `start`, from 0.0 up to (not including) 1.0. Positions inside
+    /// the area are projected onto the nearest edge.
+    pub fn offset(&self, area: Rect, pos: Position) -> f32 {
+        let (w, h) = (
+            area.width.saturating_sub(1) as f32,
+            area.height.saturating_sub(1) as f32,
+        );
+        let perimeter = (2.0 * (w + h)).max(1.0);
+        let x = pos.x.saturating_sub(area.x) as f32;
+        let y = pos.y.saturating_sub(area.y) as f32;
+        let (top, right, bottom, left) = (y, w - x, h - y, x);
+        let nearest = top.min(right).min(bottom).min(left);
+        let along = match nearest {
+            n if n == top => x,
+            n if n == right => w + y,
+            n if n == bottom => w + h + (w - x),
+            _ => 2.0 * w + h + (h - y),
+        };
+        let start = match self.start {
+            Corner::TopLeft => 0.0,
+            Corner::TopRight => w,
+            Corner::BottomRight => w + h,
+            Corner::BottomLeft => 2.0 * w + h,
+        };
+        (along - start).rem_euclid(perimeter) / perimeter
+    }
+    /// Recolors the border `cells` of a block rendered in `area` past
+    /// the progress value with the track color
+    pub fn render(
+        &self,
+        area: Rect,
+        cells: impl IntoIterator<Item = Position>,
+        buf: &mut Buffer,
+    ) {
+        let value = self.value.clamp(0.0, 1.0);
+        for pos in cells {
+            if self.offset(area, pos) < value {
+                continue;
+            }
+            if let Some(cell) = buf.cell_mut(pos) {
+                cell.fg = self.track;
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use ratatui::{buffer::Buffer, layout::Position, style::Color};
+use std::time::Duration;
+/// Makes the border breathe by modulating the brightness of its
+/// colors over time.
+///
+/// The brightness swings by `amplitude` (0.0..=1.0) around the border's
+/// own colors once every `period`, following the `elapsed` time of the
+/// block's [`super::state::GradientBlockState`]. Only rgb colors are
+/// modulated.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::pulse::PulseConfig;
+/// let alert = GradientBlock::new().pulse(PulseConfig::default());
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PulseConfig {
+    pub period: Duration,
+    pub amplitude: f32,
+}
+impl Default for PulseConfig {
+    /// A slow, two second pulse of 30%
+    fn default() -> Self {
+        Self {
+            period: Duration::from_secs(2),
+            amplitude: 0.3,
+        }
+    }
+}
+impl PulseConfig {
+    /// The brightness factor after `elapsed`
+    pub fn factor(&self, elapsed: Duration) -> f32 {
+        if self.period.is_zero() {
+            return 1.0;
+        }
+        let t = elapsed.as_secs_f32() / self.period.as_secs_f32();
+        1.0 + self.amplitude.clamp(0.0, 1.0)
+            * (t * std::f32::consts::TAU).sin()
+    }
+    /// Modulates the border `cells`
+    pub fn render(
+        &self,
+        cells: impl IntoIterator<Item = Position>,
+        elapsed: Duration,
+        buf: &mut Buffer,
+    ) {
+        let f = self.factor(elapsed);
+        for pos in cells {
+            let Some(cell) = buf.cell_mut(pos) else {
+                continue;
+            };
+            if let Color::Rgb(r, g, b) = cell.fg {
+                let scale =
+                    |c: u8| (c as f32 * f).clamp(0.0, 255.0) as u8;
+                cell.fg = Color::Rgb(scale(r), scale(g), scale(b));
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{enums::Corner, structs::progress::ProgressConfig};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::Color,
+};
+use std::time::Duration;
+/// Draws the border progressively around the perimeter when the block
+/// appears, then fades the titles in, for popups and modals.
+///
+/// The animation follows the `elapsed` time of the block's
+/// [`super::state::GradientBlockState`]: the border takes the first
+/// `1.0 - title_fade` of `duration`, the titles the rest.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::reveal::RevealConfig;
+/// # use tui_gradient_block::structs::state::GradientBlockState;
+/// # let mut state = GradientBlockState::default();
+/// # let frame_time = std::time::Duration::from_millis(16);
+/// # let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(20, 5)).unwrap();
+/// let popup = GradientBlock::new().reveal(RevealConfig::default());
+/// // every frame
+/// state.advance(frame_time);
+/// # terminal.draw(|f| {
+/// # let area = f.area();
+/// f.render_stateful_widget(&popup, area, &mut state);
+/// # }).unwrap();
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RevealConfig {
+    pub duration: Duration,
+    pub start: Corner,
+    pub title_fade: f32,
+}
+impl Default for RevealConfig {
+    /// 400ms from the top left corner, the last quarter fading the
+    /// titles in
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(400),
+            start: Corner::TopLeft,
+            title_fade: 0.25,
+        }
+    }
+}
+impl RevealConfig {
+    /// How far the animation is after `elapsed`, 0.0..=1.0
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        match self.duration.is_zero() {
+            true => 1.0,
+            false => (elapsed.as_secs_f32()
+                / self.duration.as_secs_f32())
+            .clamp(0.0, 1.0),
+        }
+    }
+    pub fn is_done(&self, elapsed: Duration) -> bool {
+        self.progress(elapsed) >= 1.0
+    }
+    /// How much of the perimeter is drawn after `elapsed`
+    pub fn border_progress(&self, elapsed: Duration) -> f32 {
+        let share = 1.0 - self.title_fade.clamp(0.0, 1.0);
+        match share > 0.0 {
+            true => (self.progress(elapsed) / share).min(1.0),
+            false => 1.0,
+        }
+    }
+    /// How opaque the titles are after `elapsed`
+    pub fn title_alpha(&self, elapsed: Duration) -> f32 {
+        let fade = self.title_fade.clamp(0.0, 1.0);
+        let t = self.progress(elapsed);
+        match fade > 0.0 {
+            true => ((t - (1.0 - fade)) / fade).clamp(0.0, 1.0),
+            false => (t >= 1.0) as u8 as f32,
+        }
+    }
+    /// Restores the border `cells` of a block rendered in `area` that
+    /// haven't been reached yet to what was there before (`before`,
+    /// taken with [`crate::blend::snapshot`])
+    pub fn hide_border(
+        &self,
+        area: Rect,
+        cells: impl IntoIterator<Item = Position>,
+        before: &Buffer,
+        elapsed: Duration,
+        buf: &mut Buffer,
+    ) {
+        let drawn = self.border_progress(elapsed);
+        let track = ProgressConfig {
+            start: self.start,
+            ..Default::default()
+        };
+        for pos in cells {
+            if track.offset(area, pos) < drawn
+                || !before.area.contains(pos)
+            {
+                continue;
+            }
+            if let Some(cell) = buf.cell_mut(pos) {
+                *cell = before[pos].clone();
+            }
+        }
+    }
+    /// Fades in every cell changed since `before` was taken, mixing its
+    /// foreground with the background underneath. Non-rgb colors can't
+    /// be mixed and pop in halfway instead.
+    pub fn fade_in(
+        &self,
+        alpha: f32,
+        before: &Buffer,
+        buf: &mut Buffer,
+    ) {
+        for pos in before.area.positions() {
+            let old = &before[pos];
+            let cell = &mut buf[pos];
+            if cell == old {
+                continue;
+            }
+            let (br, bg, bb) = match old.bg {
+                Color::Rgb(r, g, b) => (r, g, b),
+                _ => (0, 0, 0),
+            };
+            match cell.fg {
+                Color::Rgb(r, g, b) => {
+                    let mix = |c: u8, base: u8| {
+                        (base as f32
+                            + (c as f32 - base as f32) * alpha)
+                            as u8
+                    };
+                    cell.fg = Color::Rgb(
+                        mix(r, br),
+                        mix(g, bg),
+                        mix(b, bb),
+                    );
+                }
+                _ if alpha < 0.5 => *cell = old.clone(),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::structs::ticks::Ticks;
+use ratatui::style::Color;
+/// What the labels of a [`Ruler`] count
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RulerLabels {
+    /// The offset of the tick from the start of the edge, plus `start`;
+    /// with `start` set to the x (or y) of the area this gives the
+    /// terminal column (or row)
+    Index { start: i64 },
+    /// The edge mapped linearly onto `start..=end`, for axes in the
+    /// units of the data being framed
+    Range { start: f64, end: f64 },
+}
+impl Default for RulerLabels {
+    fn default() -> Self {
+        Self::Index { start: 0 }
+    }
+}
+impl RulerLabels {
+    /// The label of the tick at `offset` along an edge of `len` cells
+    pub fn label(&self, offset: u16, len: u16) -> String {
+        match *self {
+            Self::Index { start } => {
+                start.saturating_add(offset as i64).to_string()
+            }
+            Self::Range { start, end } => {
+                let t = match len {
+                    0 | 1 => 0.0,
+                    len => offset as f64 / (len - 1) as f64,
+                };
+                let v = start + (end - start) * t;
+                ((v * 100.0).round() / 100.0).to_string()
+            }
+        }
+    }
+}
+/// Ticks with numeric labels, for blocks framing charts or editors.
+///
+/// Each label is written on the line right after its tick, left to
+/// right on the top and bottom and one digit per row on the sides.
+/// Labels that would run into the next tick or the corner are left
+/// out. They keep the gradient of the segment unless `color` is set.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::ruler::Ruler;
+/// let block = GradientBlock::new()
+///     .top_ruler(Ruler::new(10, '┬'))
+///     .left_ruler(Ruler::new(5, '├').range(0.0, 1.0));
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ruler {
+    pub ticks: Ticks,
+    pub labels: RulerLabels,
+    /// The color of the labels
+    pub color: Option<Color>,
+}
+impl Ruler {
+    /// A tick with its index every `every` cells
+    pub fn new(every: u16, symbol: char) -> Self {
+        Self {
+            ticks: Ticks::every(every, symbol),
+            ..Default::default()
+        }
+    }
+    /// Counts from `start` instead of 0
+    pub fn start(mut self, start: i64) -> Self {
+        self.labels = RulerLabels::Index { start };
+        self
+    }
+    /// Labels the edge with values from `start` to `end`
+    pub fn range(mut self, start: f64, end: f64) -> Self {
+        self.labels = RulerLabels::Range { start, end };
+        self
+    }
+    /// Colors the ticks and labels instead of leaving them to the
+    /// gradient
+    pub fn color(mut self, color: Color) -> Self {
+        self.ticks.color = Some(color);
+        self.color = Some(color);
+        self
+    }
+}
@@ -0,0 +1,123 @@
+use crate::structs::border_segment::BorderSegment;
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+/// How far a pane is scrolled: `position` is the first visible row out
+/// of `content_length` rows
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ScrollPosition {
+    pub content_length: usize,
+    pub position: usize,
+}
+impl ScrollPosition {
+    pub fn new(content_length: usize, position: usize) -> Self {
+        Self {
+            content_length,
+            position,
+        }
+    }
+}
+/// A scrollbar drawn in the right border, so scrollable panes don't
+/// need a `Scrollbar` fighting the border over the same column.
+///
+/// The thumb is drawn with `thumb` in the colors of the gradient; the
+/// rest of the side is the track, drawn in the `track` color. Nothing is
+/// drawn when the content fits. The position is taken from the block's
+/// [`super::state::GradientBlockState::scroll`] when rendered with a
+/// state that has one, so the block itself doesn't have to be rebuilt
+/// on every scroll.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # let lines = vec![""; 100];
+/// # let offset = 10;
+/// let pane = GradientBlock::new().scrollbar(lines.len(), offset);
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollbarConfig {
+    pub scroll: ScrollPosition,
+    /// How many rows are visible at once, the inner height of the block
+    /// if `None`
+    pub viewport_length: Option<usize>,
+    pub thumb: char,
+    pub track: Color,
+}
+impl Default for ScrollbarConfig {
+    /// A heavy thumb on a dark gray track
+    fn default() -> Self {
+        Self {
+            scroll: ScrollPosition::default(),
+            viewport_length: None,
+            thumb: '┃',
+            track: Color::DarkGray,
+        }
+    }
+}
+impl ScrollbarConfig {
+    /// The first row and the length of the thumb on a track of `track`
+    /// rows showing `viewport` rows of content at `scroll`, `None` if
+    /// the content fits
+    pub fn thumb(
+        &self,
+        scroll: ScrollPosition,
+        viewport: usize,
+        track: u16,
+    ) -> Option<(u16, u16)> {
+        let content = scroll.content_length;
+        if content <= viewport || track == 0 {
+            return None;
+        }
+        let track_f = track as f64;
+        let len = (track_f * viewport as f64 / content as f64)
+            .round()
+            .clamp(1.0, track_f);
+        let max = content - viewport;
+        let start = ((track_f - len)
+            * scroll.position.min(max) as f64
+            / max as f64)
+            .round();
+        Some((start as u16, len as u16))
+    }
+    /// Draws the scrollbar on the line of the `right` segment of a
+    /// block rendered in `area`, between the corners. `scroll` overrides
+    /// the position of the config and `inner` is the height of the
+    /// block's content area.
+    pub fn render(
+        &self,
+        right: &BorderSegment,
+        area: Rect,
+        scroll: Option<ScrollPosition>,
+        inner: u16,
+        buf: &mut Buffer,
+    ) {
+        let track = right.edge_len(area).saturating_sub(2);
+        let viewport = self.viewport_length.unwrap_or(inner as usize);
+        let Some((start, len)) = self.thumb(
+            scroll.unwrap_or(self.scroll),
+            viewport,
+            track,
+        ) else {
+            return;
+        };
+        for row in 0..track {
+            let Some(cell) = right
+                .line_position(area, row + 1)
+                .and_then(|pos| buf.cell_mut(pos))
+            else {
+                continue;
+            };
+            match (start..start + len).contains(&row) {
+                true => {
+                    cell.set_char(self.thumb);
+                }
+                false => cell.fg = self.track,
+            }
+        }
+    }
+}
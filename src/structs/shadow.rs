@@ -0,0 +1,86 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier},
+};
+/// A drop shadow rendered under a block.
+///
+/// The shadow is the block's area moved by `offset_x`/`offset_y`; only the
+/// part not covered by the block itself is drawn, and it is clipped to the
+/// buffer. With a `color` the shadow cells get that background, otherwise
+/// the cells already there are darkened by `dim` (0.0..=1.0).
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::shadow::ShadowConfig;
+/// let popup = GradientBlock::new().shadow(ShadowConfig::default());
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShadowConfig {
+    pub offset_x: i16,
+    pub offset_y: i16,
+    pub color: Option<Color>,
+    pub dim: f32,
+}
+impl Default for ShadowConfig {
+    /// One cell to the right and one down, darkening what is underneath
+    fn default() -> Self {
+        Self {
+            offset_x: 1,
+            offset_y: 1,
+            color: None,
+            dim: 0.6,
+        }
+    }
+}
+impl ShadowConfig {
+    /// The area covered by the shadow of a block rendered in `area`,
+    /// clipped to `bounds`
+    pub fn area(&self, area: Rect, bounds: Rect) -> Rect {
+        let x = (area.x as i32 + self.offset_x as i32).max(0);
+        let y = (area.y as i32 + self.offset_y as i32).max(0);
+        Rect {
+            x: x.min(u16::MAX as i32) as u16,
+            y: y.min(u16::MAX as i32) as u16,
+            ..area
+        }
+        .intersection(bounds)
+    }
+    /// Darkens a color by `dim`. Non-rgb colors can't be darkened
+    /// gradually and fall back to `fallback`.
+    fn darken(&self, c: Color, fallback: Color) -> Color {
+        let f = 1.0 - self.dim.clamp(0.0, 1.0);
+        match c {
+            Color::Rgb(r, g, b) => Color::Rgb(
+                (r as f32 * f) as u8,
+                (g as f32 * f) as u8,
+                (b as f32 * f) as u8,
+            ),
+            _ => fallback,
+        }
+    }
+    /// Draws the shadow of a block rendered in `area`
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        let shadow = self.area(area, buf.area);
+        for pos in shadow.positions() {
+            if area.contains(pos) {
+                continue;
+            }
+            let cell = &mut buf[pos];
+            match self.color {
+                Some(c) => {
+                    cell.bg = c;
+                }
+                None => {
+                    cell.fg = self.darken(cell.fg, Color::DarkGray);
+                    cell.bg = self.darken(cell.bg, Color::Black);
+                    cell.modifier.insert(Modifier::DIM);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{enums::Corner, structs::progress::ProgressConfig};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::Color,
+};
+use std::time::Duration;
+/// A highlight band sweeping clockwise around the border.
+///
+/// The band is `width` (a fraction of the perimeter) wide, goes around
+/// once every `period` starting at `start`, and brightens the border's
+/// colors towards `highlight` by up to `intensity` at its center. It
+/// follows the `elapsed` time of the block's
+/// [`super::state::GradientBlockState`].
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::shimmer::ShimmerConfig;
+/// let selected = GradientBlock::new().shimmer(ShimmerConfig::default());
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShimmerConfig {
+    pub period: Duration,
+    pub width: f32,
+    pub intensity: f32,
+    pub highlight: Color,
+    pub start: Corner,
+}
+impl Default for ShimmerConfig {
+    /// A white band an eighth of the perimeter wide, going around every
+    /// three seconds
+    fn default() -> Self {
+        Self {
+            period: Duration::from_secs(3),
+            width: 0.125,
+            intensity: 0.7,
+            highlight: Color::Rgb(255, 255, 255),
+            start: Corner::TopLeft,
+        }
+    }
+}
+impl ShimmerConfig {
+    /// Where the center of the band is along the perimeter after
+    /// `elapsed`, 0.0..1.0
+    pub fn position(&self, elapsed: Duration) -> f32 {
+        match self.period.is_zero() {
+            true => 0.0,
+            false => (elapsed.as_secs_f32()
+                / self.period.as_secs_f32())
+            .fract(),
+        }
+    }
+    /// Brightens the border `cells` of a block rendered in `area` that
+    /// are under the band
+    pub fn render(
+        &self,
+        area: Rect,
+        cells: impl IntoIterator<Item = Position>,
+        elapsed: Duration,
+        buf: &mut Buffer,
+    ) {
+        let Color::Rgb(hr, hg, hb) = self.highlight else {
+            return;
+        };
+        let half = (self.width / 2.0).max(f32::EPSILON);
+        let center = self.position(elapsed);
+        let track = ProgressConfig {
+            start: self.start,
+            ..Default::default()
+        };
+        for pos in cells {
+            let d = (track.offset(area, pos) - center).abs();
+            let d = d.min(1.0 - d);
+            if d >= half {
+                continue;
+            }
+            let Some(cell) = buf.cell_mut(pos) else {
+                continue;
+            };
+            let Color::Rgb(r, g, b) = cell.fg else {
+                continue;
+            };
+            let f = self.intensity.clamp(0.0, 1.0) * (1.0 - d / half);
+            let mix = |c: u8, h: u8| {
+                (c as f32 + (h as f32 - c as f32) * f) as u8
+            };
+            cell.fg = Color::Rgb(mix(r, hr), mix(g, hg), mix(b, hb));
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::structs::gradient::lattice;
+use ratatui::{buffer::Buffer, layout::Position, style::Color};
+use std::time::Duration;
+/// Single border cells lighting up briefly at random, a starfield
+/// twinkle over the border's gradient.
+///
+/// Every cell gets a chance of `density` to twinkle once every
+/// `duration`, at its own moment, and brightens towards `highlight` by
+/// up to `intensity` halfway through. Which cells twinkle and when only
+/// depends on `seed` and the `elapsed` time of the block's
+/// [`super::state::GradientBlockState`], so a replayed clock gives the
+/// same sparkles. Drawn on top of the pulse and shimmer.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::sparkle::SparkleConfig;
+/// # use tui_gradient_block::theme_presets::dark::t_midnight_blurple;
+/// let starry = GradientBlock::new()
+///     .with_gradient(t_midnight_blurple::theme().vertical)
+///     .sparkle(SparkleConfig::default());
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SparkleConfig {
+    pub seed: u32,
+    /// The chance of a cell twinkling in each `duration`, 0.0..=1.0
+    pub density: f32,
+    /// How long a twinkle lasts
+    pub duration: Duration,
+    pub intensity: f32,
+    pub highlight: Color,
+}
+impl Default for SparkleConfig {
+    /// White twinkles of 0.8 seconds on about one cell in eight
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            density: 0.125,
+            duration: Duration::from_millis(800),
+            intensity: 0.8,
+            highlight: Color::Rgb(255, 255, 255),
+        }
+    }
+}
+impl SparkleConfig {
+    /// How bright the twinkle of the cell at `pos` is after `elapsed`,
+    /// 0.0 when it isn't twinkling up to `intensity` at its peak
+    pub fn brightness(
+        &self,
+        pos: Position,
+        elapsed: Duration,
+    ) -> f32 {
+        if self.duration.is_zero() {
+            return 0.0;
+        }
+        let (x, y) = (pos.x as i32, pos.y as i32);
+        // every cell twinkles at its own moment within the duration
+        let start = lattice(x, y, self.seed);
+        let t = elapsed.as_secs_f32() / self.duration.as_secs_f32()
+            + start;
+        let slot = t.floor() as u32;
+        let seed =
+            self.seed.wrapping_add(slot.wrapping_mul(0x632b_e5ab));
+        if lattice(x, y, seed ^ 0x5bd1_e995) >= self.density {
+            return 0.0;
+        }
+        let f = 1.0 - (2.0 * t.fract() - 1.0).abs();
+        self.intensity.clamp(0.0, 1.0) * f
+    }
+    /// Brightens the border `cells` that are twinkling after `elapsed`
+    pub fn render(
+        &self,
+        cells: impl IntoIterator<Item = Position>,
+        elapsed: Duration,
+        buf: &mut Buffer,
+    ) {
+        let Color::Rgb(hr, hg, hb) = self.highlight else {
+            return;
+        };
+        for pos in cells {
+            let f = self.brightness(pos, elapsed);
+            if f <= 0.0 {
+                continue;
+            }
+            let Some(cell) = buf.cell_mut(pos) else {
+                continue;
+            };
+            let Color::Rgb(r, g, b) = cell.fg else {
+                continue;
+            };
+            let mix = |c: u8, h: u8| {
+                (c as f32 + (h as f32 - c as f32) * f) as u8
+            };
+            cell.fg = Color::Rgb(mix(r, hr), mix(g, hg), mix(b, hb));
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::enums::Corner;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Margin, Position, Rect},
+};
+/// An animated glyph cycling in a corner of the border, for "loading"
+/// panels.
+///
+/// The frame shown follows the `frame` of the block's
+/// [`super::state::GradientBlockState`]; the glyph keeps the color the
+/// border gradient gave the corner.
+/// # Example
+/// ```
+/// # use tui_gradient_block::enums::Corner;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::spinner::SpinnerConfig;
+/// let loading = GradientBlock::new().spinner(SpinnerConfig {
+///     corner: Corner::TopRight,
+///     ..Default::default()
+/// });
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpinnerConfig {
+    pub corner: Corner,
+    pub frames: Vec<char>,
+}
+impl Default for SpinnerConfig {
+    /// A braille spinner in the top right corner
+    fn default() -> Self {
+        Self {
+            corner: Corner::TopRight,
+            frames: "⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏".chars().collect(),
+        }
+    }
+}
+impl SpinnerConfig {
+    /// The position of the spinner for a block rendered in `area` with
+    /// `margin` around its border
+    pub fn position(&self, area: Rect, margin: Margin) -> Position {
+        self.corner.position(area.inner(margin))
+    }
+    /// Draws the spinner's glyph for `frame`
+    pub fn render(
+        &self,
+        area: Rect,
+        margin: Margin,
+        frame: u64,
+        buf: &mut Buffer,
+    ) {
+        if self.frames.is_empty() || area.is_empty() {
+            return;
+        }
+        let glyph =
+            self.frames[(frame % self.frames.len() as u64) as usize];
+        if let Some(cell) = buf.cell_mut(self.position(area, margin))
+        {
+            cell.set_char(glyph);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+/// Animation state for a [`crate::gradient_block::GradientBlock`],
+/// rendered with `frame.render_stateful_widget`.
+///
+/// Call [`Self::tick`] (or [`Self::advance`] or [`Self::tick_with`] for
+/// time based animations) once per frame to advance the animations.
+/// # Example
+/// ```no_run
+/// # use tui_gradient_block::structs::state::GradientBlockState;
+/// # fn main() -> std::io::Result<()> {
+/// # let mut terminal = ratatui::init();
+/// # let block = tui_gradient_block::gradient_block::GradientBlock::new();
+/// let mut state = GradientBlockState::default();
+/// loop {
+///     state.tick();
+///     terminal.draw(|f| {
+///         f.render_stateful_widget(&block, f.area(), &mut state)
+///     })?;
+/// }
+/// # }
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GradientBlockState {
+    /// How many frames have been ticked
+    pub frame: u64,
+    /// How much time has been advanced
+    pub elapsed: std::time::Duration,
+    /// The clock reading of the first [`Self::tick_with`] since the
+    /// last reset, which `elapsed` counts from
+    pub clock_start: Option<std::time::Duration>,
+    /// The position of the scrollbar, overriding the one set on the
+    /// block
+    pub scroll: Option<super::scrollbar::ScrollPosition>,
+    /// The value of the block's value label, overriding the one set on
+    /// the block
+    pub value: Option<super::value_label::LabelValue>,
+    /// Buffers reused from frame to frame
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scratch: RenderScratch,
+}
+/// Buffers the stateful render path keeps between frames, so rendering
+/// a block again at the same size reuses them instead of allocating a
+/// buffer the size of the block for every side. Ignored when comparing,
+/// hashing or serializing states.
+#[derive(Clone, Debug, Default)]
+pub struct RenderScratch {
+    pub(crate) side: ratatui::buffer::Buffer,
+}
+impl PartialEq for RenderScratch {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for RenderScratch {}
+impl std::hash::Hash for RenderScratch {
+    fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+}
+impl GradientBlockState {
+    /// Advances the animations by one frame
+    pub fn tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+    }
+    /// Advances the animations by one frame that took `dt`
+    pub fn advance(&mut self, dt: std::time::Duration) {
+        self.tick();
+        self.elapsed = self.elapsed.saturating_add(dt);
+    }
+    /// Advances the animations by one frame, to the time `clock` reads.
+    /// `elapsed` counts from the first reading, and stays put if the
+    /// clock goes back.
+    /// # Example
+    /// ```no_run
+    /// # use tui_gradient_block::clock::SystemClock;
+    /// # fn main() -> std::io::Result<()> {
+    /// # let mut terminal = ratatui::init();
+    /// # let block = tui_gradient_block::gradient_block::GradientBlock::new();
+    /// # let mut state = tui_gradient_block::structs::state::GradientBlockState::default();
+    /// let clock = SystemClock::new();
+    /// loop {
+    ///     state.tick_with(&clock);
+    ///     terminal.draw(|f| {
+    ///         f.render_stateful_widget(&block, f.area(), &mut state)
+    ///     })?;
+    /// }
+    /// # }
+    /// ```
+    pub fn tick_with(
+        &mut self,
+        clock: &(impl crate::clock::Clock + ?Sized),
+    ) {
+        let now = clock.now();
+        let start = *self.clock_start.get_or_insert(now);
+        self.tick();
+        self.elapsed = self.elapsed.max(now.saturating_sub(start));
+    }
+    /// Starts the animations over, e.g. when a popup is shown again.
+    /// The scroll position and label value are kept.
+    pub fn reset(&mut self) {
+        *self = Self {
+            scroll: self.scroll,
+            value: self.value,
+            scratch: std::mem::take(&mut self.scratch),
+            ..Self::default()
+        };
+    }
+    /// Sets the position of the scrollbar
+    pub fn scroll_to(
+        &mut self,
+        content_length: usize,
+        position: usize,
+    ) {
+        self.scroll = Some(super::scrollbar::ScrollPosition::new(
+            content_length,
+            position,
+        ));
+    }
+    /// Sets the value shown by the block's value label
+    pub fn set_value(&mut self, value: f64) {
+        self.value = Some(super::value_label::LabelValue(value));
+    }
+}
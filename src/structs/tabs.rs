@@ -0,0 +1,143 @@
+use crate::structs::{
+    border_segment::BorderSegment, gradient::GradientDescriptor,
+};
+use colorgrad::Gradient;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Span,
+};
+use unicode_width::UnicodeWidthStr;
+/// A tab bar drawn in the top border, so the tabs line up with the
+/// border instead of sitting in a separate `Tabs` widget above it.
+///
+/// Each label is padded with a space on both sides and the labels are
+/// separated by `divider`. Labels keep the border gradient; the
+/// selected one is drawn bold and reversed, or bold in the colors of
+/// `highlight` when set. The bar starts after the top left corner, or
+/// after a title there, and is cut off at the next title or the top
+/// right corner. Wide characters take up two cells.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// let block = GradientBlock::new().tabs(&["Files", "Search", "Git"], 1);
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TabsConfig {
+    pub labels: Vec<String>,
+    pub selected: usize,
+    pub divider: char,
+    pub highlight: Option<GradientDescriptor>,
+}
+impl Default for TabsConfig {
+    /// No tabs, divided by `│` once added
+    fn default() -> Self {
+        Self {
+            labels: Vec::new(),
+            selected: 0,
+            divider: '│',
+            highlight: None,
+        }
+    }
+}
+impl TabsConfig {
+    pub fn new<S: ToString>(labels: &[S], selected: usize) -> Self {
+        Self {
+            labels: labels.iter().map(ToString::to_string).collect(),
+            selected,
+            ..Default::default()
+        }
+    }
+    pub fn divider(mut self, divider: char) -> Self {
+        self.divider = divider;
+        self
+    }
+    /// Colors the selected tab with `highlight`, running over its label
+    pub fn highlight(
+        mut self,
+        highlight: GradientDescriptor,
+    ) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+    /// The graphemes of the tab bar in order: the labels padded with
+    /// spaces and the dividers between them, with the style of the
+    /// selected tab
+    pub fn cells(&self) -> Vec<(String, Option<Style>)> {
+        let mut cells = Vec::new();
+        for (i, label) in self.labels.iter().enumerate() {
+            if i > 0 {
+                cells.push((self.divider.to_string(), None));
+            }
+            let text = format!(" {label} ");
+            let text: Vec<String> = Span::raw(&text)
+                .styled_graphemes(Style::default())
+                .map(|g| g.symbol.to_string())
+                .collect();
+            let last = text.len().saturating_sub(1).max(1) as f32;
+            cells.extend(text.into_iter().enumerate().map(
+                |(j, g)| {
+                    let style = (i == self.selected).then(|| {
+                        let style =
+                            Style::new().add_modifier(Modifier::BOLD);
+                        match &self.highlight {
+                            Some(highlight) => {
+                                style.fg(crate::to_ratatui_color!(
+                                    highlight.at(j as f32 / last)
+                                ))
+                            }
+                            None => {
+                                style.add_modifier(Modifier::REVERSED)
+                            }
+                        }
+                    });
+                    (g, style)
+                },
+            ));
+        }
+        cells
+    }
+    /// Draws the tabs on the line of the `top` segment of a block
+    /// rendered in `area`, in the first run of cells `titles` leaves
+    /// free, see [`BorderSegment::free_run`]
+    pub fn render(
+        &self,
+        top: &BorderSegment,
+        area: Rect,
+        titles: &[Rect],
+        buf: &mut Buffer,
+    ) {
+        let run = top.free_run(area, titles);
+        let mut offset = run.start;
+        for (symbol, style) in self.cells() {
+            let width = symbol.width() as u16;
+            if offset + width > run.end {
+                break;
+            }
+            for o in offset..offset + width {
+                let Some(cell) = top
+                    .line_position(area, o)
+                    .and_then(|pos| buf.cell_mut(pos))
+                else {
+                    continue;
+                };
+                // the cells under the rest of a wide character are
+                // reset, like `Buffer::set_string` does
+                if o > offset {
+                    cell.reset();
+                    continue;
+                }
+                cell.set_symbol(&symbol);
+                if let Some(style) = style {
+                    cell.set_style(style);
+                }
+            }
+            offset += width;
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use ratatui::style::Color;
+/// Tick marks along a side, for blocks framing charts or timelines.
+///
+/// Ticks are placed every `every` cells from the start of the edge
+/// (left or top), and at the extra offsets in `at`. The corners are
+/// never replaced. Ticks keep the color the gradient gives their cell
+/// unless `color` is set.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::ticks::Ticks;
+/// let block = GradientBlock::new().bottom_ticks(Ticks {
+///     every: 10,
+///     symbol: '┴',
+///     ..Default::default()
+/// });
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ticks {
+    /// The spacing of evenly spaced ticks, 0 for none
+    pub every: u16,
+    /// Offsets of extra ticks, from the start of the edge
+    pub at: Vec<u16>,
+    pub symbol: char,
+    pub color: Option<Color>,
+}
+impl Default for Ticks {
+    /// No ticks, drawn with `┼` once placed
+    fn default() -> Self {
+        Self {
+            every: 0,
+            at: Vec::new(),
+            symbol: '┼',
+            color: None,
+        }
+    }
+}
+impl Ticks {
+    /// Evenly spaced ticks
+    pub fn every(every: u16, symbol: char) -> Self {
+        Self {
+            every,
+            symbol,
+            ..Default::default()
+        }
+    }
+    /// Ticks at the given offsets
+    pub fn at(at: Vec<u16>, symbol: char) -> Self {
+        Self {
+            at,
+            symbol,
+            ..Default::default()
+        }
+    }
+    /// Colors the ticks instead of leaving them to the gradient
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+    /// The offsets of the ticks along an edge of `len` cells, in order,
+    /// leaving out the corners at both ends
+    pub fn offsets(&self, len: u16) -> Vec<u16> {
+        let last = len.saturating_sub(1);
+        let mut offsets: Vec<u16> = match self.every {
+            0 => Vec::new(),
+            every => (1..)
+                .map_while(|i: u16| i.checked_mul(every))
+                .take_while(|o| *o < last)
+                .collect(),
+        };
+        offsets
+            .extend(self.at.iter().filter(|o| **o > 0 && **o < last));
+        offsets.sort_unstable();
+        offsets.dedup();
+        offsets
+    }
+}
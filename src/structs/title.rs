@@ -1,4 +1,232 @@
-use crate::types::T;
+#[cfg(feature = "serde")]
+use crate::types::E;
+use crate::{enums::ThemeVariant, types::T};
+use ratatui::{
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::block::title::Position,
+};
+use std::collections::HashMap;
+/// How [`TitleSet::generate`] lays out and colors the titles
+/// # Example
+/// ```
+/// # use ratatui::layout::Alignment;
+/// # use ratatui::style::Modifier;
+/// # use tui_gradient_block::structs::title::TitleOptions;
+/// let opts = TitleOptions::default()
+///     .alignment(Alignment::Left)
+///     .modifiers(Modifier::BOLD)
+///     .gradient(true);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TitleOptions {
+    /// `None` leaves the alignment to the block
+    pub alignment: Option<Alignment>,
+    pub position: Position,
+    pub modifiers: Modifier,
+    /// Runs the colors as a gradient over every title instead of
+    /// drawing them in the first color
+    pub gradient: bool,
+}
+impl Default for TitleOptions {
+    /// Centered on top in a solid color, like the preset titles
+    fn default() -> Self {
+        Self {
+            alignment: Some(Alignment::Center),
+            position: Position::Top,
+            modifiers: Modifier::empty(),
+            gradient: false,
+        }
+    }
+}
+impl TitleOptions {
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+    pub fn modifiers(mut self, modifiers: Modifier) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+    pub fn gradient(mut self, gradient: bool) -> Self {
+        self.gradient = gradient;
+        self
+    }
+}
+/// A background painted behind every title so it stands out against a
+/// busy border, optionally with end caps that make it look like a pill.
+///
+/// The caps are drawn in `color` on the border's own background, so
+/// half circle glyphs read as the rounded ends of the title's
+/// background.
+/// # Example
+/// ```
+/// # use ratatui::style::Color;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::title::TitleBackground;
+/// let block = GradientBlock::new()
+///     .title_top(" Logs ")
+///     .title_background(TitleBackground::new(Color::Blue).caps('▐', '▌'));
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TitleBackground {
+    pub color: Color,
+    /// The glyphs before and after the title
+    pub caps: Option<(char, char)>,
+}
+impl TitleBackground {
+    /// The powerline half circles, rounded ends for terminals with a
+    /// powerline or Nerd Font
+    pub const ROUND_CAPS: (char, char) = ('\u{e0b6}', '\u{e0b4}');
+    /// A plain background, without caps
+    pub const fn new(color: Color) -> Self {
+        Self { color, caps: None }
+    }
+    /// A background with [`Self::ROUND_CAPS`]
+    pub const fn pill(color: Color) -> Self {
+        Self {
+            color,
+            caps: Some(Self::ROUND_CAPS),
+        }
+    }
+    pub const fn caps(mut self, left: char, right: char) -> Self {
+        self.caps = Some((left, right));
+        self
+    }
+    /// The number of cells the caps add to a title
+    pub const fn caps_width(&self) -> u16 {
+        match self.caps {
+            Some(_) => 2,
+            None => 0,
+        }
+    }
+    /// Draws `title` with the background in `area`, the cells the title
+    /// takes up with its caps. Caps that fall outside `area` are left
+    /// out, as is the end of a title that doesn't fit.
+    pub fn render(
+        &self,
+        title: &Line,
+        area: ratatui::layout::Rect,
+        buf: &mut ratatui::buffer::Buffer,
+    ) {
+        if area.is_empty() {
+            return;
+        }
+        let cap = |buf: &mut ratatui::buffer::Buffer, x, c| {
+            if let Some(cell) = buf.cell_mut((x, area.y)) {
+                cell.set_char(c).fg = self.color;
+            }
+        };
+        let mut text = area;
+        if let Some((left, _)) = self.caps {
+            cap(buf, area.x, left);
+            text.x += 1;
+            text.width -= 1;
+        }
+        let width = (title.width() as u16).min(text.width);
+        text.width = width;
+        buf.set_style(text, Style::new().bg(self.color));
+        buf.set_line(text.x, text.y, title, width);
+        if let Some((_, right)) = self.caps
+            && text.right() < area.right()
+        {
+            cap(buf, text.right(), right);
+        }
+    }
+}
+/// `text` as a line with one span per char, colored along `gradient`
+/// from the first char to the last, on top of `style`
+pub fn gradient_text(
+    text: &str,
+    gradient: &dyn colorgrad::Gradient,
+    style: Style,
+) -> Line<'static> {
+    gradient_spans(
+        vec![Span::styled(text.to_string(), style)],
+        gradient,
+        ..,
+    )
+}
+/// `spans` as a line, with the spans in `range` colored along
+/// `gradient` one char at a time and the others left as they are.
+///
+/// The gradient runs over the chars of the spans in `range` only, so
+/// an icon can keep its own color while the text next to it is
+/// graded. The colored spans keep the rest of their style.
+/// # Example
+/// ```
+/// # use ratatui::style::Color;
+/// # use ratatui::style::Style;
+/// # use ratatui::text::Span;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::title::gradient_spans;
+/// # use tui_gradient_block::theme_presets::cool::t_minty_green;
+/// let title = gradient_spans(
+///     vec![
+///         Span::styled("\u{e7a8} ", Style::new().fg(Color::Rgb(222, 165, 132))),
+///         Span::raw("Cargo.toml"),
+///     ],
+///     &t_minty_green::d_to_l(),
+///     1..,
+/// );
+/// let block = GradientBlock::new().title_top(title);
+/// ```
+pub fn gradient_spans<'a>(
+    spans: Vec<Span<'a>>,
+    gradient: &dyn colorgrad::Gradient,
+    range: impl std::ops::RangeBounds<usize>,
+) -> Line<'a> {
+    let len: usize = spans
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| range.contains(i))
+        .map(|(_, s)| s.content.chars().count())
+        .sum();
+    let last = len.saturating_sub(1).max(1) as f32;
+    let mut offset = 0;
+    let mut out = Vec::with_capacity(spans.len() + len);
+    for (i, span) in spans.into_iter().enumerate() {
+        if !range.contains(&i) {
+            out.push(span);
+            continue;
+        }
+        for c in span.content.chars() {
+            let color = gradient.at(offset as f32 / last);
+            out.push(Span::styled(
+                c.to_string(),
+                span.style.fg(crate::to_ratatui_color!(color)),
+            ));
+            offset += 1;
+        }
+    }
+    Line::from(out)
+}
+/// A title for every variation of a theme.
+///
+/// With the `serde` feature it serializes as a map from
+/// [`ThemeVariant`] to the text, position, alignment and style of the
+/// title. Styles of single spans are not kept, and variants missing
+/// from the map get their default title.
+/// # Example
+/// ```
+/// # use std::collections::HashMap;
+/// # use tui_gradient_block::enums::ThemeVariant;
+/// # use tui_gradient_block::theme_presets::cool::t_minty_green;
+/// let titles = t_minty_green::titles().renamed(&HashMap::from([
+///     (ThemeVariant::Up, "Hoch".to_string()),
+///     (ThemeVariant::Down, "Runter".to_string()),
+/// ]));
+/// ```
+#[derive(Clone, Debug)]
 pub struct TitleSet<'a> {
     pub up: T<'a>,
     pub down: T<'a>,
@@ -15,3 +243,249 @@ pub struct TitleSet<'a> {
     pub misc1: T<'a>,
     pub misc2: T<'a>,
 }
+impl<'a> TitleSet<'a> {
+    /// The preset titles ("Up", "Two Corners Left", ...), centered on
+    /// top and drawn with `style`
+    pub fn new(style: Style) -> Self {
+        Self::from_fn(|v| default_title(v, style))
+    }
+    /// The preset titles drawn with `colors` as laid out by `opts`.
+    /// Without `opts.gradient` only the first color is used; an empty
+    /// slice leaves the titles uncolored.
+    /// # Example
+    /// ```
+    /// # use ratatui::style::Color;
+    /// # use tui_gradient_block::structs::title::TitleOptions;
+    /// # use tui_gradient_block::structs::title::TitleSet;
+    /// let titles = TitleSet::generate(
+    ///     &[Color::Cyan, Color::Magenta],
+    ///     TitleOptions::default().gradient(true),
+    /// );
+    /// ```
+    pub fn generate(colors: &[Color], opts: TitleOptions) -> Self {
+        let style = Style::new().add_modifier(opts.modifiers);
+        let gradient = match (opts.gradient, colors) {
+            (true, [_, _, ..]) => Some(
+                crate::structs::gradient::GradientDescriptor::from_ratatui(
+                    colors,
+                ),
+            ),
+            _ => None,
+        };
+        let style = match colors.first() {
+            Some(c) => style.fg(*c),
+            None => style,
+        };
+        Self::from_fn(|v| {
+            let mut line = match &gradient {
+                Some(g) => gradient_text(v.name(), g, style),
+                None => Line::from(v.name()).style(style),
+            };
+            line.alignment = opts.alignment;
+            (line, opts.position)
+        })
+    }
+    /// A set with `f(variant)` as the title of every variant
+    pub fn from_fn(mut f: impl FnMut(ThemeVariant) -> T<'a>) -> Self {
+        use ThemeVariant as V;
+        Self {
+            up: f(V::Up),
+            down: f(V::Down),
+            left: f(V::Left),
+            right: f(V::Right),
+            top_left: f(V::TopLeft),
+            top_right: f(V::TopRight),
+            bottom_left: f(V::BottomLeft),
+            bottom_right: f(V::BottomRight),
+            double_corners_right: f(V::DoubleCornersRight),
+            double_corners_left: f(V::DoubleCornersLeft),
+            vertical: f(V::Vertical),
+            horizontal: f(V::Horizontal),
+            misc1: f(V::Misc1),
+            misc2: f(V::Misc2),
+        }
+    }
+    /// Titles with custom text, centered on top and drawn with `style`.
+    /// Variants missing from `names` keep their preset text.
+    pub fn from_names(
+        names: &HashMap<ThemeVariant, String>,
+        style: Style,
+    ) -> Self {
+        Self::new(style).renamed(names)
+    }
+    /// Replaces the text of the titles of the variants in `names`,
+    /// keeping their position, alignment and style
+    pub fn renamed(
+        mut self,
+        names: &HashMap<ThemeVariant, String>,
+    ) -> Self {
+        for (v, name) in names {
+            let (line, _) = self.title_mut(*v);
+            let (style, alignment) = (line.style, line.alignment);
+            *line = Line::from(name.clone()).style(style);
+            line.alignment = alignment;
+        }
+        self
+    }
+    /// The title of the variant named by `which`
+    pub fn title(&self, which: ThemeVariant) -> &T<'a> {
+        use ThemeVariant as V;
+        match which {
+            V::Up => &self.up,
+            V::Down => &self.down,
+            V::Left => &self.left,
+            V::Right => &self.right,
+            V::TopLeft => &self.top_left,
+            V::TopRight => &self.top_right,
+            V::BottomLeft => &self.bottom_left,
+            V::BottomRight => &self.bottom_right,
+            V::DoubleCornersLeft => &self.double_corners_left,
+            V::DoubleCornersRight => &self.double_corners_right,
+            V::Vertical => &self.vertical,
+            V::Horizontal => &self.horizontal,
+            V::Misc1 => &self.misc1,
+            V::Misc2 => &self.misc2,
+        }
+    }
+    /// The title of the variant named by `which`, mutably
+    pub fn title_mut(&mut self, which: ThemeVariant) -> &mut T<'a> {
+        use ThemeVariant as V;
+        match which {
+            V::Up => &mut self.up,
+            V::Down => &mut self.down,
+            V::Left => &mut self.left,
+            V::Right => &mut self.right,
+            V::TopLeft => &mut self.top_left,
+            V::TopRight => &mut self.top_right,
+            V::BottomLeft => &mut self.bottom_left,
+            V::BottomRight => &mut self.bottom_right,
+            V::DoubleCornersLeft => &mut self.double_corners_left,
+            V::DoubleCornersRight => &mut self.double_corners_right,
+            V::Vertical => &mut self.vertical,
+            V::Horizontal => &mut self.horizontal,
+            V::Misc1 => &mut self.misc1,
+            V::Misc2 => &mut self.misc2,
+        }
+    }
+    #[cfg(feature = "serde")]
+    pub fn from_json(path: &str) -> Result<Self, E> {
+        crate::generate_from_json!(path, Self)
+    }
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        crate::generate_to_json!(self)
+    }
+}
+fn default_title<'a>(v: ThemeVariant, style: Style) -> T<'a> {
+    (Line::from(v.name()).centered().style(style), Position::Top)
+}
+impl<'a> std::ops::Index<ThemeVariant> for TitleSet<'a> {
+    type Output = T<'a>;
+    fn index(&self, which: ThemeVariant) -> &Self::Output {
+        self.title(which)
+    }
+}
+impl std::ops::IndexMut<ThemeVariant> for TitleSet<'_> {
+    fn index_mut(
+        &mut self,
+        which: ThemeVariant,
+    ) -> &mut Self::Output {
+        self.title_mut(which)
+    }
+}
+/// The serialized form of one title
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TitleData {
+    text: String,
+    #[serde(default)]
+    position: TitlePosition,
+    #[serde(default)]
+    alignment: Option<TitleAlignment>,
+    #[serde(default)]
+    style: Style,
+}
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+enum TitlePosition {
+    #[default]
+    Top,
+    Bottom,
+}
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+enum TitleAlignment {
+    Left,
+    Center,
+    Right,
+}
+#[cfg(feature = "serde")]
+impl From<&T<'_>> for TitleData {
+    fn from((line, position): &T<'_>) -> Self {
+        use ratatui::layout::Alignment as A;
+        Self {
+            text: line
+                .spans
+                .iter()
+                .map(|s| s.content.as_ref())
+                .collect(),
+            position: match position {
+                Position::Top => TitlePosition::Top,
+                Position::Bottom => TitlePosition::Bottom,
+            },
+            alignment: line.alignment.map(|a| match a {
+                A::Left => TitleAlignment::Left,
+                A::Center => TitleAlignment::Center,
+                A::Right => TitleAlignment::Right,
+            }),
+            style: line.style,
+        }
+    }
+}
+#[cfg(feature = "serde")]
+impl<'a> From<TitleData> for T<'a> {
+    fn from(data: TitleData) -> Self {
+        use ratatui::layout::Alignment as A;
+        let mut line = Line::from(data.text).style(data.style);
+        line.alignment = data.alignment.map(|a| match a {
+            TitleAlignment::Left => A::Left,
+            TitleAlignment::Center => A::Center,
+            TitleAlignment::Right => A::Right,
+        });
+        let position = match data.position {
+            TitlePosition::Top => Position::Top,
+            TitlePosition::Bottom => Position::Bottom,
+        };
+        (line, position)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for TitleSet<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        let mut map = serializer
+            .serialize_map(Some(ThemeVariant::ALL.len()))?;
+        for v in ThemeVariant::ALL {
+            map.serialize_entry(&v, &TitleData::from(self.title(v)))?;
+        }
+        map.end()
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TitleSet<'_> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let mut map =
+            HashMap::<ThemeVariant, TitleData>::deserialize(
+                deserializer,
+            )?;
+        Ok(Self::from_fn(|v| match map.remove(&v) {
+            Some(data) => data.into(),
+            None => default_title(v, Style::new()),
+        }))
+    }
+}
@@ -0,0 +1,82 @@
+use crate::structs::{
+    gradient::GradientVariation, state::GradientBlockState,
+};
+use std::time::Duration;
+/// An animated change from one gradient variation to another, for focus
+/// changes and theme switches that shouldn't snap.
+///
+/// The transition follows the `elapsed` time of a
+/// [`GradientBlockState`], counting from `start`; every frame, build
+/// the block with the variation it is at.
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::structs::transition::Transition;
+/// # use tui_gradient_block::{structs::state::GradientBlockState, theme_presets::cool::{t_minty_green, t_misty_blue}};
+/// # let (theme, focused) = (t_misty_blue::theme(), t_minty_green::theme());
+/// # let mut state = GradientBlockState::default();
+/// # let frame_time = Duration::from_millis(16);
+/// # let mut transition;
+/// // on focus
+/// transition = Transition::between(&theme.up, &focused.up, Duration::from_millis(200))
+///     .starting_at(state.elapsed);
+/// // every frame
+/// state.advance(frame_time);
+/// let block = GradientBlock::new().with_gradient(transition.variation(&state));
+/// ```
+#[derive(Clone)]
+pub struct Transition {
+    pub from: GradientVariation,
+    pub to: GradientVariation,
+    pub duration: Duration,
+    /// The `elapsed` time of the state the transition starts at
+    pub start: Duration,
+}
+impl Transition {
+    pub fn between(
+        from: &GradientVariation,
+        to: &GradientVariation,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            from: from.clone(),
+            to: to.clone(),
+            duration,
+            start: Duration::ZERO,
+        }
+    }
+    pub fn starting_at(mut self, start: Duration) -> Self {
+        self.start = start;
+        self
+    }
+    /// How far the transition is after `elapsed`, 0.0..=1.0
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        let elapsed = elapsed.saturating_sub(self.start);
+        match self.duration.is_zero() {
+            true => 1.0,
+            false => (elapsed.as_secs_f32()
+                / self.duration.as_secs_f32())
+            .clamp(0.0, 1.0),
+        }
+    }
+    pub fn is_done(&self, elapsed: Duration) -> bool {
+        self.progress(elapsed) >= 1.0
+    }
+    /// The variation after `elapsed`: `from` before the start, `to` once
+    /// done and a mix of both in between
+    pub fn at(&self, elapsed: Duration) -> GradientVariation {
+        match self.progress(elapsed) {
+            t if t <= 0.0 => self.from.clone(),
+            t if t >= 1.0 => self.to.clone(),
+            t => self.from.mix(&self.to, t),
+        }
+    }
+    /// The variation at the time `state` is at
+    pub fn variation(
+        &self,
+        state: &GradientBlockState,
+    ) -> GradientVariation {
+        self.at(state.elapsed)
+    }
+}
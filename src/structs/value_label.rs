@@ -0,0 +1,128 @@
+use crate::structs::border_segment::BorderSegment;
+use ratatui::{buffer::Buffer, layout::Rect};
+/// A value drawn in a slot of the border, formatted when the block is
+/// rendered, like `─ 42% ─` or `─ 1.2 GiB/s ─`.
+///
+/// The text is right-aligned in a slot of at least `width` cells with
+/// a space on both sides, so a value that gets shorter doesn't make
+/// the slot jump, and keeps the border gradient under it. The slot is
+/// centered on `at` (0.0..=1.0) of the side's length and kept a line
+/// cell away from the corners; what doesn't fit is cut off. On the left and right sides
+/// the text reads one char per row.
+///
+/// The value is `value`, or the one in
+/// [`GradientBlockState::value`](crate::structs::state::GradientBlockState::value)
+/// when rendered with a state, so it can change every frame without
+/// building the block again.
+/// # Example
+/// ```
+/// # use tui_gradient_block::enums::Side;
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # let mut state = tui_gradient_block::structs::state::GradientBlockState::default();
+/// # struct Download;
+/// # impl Download { fn ratio(&self) -> f64 { 0.42 } }
+/// # let download = Download;
+/// # let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(20, 5)).unwrap();
+/// # terminal.draw(|f| {
+/// # let area = f.area();
+/// let block = GradientBlock::new()
+///     .value_label(Side::Top, |v| format!("{:.0}%", v * 100.0));
+/// state.set_value(download.ratio());
+/// f.render_stateful_widget(&block, area, &mut state);
+/// # }).unwrap();
+/// ```
+pub struct ValueLabel {
+    pub side: crate::enums::Side,
+    /// Where the slot is centered along the side, 1.0 by default
+    pub at: f32,
+    /// The least number of cells the text takes up, not counting the
+    /// spaces around it
+    pub width: u16,
+    /// The value shown when the state doesn't hold one
+    pub value: f64,
+    pub format: Box<dyn Fn(f64) -> String>,
+}
+impl ValueLabel {
+    /// A label at the end of `side`, showing values formatted with
+    /// `format`
+    pub fn new(
+        side: crate::enums::Side,
+        format: impl Fn(f64) -> String + 'static,
+    ) -> Self {
+        Self {
+            side,
+            at: 1.0,
+            width: 0,
+            value: 0.0,
+            format: Box::new(format),
+        }
+    }
+    pub const fn at(mut self, at: f32) -> Self {
+        self.at = at;
+        self
+    }
+    pub const fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+    pub const fn value(mut self, value: f64) -> Self {
+        self.value = value;
+        self
+    }
+    /// The text of the slot for `value`, spaces included
+    pub fn text(&self, value: f64) -> String {
+        let width = self.width as usize;
+        format!(" {:>width$} ", (self.format)(value))
+    }
+    /// Draws the slot for `value` on the line of `segment`, the side of
+    /// a block rendered in `area` the label is on
+    pub fn render(
+        &self,
+        segment: &BorderSegment,
+        area: Rect,
+        value: f64,
+        buf: &mut Buffer,
+    ) {
+        if !segment.should_be_rendered {
+            return;
+        }
+        let text = self.text(value);
+        let n = text.chars().count() as u16;
+        let len = segment.edge_len(area);
+        let center = (self.at.clamp(0.0, 1.0)
+            * len.saturating_sub(1) as f32)
+            .round() as u16;
+        let start = center
+            .saturating_sub(n / 2)
+            .min(len.saturating_sub(n + 2))
+            .max(1);
+        let end = len.saturating_sub(1);
+        for (offset, c) in (start..end).zip(text.chars()) {
+            if let Some(cell) = segment
+                .line_position(area, offset)
+                .and_then(|pos| buf.cell_mut(pos))
+            {
+                cell.set_char(c);
+            }
+        }
+    }
+}
+/// A value for a [`ValueLabel`] kept in a state. Compared and hashed
+/// by its bits, so the state stays `Eq` and `Hash`.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LabelValue(pub f64);
+impl PartialEq for LabelValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+impl Eq for LabelValue {}
+impl std::hash::Hash for LabelValue {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
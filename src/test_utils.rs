@@ -0,0 +1,80 @@
+use crate::{
+    buffer::Buffer, gradient_block::GradientBlock, style::Color,
+};
+use ratatui::{backend::TestBackend, Terminal};
+use std::fmt::Write;
+/// Renders a block through a `TestBackend` terminal of `width` x
+/// `height` and returns the resulting buffer.
+pub fn render(
+    block: &GradientBlock,
+    width: u16,
+    height: u16,
+) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height))
+        .expect("the test backend can't fail");
+    terminal
+        .draw(|f| f.render_widget(block, f.area()))
+        .expect("the test backend can't fail");
+    terminal.backend().buffer().clone()
+}
+/// The symbols of a buffer, one line per row
+pub fn buffer_to_string(buf: &Buffer) -> String {
+    (buf.area.top()..buf.area.bottom())
+        .map(|y| {
+            (buf.area.left()..buf.area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+fn color_name(c: Color) -> String {
+    match c {
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        c => format!("{c:?}"),
+    }
+}
+/// The symbols of a buffer, each row followed by its colored runs:
+/// ```text
+/// ┌──┐
+///   0..4 fg=#ff0000
+/// ```
+/// Runs without colors are left out, so plain cells don't clutter the
+/// snapshot.
+pub fn buffer_to_annotated(buf: &Buffer) -> String {
+    let mut out = String::new();
+    for y in buf.area.top()..buf.area.bottom() {
+        let row: String = (buf.area.left()..buf.area.right())
+            .map(|x| buf[(x, y)].symbol())
+            .collect();
+        let _ = writeln!(out, "{row}");
+        let mut x = buf.area.left();
+        while x < buf.area.right() {
+            let (fg, bg) = (buf[(x, y)].fg, buf[(x, y)].bg);
+            let start = x;
+            while x < buf.area.right()
+                && buf[(x, y)].fg == fg
+                && buf[(x, y)].bg == bg
+            {
+                x += 1;
+            }
+            if fg == Color::Reset && bg == Color::Reset {
+                continue;
+            }
+            let _ = write!(
+                out,
+                "  {}..{}",
+                start - buf.area.x,
+                x - buf.area.x
+            );
+            if fg != Color::Reset {
+                let _ = write!(out, " fg={}", color_name(fg));
+            }
+            if bg != Color::Reset {
+                let _ = write!(out, " bg={}", color_name(bg));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
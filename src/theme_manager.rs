@@ -0,0 +1,150 @@
+use crate::{
+    preset,
+    structs::{
+        border_symbols::SegmentSet,
+        gradient::{GradientTheme, GradientVariation},
+        title::TitleSet,
+    },
+    theme_presets,
+};
+use std::rc::Rc;
+/// Holds the active theme, its titles and a border set for apps that switch themes at
+/// runtime, such as a theme picker.
+///
+/// The theme and titles are shared through `Rc`s (gradients are not
+/// `Send`, so an `Arc` would buy nothing), which makes handing them out
+/// every frame cheap, and [`ThemeManager::generation`] changes on
+/// every switch so apps can tell when blocks they keep around need to
+/// be rebuilt.
+/// # Example
+/// ```
+/// # use tui_gradient_block::gradient_block::GradientBlock;
+/// # use tui_gradient_block::theme_manager::ThemeManager;
+/// let mut themes = ThemeManager::new("misty_blue").unwrap();
+/// // in the picker
+/// themes.set_theme("rusty_ruins");
+/// themes.set_border_set("heavy_rounded");
+/// // when drawing
+/// let block = GradientBlock::new()
+///     .with_gradient(themes.variation(|t| &t.up))
+///     .with_set(themes.border_set());
+/// ```
+#[derive(Clone)]
+pub struct ThemeManager {
+    name: String,
+    theme: Rc<GradientTheme>,
+    titles: Rc<TitleSet<'static>>,
+    set: SegmentSet,
+    generation: u64,
+}
+impl ThemeManager {
+    /// A manager starting with the built-in theme `name`, `None` if
+    /// there is no such theme
+    pub fn new(name: &str) -> Option<Self> {
+        let (theme, titles) = Self::builtin(name)?;
+        Some(Self {
+            name: name.to_string(),
+            theme: Rc::new(theme),
+            titles: Rc::new(titles),
+            set: preset::PLAIN,
+            generation: 0,
+        })
+    }
+    /// A manager starting with a custom theme
+    pub fn custom(
+        name: impl Into<String>,
+        theme: GradientTheme,
+        titles: TitleSet<'static>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            theme: Rc::new(theme),
+            titles: Rc::new(titles),
+            set: preset::PLAIN,
+            generation: 0,
+        }
+    }
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug")
+    )]
+    fn builtin(
+        name: &str,
+    ) -> Option<(GradientTheme, TitleSet<'static>)> {
+        let theme = theme_presets::by_name(name)?;
+        let titles = theme_presets::all_titles()
+            .find(|(n, _)| *n == name)
+            .map(|(_, titles)| titles())?;
+        Some((theme, titles))
+    }
+    /// Switches to the built-in theme `name`. Returns `false` and keeps
+    /// the current theme if there is no such theme.
+    pub fn set_theme(&mut self, name: &str) -> bool {
+        match Self::builtin(name) {
+            Some((theme, titles)) => {
+                self.set_custom(name, theme, titles);
+                true
+            }
+            None => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    name,
+                    "no built-in theme by that name"
+                );
+                false
+            }
+        }
+    }
+    /// Switches to a custom theme
+    pub fn set_custom(
+        &mut self,
+        name: impl Into<String>,
+        theme: GradientTheme,
+        titles: TitleSet<'static>,
+    ) {
+        self.name = name.into();
+        self.theme = Rc::new(theme);
+        self.titles = Rc::new(titles);
+        self.generation += 1;
+    }
+    /// Switches to the preset border set `name` (see
+    /// [`preset::sets`]). Returns `false` and keeps the current set if
+    /// there is no such set.
+    pub fn set_border_set(&mut self, name: &str) -> bool {
+        match preset::set_by_name(name) {
+            Some(set) => {
+                self.set = set;
+                self.generation += 1;
+                true
+            }
+            None => false,
+        }
+    }
+    /// The active border set, [`preset::PLAIN`] unless switched
+    pub fn border_set(&self) -> SegmentSet {
+        self.set.clone()
+    }
+    /// The name of the active theme
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The active theme
+    pub fn theme(&self) -> Rc<GradientTheme> {
+        Rc::clone(&self.theme)
+    }
+    /// The titles of the active theme
+    pub fn titles(&self) -> Rc<TitleSet<'static>> {
+        Rc::clone(&self.titles)
+    }
+    /// A variation of the active theme, picked by `select`
+    pub fn variation(
+        &self,
+        select: impl Fn(&GradientTheme) -> &GradientVariation,
+    ) -> GradientVariation {
+        select(&self.theme).clone()
+    }
+    /// Increases by one on every theme or border set switch
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
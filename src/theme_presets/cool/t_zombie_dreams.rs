@@ -35,79 +35,45 @@ pub const COLOR_5: Color = Color {
 };
 
 pub fn titles() -> TitleSet<'static> {
-    gen_titles!(COLOR_2)
+    TitleSet::generate(
+        &[to_ratatui_color!(COLOR_2)],
+        TitleOptions::default(),
+    )
 }
 pub fn solid(col_num: i32) -> G {
-    Box::new(
-        GradientBuilder::new()
-            .colors(&[match col_num {
-                1 => COLOR_1,
-                2 => COLOR_2,
-                3 => COLOR_3,
-                4 => COLOR_4,
-                5 => COLOR_5,
-                _ => COLOR_1,
-            }])
-            .build::<colorgrad::LinearGradient>()
-            .unwrap(),
-    )
+    crate::gradient!(match col_num {
+        1 => COLOR_1,
+        2 => COLOR_2,
+        3 => COLOR_3,
+        4 => COLOR_4,
+        5 => COLOR_5,
+        _ => COLOR_1,
+    })
 }
 pub fn d_to_l() -> G {
-    Box::new(
-        GradientBuilder::new()
-            .colors(&[COLOR_1, COLOR_2, COLOR_3, COLOR_4, COLOR_5])
-            .build::<colorgrad::LinearGradient>()
-            .unwrap(),
-    )
+    crate::gradient!(COLOR_1, COLOR_2, COLOR_3, COLOR_4, COLOR_5)
 }
 pub fn l_to_d() -> G {
-    Box::new(
-        GradientBuilder::new()
-            .colors(&[COLOR_5, COLOR_4, COLOR_3, COLOR_2, COLOR_1])
-            .build::<colorgrad::LinearGradient>()
-            .unwrap(),
-    )
+    gradient_utils::reverse(d_to_l())
 }
 pub fn d_to_l_d() -> G {
-    Box::new(
-        GradientBuilder::new()
-            .colors(&[
-                COLOR_1, COLOR_1, COLOR_2, COLOR_3, COLOR_4, COLOR_5,
-            ])
-            .build::<colorgrad::LinearGradient>()
-            .unwrap(),
+    crate::gradient!(
+        COLOR_1, COLOR_1, COLOR_2, COLOR_3, COLOR_4, COLOR_5,
     )
 }
 pub fn l_to_d_d() -> G {
-    Box::new(
-        GradientBuilder::new()
-            .colors(&[
-                COLOR_5, COLOR_4, COLOR_3, COLOR_2, COLOR_1, COLOR_1,
-            ])
-            .build::<colorgrad::LinearGradient>()
-            .unwrap(),
-    )
+    gradient_utils::reverse(d_to_l_d())
 }
 pub fn horizontal_g() -> G {
-    Box::new(
-        GradientBuilder::new()
-            .colors(&[
-                COLOR_1, COLOR_2, COLOR_3, COLOR_4, COLOR_5, COLOR_5,
-                COLOR_4, COLOR_3, COLOR_2, COLOR_1,
-            ])
-            .build::<colorgrad::LinearGradient>()
-            .unwrap(),
+    crate::gradient!(
+        COLOR_1, COLOR_2, COLOR_3, COLOR_4, COLOR_5, COLOR_5,
+        COLOR_4, COLOR_3, COLOR_2, COLOR_1,
     )
 }
 pub fn vertical_g() -> G {
-    Box::new(
-        GradientBuilder::new()
-            .colors(&[
-                COLOR_1, COLOR_2, COLOR_4, COLOR_5, COLOR_4, COLOR_2,
-                COLOR_1,
-            ])
-            .build::<colorgrad::LinearGradient>()
-            .unwrap(),
+    crate::gradient!(
+        COLOR_1, COLOR_2, COLOR_4, COLOR_5, COLOR_4, COLOR_2,
+        COLOR_1,
     )
 }
 pub fn bottom_right() -> GV {
@@ -208,55 +174,20 @@ pub fn double_corners_left() -> GV {
 }
 pub fn misc1() -> GV {
     GV {
-        right: Box::new(
-            GradientBuilder::new()
-                .colors(&[COLOR_4, COLOR_3, COLOR_2])
-                .build::<colorgrad::LinearGradient>()
-                .unwrap(),
-        ),
-        left: Box::new(
-            GradientBuilder::new()
-                .colors(&[COLOR_5, COLOR_2, COLOR_5, COLOR_2])
-                .build::<colorgrad::LinearGradient>()
-                .unwrap(),
-        ),
-        bottom: Box::new(
-            GradientBuilder::new()
-                .colors(&[
-                    COLOR_2, COLOR_3, COLOR_4, COLOR_3, COLOR_2,
-                ])
-                .build::<colorgrad::LinearGradient>()
-                .unwrap(),
-        ),
-        top: Box::new(
-            GradientBuilder::new()
-                .colors(&[COLOR_5, COLOR_3, COLOR_2, COLOR_4])
-                .build::<colorgrad::LinearGradient>()
-                .unwrap(),
+        right: crate::gradient!(COLOR_4, COLOR_3, COLOR_2),
+        left: crate::gradient!(COLOR_5, COLOR_2, COLOR_5, COLOR_2),
+        bottom: crate::gradient!(
+            COLOR_2, COLOR_3, COLOR_4, COLOR_3, COLOR_2,
         ),
+        top: crate::gradient!(COLOR_5, COLOR_3, COLOR_2, COLOR_4),
     }
 }
 pub fn misc2() -> GV {
     GV {
-        right: Box::new(
-            GradientBuilder::new()
-                .colors(&[COLOR_2, COLOR_1, COLOR_2])
-                .build::<colorgrad::LinearGradient>()
-                .unwrap(),
-        ),
-        left: Box::new(
-            GradientBuilder::new()
-                .colors(&[COLOR_3, COLOR_2])
-                .build::<colorgrad::LinearGradient>()
-                .unwrap(),
-        ),
+        right: crate::gradient!(COLOR_2, COLOR_1, COLOR_2),
+        left: crate::gradient!(COLOR_3, COLOR_2),
         bottom: solid(2),
-        top: Box::new(
-            GradientBuilder::new()
-                .colors(&[COLOR_3, COLOR_2])
-                .build::<colorgrad::LinearGradient>()
-                .unwrap(),
-        ),
+        top: crate::gradient!(COLOR_3, COLOR_2),
     }
 }
 pub fn full() -> GT {
@@ -277,3 +208,4 @@ pub fn full() -> GT {
         misc2: misc2(),
     }
 }
+crate::cached_preset!();
@@ -4,3 +4,6 @@ pub type T<'a> = (
     ratatui::text::Line<'a>,
     ratatui::widgets::block::title::Position,
 );
+/// A closure coloring a single border cell: `(x, y, t)` where `t` is the
+/// position along the segment from 0.0 to 1.0
+pub type Shader = Box<dyn Fn(u16, u16, f32) -> ratatui::style::Color>;
@@ -0,0 +1,142 @@
+use crate::{
+    enums::Side,
+    gradient_block::{GradientBlock, Position},
+    structs::border_symbols::Symbol,
+    text::Span,
+};
+/// A misconfiguration of a block, found by
+/// [`GradientBlock::validate`]. Its `Display` says what is wrong and how
+/// to fix it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// A drawn side uses a symbol that takes up no cells, which leaves
+    /// holes in the border
+    ZeroWidthSymbol { side: Side, symbol: Symbol },
+    /// The gradient of a drawn side has an empty or invalid domain, so
+    /// it samples the same spot (or NaN) everywhere
+    DegenerateGradient { side: Side },
+    /// The tab highlight has no stops and draws the selected tab black
+    EmptyTabHighlight,
+    /// A title is on a side that isn't drawn
+    TitleOnHiddenSide { index: usize, side: Side },
+    /// A side has a different margin than the top side, which the
+    /// layout math uses for every side
+    ConflictingMargins { side: Side },
+}
+impl std::fmt::Display for ValidationError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Self::ZeroWidthSymbol { side, symbol } => write!(
+                f,
+                "the {side:?} side uses {:?}, which is zero cells wide \
+                 and leaves holes in the border; use a visible symbol, \
+                 or `Symbol::BLANK` for a gap",
+                symbol.as_str()
+            ),
+            Self::DegenerateGradient { side } => write!(
+                f,
+                "the gradient of the {side:?} side has an empty domain; \
+                 give it at least two stops, or use a solid color"
+            ),
+            Self::EmptyTabHighlight => write!(
+                f,
+                "the tab highlight has no stops and draws the selected \
+                 tab black; give it at least one color, or set it to \
+                 `None`"
+            ),
+            Self::TitleOnHiddenSide { index, side } => write!(
+                f,
+                "title {index} is on the {side:?} side, which is hidden; \
+                 show the side or move the title with `title_position`"
+            ),
+            Self::ConflictingMargins { side } => write!(
+                f,
+                "the {side:?} side has a different margin than the top \
+                 side, but the top margin is used for the whole block; \
+                 set margins with `margin` or `margin_sides`"
+            ),
+        }
+    }
+}
+impl std::error::Error for ValidationError {}
+impl GradientBlock<'_> {
+    /// Checks the block for common misconfigurations, returning the
+    /// first one found. Rendering in a debug build panics on a
+    /// zero-width symbol or a degenerate gradient, and reports the
+    /// other problems as `tracing` warnings (with the `tracing`
+    /// feature).
+    /// # Example
+    /// ```
+    /// # let block = tui_gradient_block::gradient_block::GradientBlock::new();
+    /// if let Err(err) = block.validate() {
+    ///     eprintln!("{err}");
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match self.problems().into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+    /// Every misconfiguration [`Self::validate`] looks for
+    pub fn problems(&self) -> Vec<ValidationError> {
+        let segs = &self.border_segments;
+        let mut problems = Vec::new();
+        for seg in segs.rendered() {
+            let s = seg.seg.symbols;
+            let symbols =
+                [s.start, s.end, s.rep_1, s.rep_2, s.center];
+            if let Some(symbol) = symbols
+                .into_iter()
+                .find(|sym| Span::raw(sym.as_str()).width() == 0)
+            {
+                problems.push(ValidationError::ZeroWidthSymbol {
+                    side: seg.side,
+                    symbol,
+                });
+            }
+            if let Some(gradient) = &seg.seg.gradient {
+                let (min, max) = gradient.domain();
+                if !(min.is_finite() && max.is_finite() && min < max)
+                {
+                    problems.push(
+                        ValidationError::DegenerateGradient {
+                            side: seg.side,
+                        },
+                    );
+                }
+            }
+        }
+        if let Some(tabs) = &self.tabs
+            && tabs
+                .highlight
+                .as_ref()
+                .is_some_and(|h| h.colors.is_empty())
+        {
+            problems.push(ValidationError::EmptyTabHighlight);
+        }
+        for (index, (_, pos)) in self.titles.iter().enumerate() {
+            let side = match pos {
+                Position::Top => Side::Top,
+                Position::Bottom => Side::Bottom,
+            };
+            if !segs.get(side).should_be_rendered {
+                problems.push(ValidationError::TitleOnHiddenSide {
+                    index,
+                    side,
+                });
+            }
+        }
+        for side in [Side::Bottom, Side::Left, Side::Right] {
+            if segs.get(side).area_margin != segs.top.area_margin {
+                problems.push(ValidationError::ConflictingMargins {
+                    side,
+                });
+            }
+        }
+        problems
+    }
+}
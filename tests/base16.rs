@@ -0,0 +1,96 @@
+use tui_gradient_block::base16::Base16Scheme;
+/// The 16 (or 24) slots of a scheme as `baseXX: <value>` lines, the
+/// value made from the slot number by `value`
+fn slots(
+    n: usize,
+    indent: &str,
+    value: impl Fn(usize) -> String,
+) -> String {
+    (0..n)
+        .map(|i| format!("{indent}base{i:02X}: {}\n", value(i)))
+        .collect()
+}
+fn hex(i: usize) -> String {
+    format!("{:02x}{:02x}{:02x}", i * 10, i * 10, i * 10)
+}
+fn css(scheme: &Base16Scheme, slot: usize) -> String {
+    scheme.base(slot).unwrap().to_css_hex()
+}
+#[test]
+fn reads_the_flat_layout() {
+    let yaml = format!(
+        "scheme: \"Flat\"\nauthor: \"Someone\"\n{}",
+        slots(16, "", |i| format!("\"{}\"", hex(i)))
+    );
+    let scheme = Base16Scheme::parse(&yaml).unwrap();
+    assert_eq!(scheme.name, "Flat");
+    assert_eq!(scheme.author, "Someone");
+    assert_eq!(scheme.colors.len(), 16);
+    assert!(!scheme.is_base24());
+    assert_eq!(css(&scheme, 0x00), "#000000");
+    assert_eq!(css(&scheme, 0x0F), "#969696");
+}
+#[test]
+fn reads_the_palette_layout() {
+    let yaml = format!(
+        "system: \"base16\"\nname: \"Nested\"\nauthor: \"Someone\"\n\
+         variant: \"dark\"\npalette:\n{}",
+        slots(16, "  ", |i| format!("\"#{}\"", hex(i)))
+    );
+    let scheme = Base16Scheme::parse(&yaml).unwrap();
+    assert_eq!(scheme.name, "Nested");
+    assert_eq!(css(&scheme, 0x0D), "#828282");
+}
+#[test]
+fn quoted_and_bare_values_are_the_same() {
+    let quoted = slots(16, "", |i| format!("\"{}\"", hex(i)));
+    let single = slots(16, "", |i| format!("'#{}'", hex(i)));
+    let bare = slots(16, "", hex);
+    let hashed = slots(16, "", |i| format!("#{}", hex(i)));
+    let parse =
+        |yaml: &str| Base16Scheme::parse(yaml).unwrap().colors;
+    assert_eq!(parse(&single), parse(&quoted));
+    assert_eq!(parse(&bare), parse(&quoted));
+    assert_eq!(parse(&hashed), parse(&quoted));
+}
+#[test]
+fn trailing_comments_are_ignored() {
+    let plain = slots(16, "", hex);
+    let commented =
+        slots(16, "", |i| format!("{} # slot {i}", hex(i)));
+    let quoted =
+        slots(16, "", |i| format!("\"{}\" # slot {i}", hex(i)));
+    let hashed = slots(16, "", |i| format!("#{} # slot {i}", hex(i)));
+    let parse =
+        |yaml: &str| Base16Scheme::parse(yaml).unwrap().colors;
+    assert_eq!(parse(&commented), parse(&plain));
+    assert_eq!(parse(&quoted), parse(&plain));
+    assert_eq!(parse(&hashed), parse(&plain));
+}
+#[test]
+fn all_24_slots_make_a_base24_scheme() {
+    let scheme = Base16Scheme::parse(&slots(24, "", hex)).unwrap();
+    assert!(scheme.is_base24());
+    assert_eq!(scheme.colors.len(), 24);
+    assert_eq!(css(&scheme, 0x17), "#e6e6e6");
+}
+#[test]
+fn partial_base24_falls_back_to_base16() {
+    let scheme = Base16Scheme::parse(&slots(20, "", hex)).unwrap();
+    assert!(!scheme.is_base24());
+    assert_eq!(scheme.colors.len(), 16);
+    assert_eq!(scheme.base(0x10), None);
+}
+#[test]
+fn missing_slot_is_an_error() {
+    let yaml = slots(16, "", hex).replace("base0A:", "# base0A:");
+    let err = Base16Scheme::parse(&yaml).unwrap_err();
+    assert_eq!(err.to_string(), "the scheme lacks base0A");
+}
+#[test]
+fn bad_color_names_its_slot() {
+    let yaml = slots(16, "", hex)
+        .replace("base03: 1e1e1e", "base03: 1e1e1z");
+    let err = Base16Scheme::parse(&yaml).unwrap_err();
+    assert!(err.to_string().starts_with("base03"), "{err}");
+}
@@ -0,0 +1,61 @@
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Color, Style},
+    widgets::Widget,
+};
+use tui_gradient_block::{
+    enums::BlendMode, gradient, gradient_block::GradientBlock,
+};
+const BACKGROUND: Color = Color::Rgb(64, 128, 192);
+/// The background of the middle cell of a block with a mid-gray
+/// background, drawn with `mode` over `BACKGROUND`
+fn blended(mode: BlendMode) -> Color {
+    let area = Rect::new(0, 0, 5, 3);
+    let mut buf = Buffer::filled(
+        area,
+        Cell::new(" ")
+            .set_style(Style::new().bg(BACKGROUND))
+            .clone(),
+    );
+    GradientBlock::new()
+        .style(Style::new().bg(Color::Rgb(128, 128, 128)))
+        .blend_mode(mode)
+        .render(area, &mut buf);
+    buf[(2, 1)].bg
+}
+#[test]
+fn normal_replaces_the_background() {
+    assert_eq!(blended(BlendMode::Normal), Color::Rgb(128, 128, 128));
+}
+#[test]
+fn multiply_darkens() {
+    // 128 * 64 / 255 = 32.1, 128 * 128 / 255 = 64.3, ...
+    assert_eq!(blended(BlendMode::Multiply), Color::Rgb(32, 64, 96));
+}
+#[test]
+fn screen_lightens() {
+    // 255 - 127 * 191 / 255 = 159.9, ...
+    assert_eq!(blended(BlendMode::Screen), Color::Rgb(160, 192, 224));
+}
+#[test]
+fn overlay_multiplies_dark_and_screens_light() {
+    // red is below half and multiplied twice, green and blue screened
+    assert_eq!(blended(BlendMode::Overlay), Color::Rgb(64, 128, 192));
+}
+#[test]
+fn border_foreground_blends_against_the_background() {
+    let area = Rect::new(0, 0, 5, 3);
+    let mut buf = Buffer::filled(
+        area,
+        Cell::new(" ")
+            .set_style(Style::new().bg(BACKGROUND))
+            .clone(),
+    );
+    GradientBlock::new()
+        .top_gradient(gradient!((200, 100, 50), (200, 100, 50)))
+        .blend_mode(BlendMode::Multiply)
+        .render(area, &mut buf);
+    // 200 * 64 / 255 = 50.2, 100 * 128 / 255 = 50.2, 50 * 192 / 255 = 37.6
+    assert_eq!(buf[(2, 0)].fg, Color::Rgb(50, 50, 38));
+}
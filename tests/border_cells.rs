@@ -0,0 +1,24 @@
+use ratatui::layout::{Position, Rect};
+use tui_gradient_block::{
+    gradient_block::GradientBlock,
+    preset,
+    structs::{
+        border_segment::Segment,
+        border_symbols::{Symbol, SymbolSet},
+    },
+};
+#[test]
+fn border_cells_keep_whole_symbols() {
+    let block = GradientBlock::new().top(Segment::new(SymbolSet {
+        start: Symbol::new("e\u{301}").unwrap(),
+        center: Symbol::new("🔥").unwrap(),
+        ..preset::PLAIN.top
+    }));
+    let area = Rect::new(0, 0, 8, 3);
+    let top: Vec<(Position, String)> = block
+        .border_cells(area)
+        .filter(|(pos, _)| pos.y == 0)
+        .collect();
+    assert_eq!(top[0], (Position::new(0, 0), "e\u{301}".to_string()));
+    assert!(top.iter().any(|(_, symbol)| symbol == "🔥"));
+}
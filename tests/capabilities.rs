@@ -0,0 +1,113 @@
+#![cfg(feature = "capabilities")]
+use ratatui::style::Color;
+use tui_gradient_block::{
+    capabilities::{parse_osc_color, Capabilities},
+    enums::ColorDepth,
+};
+const UTF8: Option<&str> = Some("en_US.UTF-8");
+fn depth(colorterm: Option<&str>, term: Option<&str>) -> ColorDepth {
+    Capabilities::from_vars(colorterm, term, UTF8).color_depth
+}
+#[test]
+fn colorterm_enables_truecolor() {
+    for colorterm in ["truecolor", "24bit", "TrueColor"] {
+        assert_eq!(
+            depth(Some(colorterm), Some("xterm")),
+            ColorDepth::TrueColor
+        );
+    }
+    assert_eq!(
+        depth(None, Some("xterm-direct")),
+        ColorDepth::TrueColor
+    );
+}
+#[test]
+fn term_sets_the_palette_size() {
+    assert_eq!(
+        depth(None, Some("xterm-256color")),
+        ColorDepth::Ansi256
+    );
+    assert_eq!(
+        depth(Some("yes"), Some("screen-256color")),
+        ColorDepth::Ansi256
+    );
+    assert_eq!(depth(None, Some("xterm")), ColorDepth::Ansi16);
+    assert_eq!(depth(None, Some("linux")), ColorDepth::Ansi16);
+}
+#[test]
+fn dumb_or_missing_term_has_no_color() {
+    assert_eq!(depth(None, Some("dumb")), ColorDepth::NoColor);
+    assert_eq!(depth(None, None), ColorDepth::NoColor);
+}
+#[test]
+fn unicode_needs_a_utf8_locale() {
+    let unicode = |term, locale| {
+        Capabilities::from_vars(None, Some(term), locale).unicode
+    };
+    assert!(unicode("xterm-256color", UTF8));
+    assert!(unicode("xterm", Some("C.utf8")));
+    assert!(!unicode("xterm", Some("C")));
+    assert!(!unicode("xterm", None));
+}
+#[test]
+fn linux_and_dumb_consoles_have_no_unicode() {
+    for term in ["linux", "dumb"] {
+        assert!(
+            !Capabilities::from_vars(None, Some(term), UTF8).unicode
+        );
+    }
+}
+#[test]
+fn no_color_overrides_the_terminal() {
+    // SAFETY: no other test in this binary reads or writes the
+    // environment
+    unsafe { std::env::set_var("NO_COLOR", "1") };
+    assert_eq!(
+        Capabilities::detect().color_depth,
+        ColorDepth::NoColor
+    );
+}
+#[test]
+fn osc_channels_scale_from_any_digit_count() {
+    let parse = |rgb: &str| {
+        parse_osc_color(&format!("\x1b]11;rgb:{rgb}\x07"))
+    };
+    assert_eq!(parse("f/8/0"), Some(Color::Rgb(255, 136, 0)));
+    assert_eq!(parse("ff/80/00"), Some(Color::Rgb(255, 128, 0)));
+    assert_eq!(parse("fff/800/000"), Some(Color::Rgb(255, 127, 0)));
+    assert_eq!(
+        parse("ffff/8000/0000"),
+        Some(Color::Rgb(255, 127, 0))
+    );
+    assert_eq!(parse("1e1e/1e1e/2e2e"), Some(Color::Rgb(30, 30, 46)));
+}
+#[test]
+fn osc_reply_ends_with_bel_or_st() {
+    let color = Some(Color::Rgb(30, 30, 46));
+    assert_eq!(
+        parse_osc_color("\x1b]11;rgb:1e1e/1e1e/2e2e\x07"),
+        color
+    );
+    assert_eq!(
+        parse_osc_color("\x1b]11;rgb:1e1e/1e1e/2e2e\x1b\\"),
+        color
+    );
+    assert_eq!(parse_osc_color("\x1b]10;rgb:1e1e/1e1e/2e2e"), color);
+}
+#[test]
+fn malformed_osc_replies_are_none() {
+    for reply in [
+        "",
+        "\x07",
+        "\x1b]11;?\x07",
+        "\x1b]11;#1e1e2e\x07",
+        "\x1b]11;rgb:\x07",
+        "\x1b]11;rgb:ff/ff\x07",
+        "\x1b]11;rgb:ff/ff/ff/ff\x07",
+        "\x1b]11;rgb:ff//ff\x07",
+        "\x1b]11;rgb:fffff/0/0\x07",
+        "\x1b]11;rgb:zz/00/00\x07",
+    ] {
+        assert_eq!(parse_osc_color(reply), None, "{reply:?}");
+    }
+}
@@ -0,0 +1,90 @@
+use colorgrad::{Color, Gradient};
+use tui_gradient_block::{
+    color_vision::{daltonize, simulate},
+    enums::ColorVision,
+    gradient,
+    structs::gradient::GradientTheme,
+};
+const RED: (u8, u8, u8) = (255, 0, 0);
+const GREEN: (u8, u8, u8) = (0, 255, 0);
+const BLUE: (u8, u8, u8) = (0, 0, 255);
+fn rgb((r, g, b): (u8, u8, u8)) -> Color {
+    Color::from_rgba8(r, g, b, 255)
+}
+fn seen(vision: ColorVision, c: (u8, u8, u8)) -> [u8; 3] {
+    let [r, g, b, _] = simulate(vision, &rgb(c)).to_rgba8();
+    [r, g, b]
+}
+#[test]
+fn protanopia_loses_red() {
+    let v = ColorVision::Protanopia;
+    assert_eq!(seen(v, RED), [109, 95, 0]);
+    assert_eq!(seen(v, GREEN), [255, 229, 0]);
+    assert_eq!(seen(v, BLUE), [0, 89, 255]);
+}
+#[test]
+fn deuteranopia_loses_green() {
+    let v = ColorVision::Deuteranopia;
+    assert_eq!(seen(v, RED), [163, 144, 0]);
+    assert_eq!(seen(v, GREEN), [239, 214, 58]);
+    assert_eq!(seen(v, BLUE), [0, 61, 251]);
+}
+#[test]
+fn tritanopia_loses_blue() {
+    let v = ColorVision::Tritanopia;
+    assert_eq!(seen(v, RED), [255, 0, 15]);
+    assert_eq!(seen(v, GREEN), [0, 247, 217]);
+    assert_eq!(seen(v, BLUE), [0, 107, 150]);
+}
+#[test]
+fn achromatopsia_sees_luminance() {
+    let v = ColorVision::Achromatopsia;
+    assert_eq!(seen(v, RED), [127, 127, 127]);
+    assert_eq!(seen(v, GREEN), [220, 220, 220]);
+    assert_eq!(seen(v, BLUE), [76, 76, 76]);
+}
+#[test]
+fn normal_vision_is_unchanged() {
+    for c in [RED, GREEN, BLUE, (255, 128, 0)] {
+        let [r, g, b] = seen(ColorVision::Normal, c);
+        assert_eq!((r, g, b), c);
+    }
+}
+#[test]
+fn grays_stay_gray() {
+    for v in [
+        ColorVision::Protanopia,
+        ColorVision::Deuteranopia,
+        ColorVision::Tritanopia,
+        ColorVision::Achromatopsia,
+    ] {
+        for l in [0, 64, 128, 200, 255] {
+            let gray = rgb((l, l, l));
+            assert_eq!(
+                simulate(v, &gray).to_rgba8(),
+                [l, l, l, 255],
+                "{v:?}"
+            );
+            assert_eq!(
+                daltonize(v, &gray).to_rgba8(),
+                [l, l, l, 255],
+                "{v:?}"
+            );
+        }
+    }
+}
+#[test]
+fn daltonize_separates_red_and_green() {
+    let v = ColorVision::Deuteranopia;
+    let red = daltonize(v, &rgb(RED));
+    let green = daltonize(v, &rgb(GREEN));
+    assert_eq!(red.to_rgba8(), [255, 0, 64, 255]);
+    assert_eq!(green.to_rgba8(), [0, 129, 0, 255]);
+}
+#[test]
+fn simulated_themes_follow_simulate() {
+    let theme = GradientTheme::uniform(gradient!(RED, GREEN))
+        .simulate(ColorVision::Protanopia);
+    assert_eq!(theme.up.top.at(0.0).to_rgba8(), [109, 95, 0, 255]);
+    assert_eq!(theme.up.top.at(1.0).to_rgba8(), [255, 229, 0, 255]);
+}
@@ -0,0 +1,73 @@
+use ratatui::style::Color;
+use tui_gradient_block::contrast::{
+    contrast_ratio, high_contrast_color, readable_title_fg,
+    relative_luminance,
+};
+const WHITE: Color = Color::Rgb(255, 255, 255);
+const BLACK: Color = Color::Rgb(0, 0, 0);
+fn ratio(a: Color, b: Color) -> f32 {
+    contrast_ratio(a, b).unwrap()
+}
+#[test]
+fn luminance_spans_black_to_white() {
+    assert_eq!(relative_luminance(BLACK), Some(0.0));
+    assert!((relative_luminance(WHITE).unwrap() - 1.0).abs() < 1e-4);
+    assert_eq!(relative_luminance(Color::Reset), None);
+}
+#[test]
+fn black_on_white_is_21_to_1() {
+    assert!((ratio(BLACK, WHITE) - 21.0).abs() < 0.01);
+    // the order of the colors doesn't matter
+    assert_eq!(ratio(WHITE, BLACK), ratio(BLACK, WHITE));
+}
+#[test]
+fn identical_colors_are_1_to_1() {
+    for c in [BLACK, WHITE, Color::Rgb(118, 179, 214), Color::Red] {
+        assert!((ratio(c, c) - 1.0).abs() < 1e-4);
+    }
+}
+#[test]
+fn gray_text_sits_around_the_aa_threshold() {
+    // #767676 is the lightest gray passing AA (4.5:1) on white,
+    // #777777 just fails it
+    assert!(ratio(Color::Rgb(0x76, 0x76, 0x76), WHITE) >= 4.5);
+    assert!(ratio(Color::Rgb(0x77, 0x77, 0x77), WHITE) < 4.5);
+}
+#[test]
+fn reset_has_no_ratio() {
+    assert_eq!(contrast_ratio(Color::Reset, WHITE), None);
+}
+#[test]
+fn readable_title_falls_back_to_black_or_white() {
+    let light = [Color::Rgb(230, 230, 230)];
+    let dark = [Color::Rgb(20, 20, 30)];
+    assert_eq!(readable_title_fg(&light, None, 4.5), Color::Black);
+    assert_eq!(readable_title_fg(&dark, None, 4.5), Color::White);
+    // an accent that is readable enough is kept
+    assert_eq!(
+        readable_title_fg(&dark, Some(Color::Rgb(255, 200, 0)), 4.5),
+        Color::Rgb(255, 200, 0)
+    );
+    assert_eq!(
+        readable_title_fg(&light, Some(Color::Rgb(255, 200, 0)), 4.5),
+        Color::Black
+    );
+}
+#[test]
+fn high_contrast_colors_reach_aaa() {
+    for bg in [
+        BLACK,
+        WHITE,
+        Color::Rgb(40, 42, 54),
+        Color::Rgb(250, 240, 220),
+    ] {
+        for c in [
+            Color::Rgb(28, 123, 186),
+            Color::Rgb(120, 120, 120),
+            Color::Magenta,
+        ] {
+            let hc = high_contrast_color(c, bg);
+            assert!(ratio(hc, bg) >= 7.0, "{hc:?} on {bg:?}");
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use tui_gradient_block::{
+    gradient_block::GradientBlock,
+    test_utils::{buffer_to_string, render},
+};
+fn draw(width: u16, height: u16) -> String {
+    buffer_to_string(&render(&GradientBlock::new(), width, height))
+}
+#[test]
+fn empty_area_draws_nothing() {
+    assert_eq!(draw(0, 0), "");
+}
+#[test]
+fn single_cell_is_a_corner() {
+    assert_eq!(draw(1, 1), "┌");
+}
+#[test]
+fn single_column_draws_the_left_side() {
+    assert_eq!(draw(1, 4), "┌\n│\n│\n└");
+}
+#[test]
+fn single_row_draws_the_top() {
+    assert_eq!(draw(5, 1), "┌───┐");
+}
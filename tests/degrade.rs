@@ -0,0 +1,119 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+use tui_gradient_block::{
+    enums::ColorDepth,
+    gradient,
+    gradient_block::GradientBlock,
+    structs::{glow::GlowConfig, shadow::ShadowConfig},
+};
+/// Renders `block` in the middle of a larger buffer, leaving room for
+/// its shadow and glow
+fn draw(block: GradientBlock) -> Buffer {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 9, 7));
+    block.render(Rect::new(2, 2, 5, 3), &mut buf);
+    buf
+}
+fn lit() -> GradientBlock<'static> {
+    GradientBlock::new()
+        .top_gradient(gradient!((255, 0, 0), (0, 0, 255)))
+        .glow(GlowConfig::default())
+}
+#[test]
+fn monochrome_strips_the_shadow_color() {
+    let buf = draw(
+        lit()
+            .shadow(ShadowConfig {
+                color: Some(Color::Rgb(40, 40, 80)),
+                ..Default::default()
+            })
+            .monochrome(true),
+    );
+    for cell in buf.content() {
+        assert_eq!((cell.fg, cell.bg), (Color::Reset, Color::Reset));
+    }
+}
+#[test]
+fn monochrome_keeps_a_dimmed_shadow() {
+    let buf =
+        draw(lit().shadow(ShadowConfig::default()).monochrome(true));
+    for cell in buf.content() {
+        assert_eq!((cell.fg, cell.bg), (Color::Reset, Color::Reset));
+    }
+    assert!(buf[(7, 3)].modifier.contains(Modifier::DIM));
+}
+#[cfg(feature = "capabilities")]
+#[test]
+fn auto_degrade_covers_the_shadow_and_glow() {
+    use tui_gradient_block::capabilities::Capabilities;
+    let caps = Capabilities::from_vars(
+        None,
+        Some("xterm"),
+        Some("en_US.UTF-8"),
+    );
+    let buf = draw(
+        lit()
+            .shadow(ShadowConfig {
+                color: Some(Color::Rgb(40, 40, 80)),
+                ..Default::default()
+            })
+            .auto_degrade(caps),
+    );
+    for cell in buf.content() {
+        assert!(!matches!(cell.fg, Color::Rgb(..)));
+        assert!(!matches!(cell.bg, Color::Rgb(..)));
+    }
+    // the glow above the border and the shadow below it
+    assert_ne!(buf[(4, 1)].bg, Color::Reset);
+    assert_eq!(buf[(4, 5)].bg, Color::Blue);
+}
+#[test]
+fn color_depth_maps_the_shadow_color() {
+    let buf = draw(
+        GradientBlock::new()
+            .shadow(ShadowConfig {
+                color: Some(Color::Rgb(20, 20, 140)),
+                ..Default::default()
+            })
+            .color_depth(ColorDepth::Ansi16),
+    );
+    assert_eq!(buf[(7, 3)].bg, Color::Blue);
+    assert_eq!(buf[(4, 5)].bg, Color::Blue);
+}
+#[test]
+fn color_depth_maps_the_glow() {
+    let buf = draw(lit().color_depth(ColorDepth::Indexed16));
+    // red faded halfway to the black background above the corner
+    assert_eq!(buf[(2, 1)].bg, Color::Indexed(1));
+    for cell in buf.content() {
+        assert!(!matches!(cell.bg, Color::Rgb(..)));
+    }
+}
+#[test]
+fn ansi16_leaves_no_rgb_in_the_buffer() {
+    for shadow in [
+        ShadowConfig::default(),
+        ShadowConfig {
+            color: Some(Color::Rgb(40, 40, 80)),
+            offset_x: -1,
+            offset_y: 2,
+            ..Default::default()
+        },
+    ] {
+        let buf = draw(
+            lit()
+                .style(Style::new().bg(Color::Rgb(30, 30, 46)))
+                .title_top("Logs")
+                .shadow(shadow)
+                .color_depth(ColorDepth::Ansi16),
+        );
+        for (i, cell) in buf.content().iter().enumerate() {
+            let pos = buf.pos_of(i);
+            assert!(!matches!(cell.fg, Color::Rgb(..)), "{pos:?}");
+            assert!(!matches!(cell.bg, Color::Rgb(..)), "{pos:?}");
+        }
+    }
+}
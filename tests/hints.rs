@@ -0,0 +1,41 @@
+use ratatui::layout::Alignment;
+use tui_gradient_block::{
+    gradient_block::GradientBlock,
+    structs::hints::Hint,
+    test_utils::{buffer_to_string, render},
+};
+fn bottom_row(block: &GradientBlock, width: u16) -> String {
+    let text = buffer_to_string(&render(block, width, 3));
+    text.lines().last().unwrap_or_default().to_string()
+}
+#[test]
+fn hint_width_counts_cells() {
+    assert_eq!(Hint::new("q", "quit").width(), 8);
+    assert_eq!(Hint::new("q", "終了").width(), 8);
+}
+#[test]
+fn wide_labels_take_two_cells_per_char() {
+    let block = GradientBlock::new().hints(&[("q", "終了")]);
+    let buf = render(&block, 14, 3);
+    let symbols: Vec<&str> =
+        (0..14).map(|x| buf[(x, 2)].symbol()).collect();
+    assert_eq!(symbols[2..5].concat(), " q:");
+    assert_eq!(symbols[5], "終");
+    assert_eq!(symbols[7], "了");
+    assert_eq!(symbols[9..].concat(), " ───┘");
+}
+#[test]
+fn hints_start_after_a_left_title() {
+    let block = GradientBlock::new()
+        .title_bottom("Status")
+        .hints(&[("q", "quit"), ("s", "save")]);
+    assert_eq!(bottom_row(&block, 24), "└Status─ q:quit ───────┘");
+}
+#[test]
+fn hints_stop_before_a_right_title() {
+    let block = GradientBlock::new()
+        .title_bottom("Status")
+        .title_alignment(Alignment::Right)
+        .hints(&[("q", "quit"), ("s", "save")]);
+    assert_eq!(bottom_row(&block, 22), "└─ q:quit ─────Status┘");
+}
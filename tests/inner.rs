@@ -0,0 +1,121 @@
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::{Position, Rect},
+    style::Color,
+    widgets::Widget,
+};
+use tui_gradient_block::{
+    enums::ClearMode,
+    gradient_block::GradientBlock,
+    structs::{flags::Sides, glow::GlowConfig, pattern::Pattern},
+    test_utils::{buffer_to_string, render},
+};
+#[test]
+fn inner_leaves_out_an_inset_glow() {
+    let block = GradientBlock::new()
+        .glow(GlowConfig {
+            inset: true,
+            ..Default::default()
+        })
+        .border_thickness(Sides::ALL, 2);
+    let area = Rect::new(0, 0, 20, 10);
+    let inner = block.inner(area);
+    assert_eq!(inner, Rect::new(3, 3, 14, 4));
+    for (pos, _) in block.border_cells(area) {
+        assert!(!inner.contains(pos), "{pos:?} is in {inner:?}");
+    }
+}
+#[test]
+fn fill_starts_below_a_thick_top() {
+    let block = GradientBlock::new()
+        .border_thickness(Sides::TOP, 2)
+        .fill("hello");
+    let text = buffer_to_string(&render(&block, 9, 5));
+    let rows: Vec<&str> = text.lines().collect();
+    assert_eq!(rows[2], "│hello  │");
+    assert!(!rows[1].contains("hello"));
+}
+#[test]
+fn fill_leaves_the_margin_blank() {
+    let block = GradientBlock::new().margin(1, 1).fill("hi");
+    let text = buffer_to_string(&render(&block, 8, 5));
+    assert_eq!(
+        text,
+        "        \n ┌────┐ \n │hi  │ \n └────┘ \n        "
+    );
+}
+fn cells_where(
+    buf: &Buffer,
+    pred: impl Fn(&str) -> bool,
+) -> Vec<Position> {
+    buf.area
+        .positions()
+        .filter(|&pos| pred(buf[pos].symbol()))
+        .collect()
+}
+#[test]
+fn clear_inner_clears_exactly_inner() {
+    let block = GradientBlock::new()
+        .margin_sides(2, 1, 1, 0)
+        .clear_mode(ClearMode::Inner);
+    let area = Rect::new(0, 0, 14, 7);
+    let mut buf = Buffer::filled(area, Cell::new("x"));
+    (&block).render(area, &mut buf);
+    assert_eq!(
+        cells_where(&buf, |s| s == " "),
+        block.inner(area).positions().collect::<Vec<_>>()
+    );
+}
+#[test]
+fn pattern_covers_exactly_inner() {
+    let block = GradientBlock::new()
+        .margin_sides(2, 1, 1, 0)
+        .fill_pattern(Pattern::Checkerboard {
+            chars: ['a', 'b'],
+            colors: [Color::Red, Color::Blue],
+        });
+    let area = Rect::new(0, 0, 14, 7);
+    let buf = render(&block, area.width, area.height);
+    assert_eq!(
+        cells_where(&buf, |s| s == "a" || s == "b"),
+        block.inner(area).positions().collect::<Vec<_>>()
+    );
+}
+#[test]
+fn border_cells_follow_an_inset_glow() {
+    let block = GradientBlock::new().glow(GlowConfig {
+        inset: true,
+        ..Default::default()
+    });
+    let area = Rect::new(0, 0, 6, 5);
+    let buf = render(&block, area.width, area.height);
+    for (pos, _) in block.border_cells(area) {
+        assert_ne!(buf[pos].symbol(), " ", "{pos:?} is not drawn");
+    }
+    assert_eq!(
+        block.border_cells(area).next().map(|(pos, _)| pos),
+        Some(Position::new(1, 1))
+    );
+}
+#[test]
+fn wide_inset_glow_takes_at_most_two_rings() {
+    let block = GradientBlock::new().glow(GlowConfig {
+        width: 5,
+        inset: true,
+        ..Default::default()
+    });
+    assert_eq!(
+        block.inner(Rect::new(0, 0, 10, 8)),
+        Rect::new(3, 3, 4, 2)
+    );
+    let plain = GradientBlock::new().min_size();
+    let size = block.min_size();
+    assert_eq!(
+        (size.width, size.height),
+        (plain.width + 4, plain.height + 4)
+    );
+    assert_eq!(
+        buffer_to_string(&render(&block, 8, 6)),
+        "        \n        \n  ┌──┐  \n  └──┘  \n        \n        "
+    );
+}
@@ -0,0 +1,63 @@
+use colorgrad::Gradient;
+use tui_gradient_block::{
+    enums::Interpolation,
+    gradient,
+    gradient_utils::BlendMode,
+    structs::gradient::{GradientDescriptor, GradientTheme},
+    theme,
+    theme_presets::cool::t_misty_blue::{
+        COLOR_1, COLOR_2, COLOR_3, COLOR_4, COLOR_5,
+    },
+};
+fn midpoint(g: &dyn Gradient) -> [u8; 4] {
+    g.at(0.5).to_rgba8()
+}
+#[test]
+fn rgb_midpoint_is_the_channel_average() {
+    let g = gradient!((255, 0, 0), (0, 0, 255));
+    assert_eq!(midpoint(&g), [128, 0, 128, 255]);
+}
+#[test]
+fn oklab_midpoint_differs_from_rgb() {
+    let rgb = gradient!((255, 0, 0), (0, 0, 255));
+    let oklab = gradient!((255, 0, 0), (0, 0, 255); mode = Oklab);
+    assert_ne!(midpoint(&oklab), midpoint(&rgb));
+    // both still start and end on the same colors
+    assert_eq!(oklab.at(0.0).to_rgba8(), rgb.at(0.0).to_rgba8());
+    assert_eq!(oklab.at(1.0).to_rgba8(), rgb.at(1.0).to_rgba8());
+}
+#[test]
+fn descriptor_midpoint_follows_its_interpolation() {
+    let d = GradientDescriptor::new(vec![(255, 0, 0), (0, 0, 255)]);
+    let oklab = d.clone().interpolation(Interpolation::Oklab);
+    assert_eq!(midpoint(&d), [128, 0, 128, 255]);
+    assert_eq!(
+        midpoint(&oklab),
+        midpoint(&gradient!((255, 0, 0), (0, 0, 255); mode = Oklab))
+    );
+}
+#[test]
+fn presets_can_be_rebuilt_in_oklab() {
+    let colors = [COLOR_1, COLOR_2, COLOR_3, COLOR_4, COLOR_5];
+    let rgb = GradientTheme::from_colors(colors.clone());
+    let oklab =
+        GradientTheme::from_colors_in(colors, BlendMode::Oklab);
+    let at = |theme: &GradientTheme, t| {
+        theme.top_right.top.at(t).to_rgba8()
+    };
+    assert_ne!(at(&oklab, 0.125), at(&rgb, 0.125));
+    assert_eq!(at(&oklab, 0.25), at(&rgb, 0.25));
+}
+#[test]
+fn theme_macro_takes_a_mode() {
+    let oklab = theme! {
+        colors: [(255, 0, 0), (0, 0, 255)],
+        mode: Oklab,
+        misc2: { top: [1, 5] },
+    };
+    let rgb = theme! {
+        colors: [(255, 0, 0), (0, 0, 255)],
+        misc2: { top: [1, 5] },
+    };
+    assert_ne!(midpoint(&oklab.misc2.top), midpoint(&rgb.misc2.top));
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "serde")]
+use colorgrad::Color;
+use std::path::{Path, PathBuf};
+use tui_gradient_block::{
+    pywal::PywalScheme, structs::gradient::GradientTheme,
+};
+fn fixture() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/colors.json")
+}
+/// The fixture with `from` replaced by `to`, written to a file of its
+/// own named after the test
+fn broken(name: &str, from: &str, to: &str) -> PathBuf {
+    let json = std::fs::read_to_string(fixture()).unwrap();
+    assert!(json.contains(from));
+    let path = std::env::temp_dir().join(format!(
+        "tui-gradient-block-{}-{name}.json",
+        std::process::id()
+    ));
+    std::fs::write(&path, json.replace(from, to)).unwrap();
+    path
+}
+fn hex(c: &Color) -> String {
+    c.to_css_hex()
+}
+#[test]
+fn reads_the_special_and_ansi_colors() {
+    let scheme = PywalScheme::from_json(&fixture()).unwrap();
+    assert_eq!(hex(&scheme.background), "#1b1a1f");
+    assert_eq!(hex(&scheme.foreground), "#e3dcd2");
+    assert_eq!(scheme.colors.len(), 16);
+    assert_eq!(hex(&scheme.colors[0]), "#1b1a1f");
+    assert_eq!(hex(&scheme.colors[15]), "#e3dcd2");
+}
+#[test]
+fn accents_are_color1_to_color6() {
+    let scheme = PywalScheme::from_json(&fixture()).unwrap();
+    let accents: Vec<String> =
+        scheme.accents().iter().map(hex).collect();
+    assert_eq!(
+        accents,
+        [
+            "#a4553f", "#c0703f", "#d08a4e", "#6f7d96", "#8c8ea8",
+            "#b5a48f"
+        ]
+    );
+}
+#[test]
+fn missing_color_is_an_error() {
+    let path = broken("missing", "\"color12\"", "\"color_12\"");
+    let err = PywalScheme::from_json(&path).unwrap_err();
+    assert!(err.to_string().contains("color12"), "{err}");
+}
+#[test]
+fn bad_hex_is_an_error() {
+    let path = broken("bad-hex", "#6f7d96", "#6f7z96");
+    assert!(PywalScheme::from_json(&path).is_err());
+}
+#[test]
+fn missing_file_is_an_error() {
+    assert!(GradientTheme::from_pywal("tests/fixtures/nope.json")
+        .is_err());
+}
+#[test]
+fn theme_goes_through_the_accents() {
+    let theme = GradientTheme::from_pywal(fixture()).unwrap();
+    // the darkest accent starts the dark to light gradients
+    assert_eq!(hex(&theme.down.right.at(0.0)), "#a4553f");
+}
@@ -0,0 +1,85 @@
+use tui_gradient_block::{
+    assert_block_snapshot, gradient,
+    gradient_block::GradientBlock,
+    preset,
+    test_utils::{buffer_to_annotated, render},
+};
+#[test]
+fn plain_corners() {
+    assert_block_snapshot!(
+        GradientBlock::new(),
+        5,
+        3,
+        "
+┌───┐
+│   │
+└───┘"
+    );
+}
+#[test]
+fn rounded_corners() {
+    assert_block_snapshot!(
+        GradientBlock::new().with_set(preset::ROUNDED),
+        5,
+        3,
+        "
+╭───╮
+│   │
+╰───╯"
+    );
+}
+#[test]
+fn double_corners() {
+    assert_block_snapshot!(
+        GradientBlock::new().with_set(preset::DOUBLE),
+        5,
+        3,
+        "
+╔═══╗
+║   ║
+╚═══╝"
+    );
+}
+#[test]
+fn ascii_corners() {
+    assert_block_snapshot!(
+        GradientBlock::new().with_set(preset::ASCII),
+        4,
+        3,
+        "
++--+
+|  |
++--+"
+    );
+}
+#[test]
+fn gradients_reach_their_endpoints() {
+    let block = GradientBlock::new()
+        .top_gradient(gradient!((255, 0, 0), (0, 0, 255)))
+        .bottom_gradient(gradient!((0, 0, 0), (255, 255, 255)))
+        .left_gradient(gradient!((0, 255, 0), (0, 0, 255)))
+        .right_gradient(gradient!((255, 255, 0), (0, 255, 255)));
+    assert_eq!(
+        buffer_to_annotated(&render(&block, 5, 4)),
+        "\
+┌───┐
+  0..1 fg=#ff0000
+  1..2 fg=#bf003f
+  2..3 fg=#7f007f
+  3..4 fg=#3f00bf
+  4..5 fg=#0000ff
+│   │
+  0..1 fg=#00a955
+  4..5 fg=#a9ff55
+│   │
+  0..1 fg=#0054aa
+  4..5 fg=#54ffaa
+└───┘
+  0..1 fg=#000000
+  1..2 fg=#3f3f3f
+  2..3 fg=#7f7f7f
+  3..4 fg=#bfbfbf
+  4..5 fg=#ffffff
+"
+    );
+}
@@ -0,0 +1,35 @@
+use ratatui::layout::Alignment;
+use tui_gradient_block::{
+    gradient_block::GradientBlock,
+    test_utils::{buffer_to_string, render},
+};
+fn top_row(block: &GradientBlock, width: u16) -> String {
+    let text = buffer_to_string(&render(block, width, 3));
+    text.lines().next().unwrap_or_default().to_string()
+}
+#[test]
+fn wide_labels_take_two_cells_per_char() {
+    let block = GradientBlock::new().tabs(&["日本", "ab"], 5);
+    let buf = render(&block, 14, 3);
+    let symbols: Vec<&str> =
+        (0..14).map(|x| buf[(x, 0)].symbol()).collect();
+    assert_eq!(symbols[2], "日");
+    assert_eq!(symbols[4], "本");
+    assert_eq!(symbols[6..12].concat(), " │ ab ");
+    assert_eq!(symbols[12..].concat(), "─┐");
+}
+#[test]
+fn tabs_start_after_a_left_title() {
+    let block = GradientBlock::new()
+        .title_top("Title")
+        .tabs(&["one", "two"], 5);
+    assert_eq!(top_row(&block, 22), "┌Title one │ two ────┐");
+}
+#[test]
+fn tabs_stop_before_a_right_title() {
+    let block = GradientBlock::new()
+        .title_top("Title")
+        .title_alignment(Alignment::Right)
+        .tabs(&["one", "two", "three"], 5);
+    assert_eq!(top_row(&block, 18), "┌ one │ two Title┐");
+}
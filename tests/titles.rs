@@ -0,0 +1,27 @@
+use tui_gradient_block::{
+    assert_block_snapshot, gradient_block::GradientBlock,
+};
+#[test]
+fn long_title_is_clipped_between_the_corners() {
+    assert_block_snapshot!(
+        GradientBlock::new().title_top("A very long title indeed"),
+        10,
+        3,
+        "
+┌A very l┐
+│        │
+└────────┘"
+    );
+}
+#[test]
+fn long_bottom_title_keeps_the_corner() {
+    assert_block_snapshot!(
+        GradientBlock::new().title_bottom("another long one"),
+        10,
+        3,
+        "
+┌────────┐
+│        │
+└another ┘"
+    );
+}
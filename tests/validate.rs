@@ -0,0 +1,40 @@
+use ratatui::widgets::Borders;
+use tui_gradient_block::{
+    enums::Side,
+    gradient_block::GradientBlock,
+    test_utils::{buffer_to_string, render},
+    validate::ValidationError,
+};
+fn borderless() -> GradientBlock<'static> {
+    GradientBlock::new()
+        .borders(Borders::NONE, false)
+        .title_top("x")
+}
+#[test]
+fn title_on_a_hidden_side_is_reported() {
+    assert_eq!(
+        borderless().validate(),
+        Err(ValidationError::TitleOnHiddenSide {
+            index: 0,
+            side: Side::Top
+        })
+    );
+}
+#[test]
+fn title_on_a_hidden_side_still_renders() {
+    let block = borderless();
+    for (w, h) in [(0, 0), (1, 1), (5, 3), (20, 6)] {
+        let text = buffer_to_string(&render(&block, w, h));
+        assert!(!text.contains('┌'), "{w}x{h}:\n{text}");
+    }
+}
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "invalid GradientBlock")]
+fn zero_width_symbols_panic_in_debug() {
+    render(
+        &GradientBlock::new().top_horizontal_symbol('\u{200b}'),
+        5,
+        3,
+    );
+}